@@ -0,0 +1,547 @@
+use core::time::Duration;
+use std::sync::Arc;
+
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::prelude::{Entity, Event, IntoSystemConfigs, With, World};
+use bevy_ecs::schedule::common_conditions::any_with_component;
+use bevy_render::color::Color;
+use bevy_time::Time;
+
+use rxy_core::{
+   AttrValueLerp, ElementAttrType, MemberHashMapState, MemberOwner, NodeTree, ViewMember,
+   ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin, XValueWrapper,
+};
+
+use crate::{update_leaving_nodes, BevyRenderer, LeavingNode, RendererState};
+
+type AnimationPlayersState = RendererState<MemberHashMapState<AttrAnimationPlayer>>;
+
+pub struct RxyAnimationPlugin;
+
+impl Plugin for RxyAnimationPlugin {
+   fn build(&self, app: &mut App) {
+      app.add_event::<AnimationEvent>().add_systems(
+         Update,
+         (
+            update_animations.run_if(any_with_component::<AnimationPlayersState>),
+            update_leaving_nodes.run_if(any_with_component::<RendererState<LeavingNode>>),
+         ),
+      );
+   }
+}
+
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnimationEvent {
+   pub entity: Entity,
+   pub kind: AnimationEventKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimationEventKind {
+   /// One pass of the keyframes finished, with the index of the finished pass.
+   /// In ping-pong mode, each direction counts as a pass.
+   Iteration(u32),
+   Completed,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnimationRepeat {
+   #[default]
+   Once,
+   Times(u32),
+   Forever,
+}
+
+impl AnimationRepeat {
+   fn passes(&self) -> Option<u32> {
+      match self {
+         AnimationRepeat::Once => Some(1),
+         AnimationRepeat::Times(n) => Some((*n).max(1)),
+         AnimationRepeat::Forever => None,
+      }
+   }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Easing {
+   #[default]
+   Linear,
+   QuadIn,
+   QuadOut,
+   QuadInOut,
+   CubicIn,
+   CubicOut,
+   CubicInOut,
+   Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+   pub fn ease(&self, t: f32) -> f32 {
+      match self {
+         Easing::Linear => t,
+         Easing::QuadIn => t * t,
+         Easing::QuadOut => 1. - (1. - t) * (1. - t),
+         Easing::QuadInOut => {
+            if t < 0.5 {
+               2. * t * t
+            } else {
+               1. - (-2. * t + 2.).powi(2) / 2.
+            }
+         }
+         Easing::CubicIn => t * t * t,
+         Easing::CubicOut => 1. - (1. - t).powi(3),
+         Easing::CubicInOut => {
+            if t < 0.5 {
+               4. * t * t * t
+            } else {
+               1. - (-2. * t + 2.).powi(3) / 2.
+            }
+         }
+         Easing::Custom(f) => f(t),
+      }
+   }
+}
+
+trait AnimationTrack: Send + Sync + 'static {
+   fn apply(&self, world: &mut World, entity: Entity, progress: f32);
+}
+
+struct AttrAnimationTrack<A>
+where
+   A: ElementAttrType<BevyRenderer>,
+{
+   frames: Vec<(f32, A::Value)>,
+}
+
+impl<A> AttrAnimationTrack<A>
+where
+   A: ElementAttrType<BevyRenderer>,
+   A::Value: AttrValueLerp,
+{
+   fn sample(&self, progress: f32) -> Option<A::Value> {
+      let index = self
+         .frames
+         .partition_point(|(offset, _)| *offset <= progress);
+      if index == 0 {
+         return self.frames.first().map(|(_, value)| value.clone());
+      }
+      if index == self.frames.len() {
+         return self.frames.last().map(|(_, value)| value.clone());
+      }
+      let (from_offset, from) = &self.frames[index - 1];
+      let (to_offset, to) = &self.frames[index];
+      let t = (progress - from_offset) / (to_offset - from_offset);
+      Some(from.lerp(to, t))
+   }
+}
+
+impl<A> AnimationTrack for AttrAnimationTrack<A>
+where
+   A: ElementAttrType<BevyRenderer>,
+   A::Value: AttrValueLerp,
+{
+   fn apply(&self, world: &mut World, entity: Entity, progress: f32) {
+      let Some(value) = self.sample(progress) else {
+         return;
+      };
+      if world.get_entity(entity).is_none() {
+         return;
+      }
+      A::set_value(world, entity, Some(value));
+   }
+}
+
+/// A set of attribute tracks played together over `duration`.
+/// Frame offsets are normalized to `0.0..=1.0`.
+///
+/// ```ignore
+/// div().member(x_animate(
+///    AttrKeyframes::new(Duration::from_millis(400))
+///       .track(translation, [(0., Vec3::new(-40., 0., 0.)), (1., Vec3::ZERO)])
+///       .track(bg_color, [(0., Color::NONE), (1., Color::WHITE)])
+///       .easing(Easing::CubicOut),
+/// ))
+/// ```
+#[derive(Clone)]
+pub struct AttrKeyframes {
+   pub duration: Duration,
+   pub delay: Duration,
+   pub easing: Easing,
+   pub repeat: AnimationRepeat,
+   pub ping_pong: bool,
+   tracks: Vec<Arc<dyn AnimationTrack>>,
+}
+
+impl AttrKeyframes {
+   pub fn new(duration: Duration) -> Self {
+      Self {
+         duration,
+         delay: Duration::ZERO,
+         easing: Easing::Linear,
+         repeat: AnimationRepeat::Once,
+         ping_pong: false,
+         tracks: vec![],
+      }
+   }
+
+   pub fn track<A, V>(mut self, _attr: A, frames: impl IntoIterator<Item = (f32, V)>) -> Self
+   where
+      A: ElementAttrType<BevyRenderer>,
+      A::Value: AttrValueLerp,
+      V: Into<XValueWrapper<A::Value>>,
+   {
+      let mut frames = frames
+         .into_iter()
+         .map(|(offset, value)| (offset.clamp(0., 1.), value.into().0))
+         .collect::<Vec<_>>();
+      frames.sort_by(|a, b| a.0.total_cmp(&b.0));
+      self
+         .tracks
+         .push(Arc::new(AttrAnimationTrack::<A> { frames }));
+      self
+   }
+
+   /// Fades `color` through the alpha `frames`, on a color attribute like `bg_color`.
+   pub fn opacity<A>(
+      self,
+      attr: A,
+      color: Color,
+      frames: impl IntoIterator<Item = (f32, f32)>,
+   ) -> Self
+   where
+      A: ElementAttrType<BevyRenderer, Value = Color>,
+   {
+      self.track(
+         attr,
+         frames
+            .into_iter()
+            .map(|(offset, alpha)| (offset, color.with_a(alpha))),
+      )
+   }
+
+   pub fn delay(mut self, delay: Duration) -> Self {
+      self.delay = delay;
+      self
+   }
+
+   pub fn easing(mut self, easing: Easing) -> Self {
+      self.easing = easing;
+      self
+   }
+
+   pub fn repeat(mut self, repeat: AnimationRepeat) -> Self {
+      self.repeat = repeat;
+      self
+   }
+
+   pub fn looped(self) -> Self {
+      self.repeat(AnimationRepeat::Forever)
+   }
+
+   pub fn ping_pong(mut self) -> Self {
+      self.ping_pong = true;
+      self
+   }
+
+   pub(crate) fn apply(&self, world: &mut World, entity: Entity, progress: f32) {
+      let progress = self.easing.ease(progress);
+      for track in self.tracks.iter() {
+         track.apply(world, entity, progress);
+      }
+   }
+}
+
+pub struct AttrAnimationPlayer {
+   keyframes: AttrKeyframes,
+   elapsed: f32,
+   pass: u32,
+}
+
+impl AttrAnimationPlayer {
+   pub(crate) fn new(keyframes: AttrKeyframes) -> Self {
+      Self {
+         keyframes,
+         elapsed: 0.,
+         pass: 0,
+      }
+   }
+
+   /// Returns `false` once the animation is completed.
+   pub(crate) fn tick(
+      &mut self,
+      delta: f32,
+      world: &mut World,
+      entity: Entity,
+      events: &mut Vec<AnimationEvent>,
+   ) -> bool {
+      self.elapsed += delta;
+      let elapsed = self.elapsed - self.keyframes.delay.as_secs_f32();
+      if elapsed < 0. {
+         return true;
+      }
+      let duration = self.keyframes.duration.as_secs_f32().max(f32::EPSILON);
+      let passes = elapsed / duration;
+      let pass = passes as u32;
+
+      if self.keyframes.repeat.passes().is_some_and(|n| pass >= n) {
+         let last_pass = self.keyframes.repeat.passes().unwrap() - 1;
+         let progress = if self.keyframes.ping_pong && last_pass % 2 == 1 {
+            0.
+         } else {
+            1.
+         };
+         self.keyframes.apply(world, entity, progress);
+         events.extend((self.pass..=last_pass).map(|pass| AnimationEvent {
+            entity,
+            kind: AnimationEventKind::Iteration(pass),
+         }));
+         events.push(AnimationEvent {
+            entity,
+            kind: AnimationEventKind::Completed,
+         });
+         return false;
+      }
+
+      events.extend((self.pass..pass).map(|pass| AnimationEvent {
+         entity,
+         kind: AnimationEventKind::Iteration(pass),
+      }));
+      self.pass = pass;
+
+      let progress = passes.fract();
+      let progress = if self.keyframes.ping_pong && pass % 2 == 1 {
+         1. - progress
+      } else {
+         progress
+      };
+      self.keyframes.apply(world, entity, progress);
+      true
+   }
+}
+
+fn update_animations(world: &mut World) {
+   let delta = world.resource::<Time>().delta_seconds();
+   let entities = world
+      .query_filtered::<Entity, With<AnimationPlayersState>>()
+      .iter(world)
+      .collect::<Vec<_>>();
+
+   let mut events = vec![];
+   for entity in entities {
+      world.node_state_scoped(
+         &entity,
+         |world, state: &mut MemberHashMapState<AttrAnimationPlayer>| {
+            state
+               .0
+               .get()
+               .retain(|_, player| player.tick(delta, world, entity, &mut events));
+         },
+      );
+   }
+   world.send_event_batch(events);
+}
+
+pub struct XAnimate(pub AttrKeyframes);
+
+pub fn x_animate(keyframes: AttrKeyframes) -> XAnimate {
+   XAnimate(keyframes)
+}
+
+impl ViewMemberOrigin<BevyRenderer> for XAnimate {
+   type Origin = Self;
+}
+
+impl ViewMember<BevyRenderer> for XAnimate {
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(mut ctx: ViewMemberCtx<BevyRenderer>, view_removed: bool) {
+      if view_removed {
+         return;
+      }
+      drop(ctx.take_indexed_view_member_state::<AttrAnimationPlayer>());
+   }
+
+   fn build(self, mut ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      // apply the first frame immediately, so the element never shows up un-animated
+      self.0.apply(&mut *ctx.world, ctx.node_id, 0.);
+      ctx.set_indexed_view_member_state(AttrAnimationPlayer::new(self.0));
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      self.build(ctx, true);
+   }
+}
+
+pub trait MemberOwnerAnimateExt: MemberOwner<BevyRenderer> + Sized {
+   fn animate(self, keyframes: AttrKeyframes) -> Self::AddMember<XAnimate>
+   where
+      (Self::VM, XAnimate): ViewMember<BevyRenderer>,
+   {
+      self.member(x_animate(keyframes))
+   }
+}
+
+impl<T> MemberOwnerAnimateExt for T where T: MemberOwner<BevyRenderer> {}
+
+#[cfg(test)]
+mod tests {
+   use crate::all_attrs::bg_color;
+   use crate::elements::element_div;
+   use bevy_ui::BackgroundColor;
+
+   use super::*;
+
+   const CURVES: [Easing; 7] = [
+      Easing::Linear,
+      Easing::QuadIn,
+      Easing::QuadOut,
+      Easing::QuadInOut,
+      Easing::CubicIn,
+      Easing::CubicOut,
+      Easing::CubicInOut,
+   ];
+
+   fn assert_near(value: f32, expected: f32) {
+      assert!((value - expected).abs() < 1e-4, "{value} is not {expected}");
+   }
+
+   #[test]
+   fn easing_curves() {
+      for easing in CURVES {
+         assert_near(easing.ease(0.), 0.);
+         assert_near(easing.ease(1.), 1.);
+      }
+      assert_near(Easing::QuadIn.ease(0.5), 0.25);
+      assert_near(Easing::QuadOut.ease(0.5), 0.75);
+      assert_near(Easing::QuadInOut.ease(0.25), 0.125);
+      assert_near(Easing::QuadInOut.ease(0.5), 0.5);
+      assert_near(Easing::CubicIn.ease(0.5), 0.125);
+      assert_near(Easing::CubicOut.ease(0.5), 0.875);
+      assert_near(Easing::CubicInOut.ease(0.75), 0.9375);
+      assert_near(Easing::Custom(|t| t.sqrt()).ease(0.25), 0.5);
+   }
+
+   struct Playing {
+      world: World,
+      entity: Entity,
+      player: AttrAnimationPlayer,
+      events: Vec<AnimationEvent>,
+   }
+
+   impl Playing {
+      fn new(keyframes: AttrKeyframes) -> Self {
+         let mut world = World::new();
+         let entity = world.spawn_node::<element_div>(None, None);
+         Self {
+            world,
+            entity,
+            player: AttrAnimationPlayer::new(keyframes),
+            events: vec![],
+         }
+      }
+
+      fn tick(&mut self, delta: f32) -> bool {
+         self
+            .player
+            .tick(delta, &mut self.world, self.entity, &mut self.events)
+      }
+
+      fn alpha(&self) -> f32 {
+         self
+            .world
+            .get::<BackgroundColor>(self.entity)
+            .unwrap()
+            .0
+            .a()
+      }
+
+      fn iterations(&self) -> Vec<u32> {
+         self
+            .events
+            .iter()
+            .filter_map(|event| match event.kind {
+               AnimationEventKind::Iteration(pass) => Some(pass),
+               AnimationEventKind::Completed => None,
+            })
+            .collect()
+      }
+
+      fn completed(&self) -> bool {
+         self
+            .events
+            .iter()
+            .any(|event| event.kind == AnimationEventKind::Completed)
+      }
+   }
+
+   fn fade_in() -> AttrKeyframes {
+      AttrKeyframes::new(Duration::from_secs(1)).opacity(
+         bg_color,
+         Color::WHITE,
+         [(0., 0.), (1., 1.)],
+      )
+   }
+
+   #[test]
+   fn samples_between_keyframes() {
+      let mut playing = Playing::new(AttrKeyframes::new(Duration::from_secs(1)).opacity(
+         bg_color,
+         Color::WHITE,
+         [(0., 0.), (0.5, 1.), (1., 0.)],
+      ));
+      assert!(playing.tick(0.25));
+      assert_near(playing.alpha(), 0.5);
+      assert!(playing.tick(0.5));
+      assert_near(playing.alpha(), 0.5);
+   }
+
+   #[test]
+   fn waits_for_the_delay() {
+      let mut playing = Playing::new(fade_in().delay(Duration::from_millis(500)));
+      let entity = playing.entity;
+      playing
+         .world
+         .entity_mut(entity)
+         .insert(BackgroundColor(Color::RED));
+      assert!(playing.tick(0.25));
+      assert_eq!(
+         playing.world.get::<BackgroundColor>(entity).unwrap().0,
+         Color::RED
+      );
+      assert!(playing.tick(0.5));
+      assert_near(playing.alpha(), 0.25);
+   }
+
+   #[test]
+   fn once_completes_on_the_last_frame() {
+      let mut playing = Playing::new(fade_in());
+      assert!(playing.tick(0.5));
+      assert!(!playing.tick(0.75));
+      assert_near(playing.alpha(), 1.);
+      assert_eq!(playing.iterations(), [0]);
+      assert!(playing.completed());
+   }
+
+   #[test]
+   fn looped_wraps_around() {
+      let mut playing = Playing::new(fade_in().looped());
+      assert!(playing.tick(2.25));
+      assert_near(playing.alpha(), 0.25);
+      assert_eq!(playing.iterations(), [0, 1]);
+      assert!(playing.tick(100.));
+      assert!(!playing.completed());
+   }
+
+   #[test]
+   fn ping_pong_plays_odd_passes_backwards() {
+      let mut playing = Playing::new(fade_in().ping_pong().repeat(AnimationRepeat::Times(2)));
+      assert!(playing.tick(1.25));
+      assert_near(playing.alpha(), 0.75);
+      assert!(!playing.tick(1.));
+      assert_near(playing.alpha(), 0.);
+      assert_eq!(playing.iterations(), [0, 1]);
+      assert!(playing.completed());
+   }
+}
@@ -0,0 +1,191 @@
+use core::future::Future;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::{Component, Entity, FromWorld, Resource, World};
+use bevy_hierarchy::DespawnRecursiveExt;
+use bevy_input::gamepad::{Gamepad, GamepadButton, GamepadButtonType};
+use bevy_input::keyboard::KeyCode;
+use bevy_render::color::Color;
+use bevy_ui::prelude::NodeBundle;
+use bevy_ui::{
+   AlignItems, FlexDirection, FocusPolicy, JustifyContent, PositionType, Style, Val, ZIndex,
+};
+use bevy_utils::synccell::SyncCell;
+
+use rxy_core::x_iter;
+
+use crate::event::{x_just_pressed, x_pointer_click, ElementEventIds};
+use crate::prelude::{button, div, CommonAttrsElementViewBuilder, ElementViewCompositeAttrs};
+use crate::renderer::event::ElementViewEventViewBuilder;
+use crate::{CmdSender, FocusScope, RxyViewSpawner};
+
+/// The dialogs are above the rest of the ui, a later dialog above the earlier ones.
+const DIALOG_Z_INDEX: i32 = i32::MAX - 64;
+
+/// Adds the [`Dialogs`] resource.
+pub struct RxyDialogPlugin;
+
+impl Plugin for RxyDialogPlugin {
+   fn build(&self, app: &mut App) {
+      app.init_resource::<Dialogs>();
+   }
+}
+
+/// Opens modal dialogs and resolves the choice of the user, so async code can wait for it.
+///
+/// The dialog traps the focus while it is open and the focus goes back to where it was once it closes.
+///
+/// ```ignore
+/// let dialogs = world.resource::<Dialogs>().clone();
+/// BevyRenderer::spawn_task(async move {
+///    if dialogs.confirm("Quit?", ["Yes", "No"]).await == Some(0) {
+///       quit_sender.send(()).await.unwrap();
+///    }
+/// })
+/// ```
+#[derive(Resource, Clone)]
+pub struct Dialogs {
+   cmd_sender: CmdSender,
+}
+
+impl FromWorld for Dialogs {
+   fn from_world(world: &mut World) -> Self {
+      Self {
+         cmd_sender: world.resource::<CmdSender>().clone(),
+      }
+   }
+}
+
+impl Dialogs {
+   /// Shows the message with a button per choice.
+   ///
+   /// Resolves to the index of the confirmed choice, or `None` when the dialog is dismissed with
+   /// escape or removed without a choice.
+   pub fn confirm<S>(
+      &self,
+      message: impl Into<String>,
+      choices: impl IntoIterator<Item = S>,
+   ) -> impl Future<Output = Option<usize>> + Send + 'static
+   where
+      S: Into<String>,
+   {
+      let message = message.into();
+      let choices = choices.into_iter().map(Into::into).collect::<Vec<String>>();
+      let (sender, receiver) = oneshot::channel();
+      self.cmd_sender.add(move |world: &mut World| {
+         open_confirm_dialog(world, message, choices, sender);
+      });
+      async move { receiver.await.ok().flatten() }
+   }
+}
+
+/// The modal layer of an open dialog, it blocks the pointer from the views below.
+#[derive(Component)]
+pub struct Dialog {
+   sender: SyncCell<oneshot::Sender<Option<usize>>>,
+}
+
+fn open_confirm_dialog(
+   world: &mut World,
+   message: String,
+   choices: Vec<String>,
+   sender: oneshot::Sender<Option<usize>>,
+) {
+   let open_dialogs = world.query::<&Dialog>().iter(world).count() as i32;
+   let layer = world
+      .spawn((
+         NodeBundle {
+            style: Style {
+               position_type: PositionType::Absolute,
+               width: Val::Percent(100.),
+               height: Val::Percent(100.),
+               justify_content: JustifyContent::Center,
+               align_items: AlignItems::Center,
+               ..Default::default()
+            },
+            background_color: Color::rgba(0., 0., 0., 0.5).into(),
+            focus_policy: FocusPolicy::Block,
+            z_index: ZIndex::Global(DIALOG_Z_INDEX + open_dialogs),
+            ..Default::default()
+         },
+         Dialog {
+            sender: SyncCell::new(sender),
+         },
+         // focuses the first choice and restores the focus when the dialog is closed
+         FocusScope::default(),
+      ))
+      .id();
+
+   let confirm = confirm_event_ids();
+   let dismiss = dismiss_event_ids();
+   let _ = world.spawn_view(
+      div()
+         .name("dialog")
+         .flex_direction(FlexDirection::Column)
+         .row_gap(16)
+         .padding(20)
+         .min_width(280)
+         .bg_color(Color::rgb(0.15, 0.15, 0.15))
+         .border(1)
+         .border_color(Color::rgb(0.3, 0.3, 0.3))
+         .children((
+            div()
+               .name("dialog-message")
+               .text_color(Color::WHITE)
+               .children(message),
+            div()
+               .name("dialog-choices")
+               .justify_content(JustifyContent::End)
+               .column_gap(8)
+               .children(x_iter(choices.into_iter().enumerate().map(
+                  move |(index, choice)| {
+                     button()
+                        .name("dialog-choice")
+                        .padding_horizontal(12)
+                        .padding_vertical(6)
+                        .bg_color(Color::rgb(0.25, 0.25, 0.25))
+                        .text_color(Color::WHITE)
+                        .on(confirm.clone(), move |world: &mut World| {
+                           close_dialog(world, layer, Some(index));
+                        })
+                        .on(dismiss.clone(), move |world: &mut World| {
+                           close_dialog(world, layer, None);
+                        })
+                        .children(choice)
+                  },
+               ))),
+         )),
+      move |_| layer,
+   );
+}
+
+/// Enter, the south button or a click confirm the focused choice.
+fn confirm_event_ids() -> impl ElementEventIds {
+   (
+      x_just_pressed(KeyCode::Enter),
+      x_just_pressed(GamepadButton::new(
+         Gamepad::new(0),
+         GamepadButtonType::South,
+      )),
+      x_pointer_click(),
+   )
+}
+
+/// Escape or the east button dismiss the dialog.
+fn dismiss_event_ids() -> impl ElementEventIds {
+   (
+      x_just_pressed(KeyCode::Escape),
+      x_just_pressed(GamepadButton::new(Gamepad::new(0), GamepadButtonType::East)),
+   )
+}
+
+fn close_dialog(world: &mut World, layer: Entity, choice: Option<usize>) {
+   let Some(mut layer) = world.get_entity_mut(layer) else {
+      return;
+   };
+   if let Some(dialog) = layer.take::<Dialog>() {
+      // the waiting future may have been dropped
+      let _ = SyncCell::to_inner(dialog.sender).send(choice);
+   }
+   layer.despawn_recursive();
+}
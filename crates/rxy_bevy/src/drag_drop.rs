@@ -0,0 +1,479 @@
+use core::any::Any;
+use core::marker::PhantomData;
+use std::sync::Arc;
+
+use bevy_app::{App, Plugin, Update};
+use bevy_core::Name;
+use bevy_ecs::prelude::{
+   Commands, Component, Entity, EventReader, IntoSystem, IntoSystemConfigs, Query, Res, ResMut,
+   Resource, World,
+};
+use bevy_ecs::system::SystemId;
+use bevy_hierarchy::{DespawnRecursiveExt, HierarchyQueryExt, Parent};
+use bevy_input::keyboard::KeyCode;
+use bevy_input::ButtonInput;
+use bevy_mod_picking::prelude::{Drag, DragEnd, DragStart, Pickable, Pointer, PointerButton};
+use bevy_render::color::Color;
+use bevy_render::view::ViewVisibility;
+use bevy_transform::prelude::GlobalTransform;
+use bevy_ui::prelude::{BackgroundColor, BorderColor, NodeBundle, UiImage};
+use bevy_ui::{FocusPolicy, Node, PositionType, Style, UiStack, Val, ZIndex};
+use bevy_utils::tracing::error;
+use glam::Vec2;
+
+use rxy_core::{IntoView, ViewMember, ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin};
+
+use crate::{BevyRenderer, ElementStates, RxyViewSpawner, ShapeStyle};
+
+/// Drags the views with a [`draggable`] member and drops their payload on the views with a [`drop_target`] member.
+pub struct RxyDragDropPlugin;
+
+impl Plugin for RxyDragDropPlugin {
+   fn build(&self, app: &mut App) {
+      app.init_resource::<DragState>().add_systems(
+         Update,
+         (start_drag, update_drag, end_drag, cancel_drag).chain(),
+      );
+   }
+}
+
+type DragPayload = Arc<dyn Any + Send + Sync>;
+
+type DragPreviewFn = Arc<dyn Fn(&mut World, Entity) + Send + Sync>;
+
+/// The drag source of a [`draggable`] view.
+#[derive(Component, Clone)]
+pub struct DragSource {
+   payload: DragPayload,
+   preview: Option<DragPreviewFn>,
+}
+
+/// The drop target of a [`drop_target`] view.
+#[derive(Component, Clone)]
+pub struct DropTarget {
+   accept: Arc<dyn Fn(&(dyn Any + Send + Sync)) -> bool + Send + Sync>,
+   on_drop: Arc<dyn Fn(&mut World, Entity, Entity, &(dyn Any + Send + Sync)) + Send + Sync>,
+}
+
+impl DropTarget {
+   pub fn accepts(&self, payload: &(dyn Any + Send + Sync)) -> bool {
+      (self.accept)(payload)
+   }
+}
+
+/// The input of the `on_drop` system of a [`drop_target`].
+#[derive(Clone, Debug)]
+pub struct DragDrop<T> {
+   pub source: Entity,
+   pub target: Entity,
+   pub payload: T,
+}
+
+struct ActiveDrag {
+   source: Entity,
+   payload: DragPayload,
+   ghost: Entity,
+   grab_offset: Vec2,
+   over: Option<Entity>,
+}
+
+/// The current drag, observe it with [`x_res`](crate::x_res) to react to the dragging.
+#[derive(Resource, Default)]
+pub struct DragState {
+   active: Option<ActiveDrag>,
+}
+
+impl DragState {
+   pub fn is_dragging(&self) -> bool {
+      self.active.is_some()
+   }
+
+   pub fn source(&self) -> Option<Entity> {
+      self.active.as_ref().map(|n| n.source)
+   }
+
+   /// The payload of the current drag, if it is a `T`.
+   pub fn payload<T: 'static>(&self) -> Option<&T> {
+      self.active.as_ref()?.payload.downcast_ref()
+   }
+
+   /// The drop target under the pointer that accepts the payload.
+   pub fn over(&self) -> Option<Entity> {
+      self.active.as_ref().and_then(|n| n.over)
+   }
+}
+
+/// Makes the view draggable with the primary button, carrying the payload to a [`drop_target`].
+///
+/// While dragging, a ghost of the view follows the pointer, or the [`XDraggable::preview`] view.
+/// <kbd>Esc</kbd> cancels the drag.
+pub fn draggable<T>(payload: T) -> XDraggable
+where
+   T: Send + Sync + 'static,
+{
+   XDraggable(DragSource {
+      payload: Arc::new(payload),
+      preview: None,
+   })
+}
+
+pub struct XDraggable(DragSource);
+
+impl XDraggable {
+   /// The view that follows the pointer while dragging.
+   pub fn preview<F, IV>(mut self, f: F) -> Self
+   where
+      F: Fn() -> IV + Send + Sync + 'static,
+      IV: IntoView<BevyRenderer>,
+   {
+      self.0.preview = Some(Arc::new(move |world: &mut World, parent: Entity| {
+         let _ = world.spawn_view(f(), move |_| parent);
+      }));
+      self
+   }
+}
+
+impl ViewMemberOrigin<BevyRenderer> for XDraggable {
+   type Origin = Self;
+}
+
+impl ViewMember<BevyRenderer> for XDraggable {
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(ctx: ViewMemberCtx<BevyRenderer>, view_removed: bool) {
+      if view_removed {
+         return;
+      }
+      ctx.world.entity_mut(ctx.node_id).remove::<DragSource>();
+   }
+
+   fn build(self, ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      ctx.world.entity_mut(ctx.node_id).insert(self.0);
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      self.build(ctx, true);
+   }
+}
+
+/// Accepts the dragged payloads of type `T` for which `accept` returns `true`,
+/// and runs the `on_drop` system with the [`DragDrop`] when one is dropped on the view.
+///
+/// While an accepted payload is over the view, the `drag-over` style sheets apply.
+pub fn drop_target<T, A, S, M>(accept: A, on_drop: S) -> XDropTarget<T, A, S, M>
+where
+   T: Clone + Send + Sync + 'static,
+   A: Fn(&T) -> bool + Send + Sync + 'static,
+   S: IntoSystem<DragDrop<T>, (), M> + Send + 'static,
+   M: Send + 'static,
+{
+   XDropTarget {
+      accept,
+      on_drop,
+      _marker: PhantomData,
+   }
+}
+
+pub struct XDropTarget<T, A, S, M> {
+   accept: A,
+   on_drop: S,
+   _marker: PhantomData<fn() -> (T, M)>,
+}
+
+struct DropTargetMemberState<T: 'static>(SystemId<DragDrop<T>>);
+
+impl<T, A, S, M> ViewMemberOrigin<BevyRenderer> for XDropTarget<T, A, S, M>
+where
+   T: Clone + Send + Sync + 'static,
+   A: Fn(&T) -> bool + Send + Sync + 'static,
+   S: IntoSystem<DragDrop<T>, (), M> + Send + 'static,
+   M: Send + 'static,
+{
+   type Origin = Self;
+}
+
+impl<T, A, S, M> ViewMember<BevyRenderer> for XDropTarget<T, A, S, M>
+where
+   T: Clone + Send + Sync + 'static,
+   A: Fn(&T) -> bool + Send + Sync + 'static,
+   S: IntoSystem<DragDrop<T>, (), M> + Send + 'static,
+   M: Send + 'static,
+{
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(mut ctx: ViewMemberCtx<BevyRenderer>, view_removed: bool) {
+      if let Some(DropTargetMemberState(system_id)) =
+         ctx.take_indexed_view_member_state::<DropTargetMemberState<T>>()
+      {
+         if let Err(err) = ctx.world.remove_system(system_id) {
+            error!("remove_system error: {:?}", err);
+         }
+      }
+      if !view_removed {
+         ctx.world.entity_mut(ctx.node_id).remove::<DropTarget>();
+      }
+   }
+
+   fn build(self, mut ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      let system_id = ctx.world.register_system(self.on_drop);
+      let accept = self.accept;
+      ctx.world.entity_mut(ctx.node_id).insert(DropTarget {
+         accept: Arc::new(move |payload: &(dyn Any + Send + Sync)| {
+            payload.downcast_ref::<T>().is_some_and(&accept)
+         }),
+         on_drop: Arc::new(
+            move |world: &mut World,
+                  source: Entity,
+                  target: Entity,
+                  payload: &(dyn Any + Send + Sync)| {
+               let Some(payload) = payload.downcast_ref::<T>() else {
+                  return;
+               };
+               let drag_drop = DragDrop {
+                  source,
+                  target,
+                  payload: payload.clone(),
+               };
+               if let Err(err) = world.run_system_with_input(system_id, drag_drop) {
+                  error!("run_system_with_input error: {:?}", err);
+               }
+            },
+         ),
+      });
+      ctx.set_indexed_view_member_state(DropTargetMemberState(system_id));
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      Self::unbuild(
+         ViewMemberCtx {
+            index: ctx.index,
+            world: &mut *ctx.world,
+            node_id: ctx.node_id,
+         },
+         false,
+      );
+      self.build(ctx, true);
+   }
+}
+
+fn node_min_and_size(node: &Node, transform: &GlobalTransform) -> (Vec2, Vec2) {
+   let size = node.size();
+   (transform.translation().truncate() - size / 2., size)
+}
+
+fn set_drag_over(
+   commands: &mut Commands,
+   states: &mut Query<&mut ElementStates>,
+   entity: Entity,
+   drag_over: bool,
+) {
+   match states.get_mut(entity) {
+      Ok(mut states) => {
+         if states.drag_over != drag_over {
+            states.drag_over = drag_over;
+         }
+      }
+      Err(_) if drag_over => {
+         if let Some(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.insert(ElementStates {
+               drag_over,
+               ..Default::default()
+            });
+         }
+      }
+      Err(_) => {}
+   }
+}
+
+fn start_drag(
+   mut commands: Commands,
+   mut events: EventReader<Pointer<DragStart>>,
+   mut drag_state: ResMut<DragState>,
+   sources: Query<(
+      &DragSource,
+      &Node,
+      &GlobalTransform,
+      &Style,
+      Option<&BackgroundColor>,
+      Option<&BorderColor>,
+      Option<&UiImage>,
+      Option<&ShapeStyle>,
+   )>,
+   parents: Query<&Parent>,
+) {
+   for event in events.read() {
+      if event.button != PointerButton::Primary || drag_state.is_dragging() {
+         continue;
+      }
+      let Some(source) = Some(event.target)
+         .into_iter()
+         .chain(parents.iter_ancestors(event.target))
+         .find(|n| sources.contains(*n))
+      else {
+         continue;
+      };
+      let (drag_source, node, transform, style, background, border_color, image, shape) =
+         sources.get(source).unwrap();
+      let (min, size) = node_min_and_size(node, transform);
+      let pointer_position = event.pointer_location.position;
+      let grab_offset = pointer_position - min;
+
+      let ghost = commands
+         .spawn((
+            NodeBundle {
+               style: Style {
+                  position_type: PositionType::Absolute,
+                  left: Val::Px(min.x),
+                  top: Val::Px(min.y),
+                  width: Val::Px(size.x),
+                  height: Val::Px(size.y),
+                  border: style.border,
+                  ..Default::default()
+               },
+               focus_policy: FocusPolicy::Pass,
+               z_index: ZIndex::Global(i32::MAX),
+               ..Default::default()
+            },
+            Pickable::IGNORE,
+            Name::new("[Drag Preview]"),
+         ))
+         .id();
+      match drag_source.preview.clone() {
+         Some(preview) => {
+            commands.add(move |world: &mut World| preview(world, ghost));
+         }
+         None => {
+            let mut ghost = commands.entity(ghost);
+            // a translucent copy of the node
+            let translucent = |color: Color| color.with_a(color.a() * 0.6);
+            if let Some(background) = background {
+               ghost.insert(BackgroundColor(translucent(background.0)));
+            }
+            if let Some(border_color) = border_color {
+               ghost.insert(BorderColor(translucent(border_color.0)));
+            }
+            // the image is tinted by the background color
+            if let Some(image) = image {
+               ghost.insert(image.clone());
+            }
+            if let Some(shape) = shape {
+               ghost.insert(*shape);
+            }
+         }
+      }
+
+      drag_state.active = Some(ActiveDrag {
+         source,
+         payload: drag_source.payload.clone(),
+         ghost,
+         grab_offset,
+         over: None,
+      });
+   }
+}
+
+fn update_drag(
+   mut commands: Commands,
+   mut events: EventReader<Pointer<Drag>>,
+   mut drag_state: ResMut<DragState>,
+   mut styles: Query<&mut Style>,
+   mut states: Query<&mut ElementStates>,
+   targets: Query<(&DropTarget, &Node, &GlobalTransform, &ViewVisibility)>,
+   ui_stack: Res<UiStack>,
+) {
+   let Some(active) = drag_state.active.as_mut() else {
+      events.clear();
+      return;
+   };
+   let Some(pointer_position) = events
+      .read()
+      .filter(|event| event.button == PointerButton::Primary)
+      .last()
+      .map(|event| event.pointer_location.position)
+   else {
+      return;
+   };
+
+   if let Ok(mut style) = styles.get_mut(active.ghost) {
+      let position = pointer_position - active.grab_offset;
+      style.left = Val::Px(position.x);
+      style.top = Val::Px(position.y);
+   }
+
+   // the topmost drop target under the pointer that accepts the payload
+   let over = ui_stack.uinodes.iter().rev().copied().find(|entity| {
+      *entity != active.source
+         && targets
+            .get(*entity)
+            .is_ok_and(|(target, node, transform, visibility)| {
+               let (min, size) = node_min_and_size(node, transform);
+               let max = min + size;
+               visibility.get()
+                  && (min.x..max.x).contains(&pointer_position.x)
+                  && (min.y..max.y).contains(&pointer_position.y)
+                  && target.accepts(&*active.payload)
+            })
+   });
+   if over != active.over {
+      if let Some(entity) = active.over {
+         set_drag_over(&mut commands, &mut states, entity, false);
+      }
+      if let Some(entity) = over {
+         set_drag_over(&mut commands, &mut states, entity, true);
+      }
+      active.over = over;
+   }
+}
+
+fn end_drag(
+   mut commands: Commands,
+   mut events: EventReader<Pointer<DragEnd>>,
+   mut drag_state: ResMut<DragState>,
+   mut states: Query<&mut ElementStates>,
+   targets: Query<&DropTarget>,
+) {
+   if !events
+      .read()
+      .any(|event| event.button == PointerButton::Primary)
+   {
+      return;
+   }
+   let Some(active) = drag_state.active.take() else {
+      return;
+   };
+   if let Some(ghost) = commands.get_entity(active.ghost) {
+      ghost.despawn_recursive();
+   }
+   let Some(target) = active.over else {
+      return;
+   };
+   set_drag_over(&mut commands, &mut states, target, false);
+   if let Ok(drop_target) = targets.get(target) {
+      let on_drop = drop_target.on_drop.clone();
+      commands.add(move |world: &mut World| {
+         on_drop(world, active.source, target, &*active.payload);
+      });
+   }
+}
+
+fn cancel_drag(
+   mut commands: Commands,
+   keyboard_input: Res<ButtonInput<KeyCode>>,
+   mut drag_state: ResMut<DragState>,
+   mut states: Query<&mut ElementStates>,
+) {
+   if !drag_state.is_dragging() || !keyboard_input.just_pressed(KeyCode::Escape) {
+      return;
+   }
+   let active = drag_state.active.take().unwrap();
+   if let Some(ghost) = commands.get_entity(active.ghost) {
+      ghost.despawn_recursive();
+   }
+   if let Some(target) = active.over {
+      set_drag_over(&mut commands, &mut states, target, false);
+   }
+}
@@ -1,58 +1,137 @@
 use bevy_ecs::prelude::Component;
+use smallvec::SmallVec;
 
 use rxy_core::{AttrIndex, ElementTypeUnTyped};
 
 use crate::BevyRenderer;
 
-pub type AttrSetBits = u128;
-pub type AttrInitBits = u128;
+/// A growable bitset of attr indexes, so the number of attrs is not limited.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct AttrBits(SmallVec<[u64; 2]>);
+
+impl AttrBits {
+   const BITS: usize = u64::BITS as usize;
+
+   #[inline]
+   fn position(attr_index: AttrIndex) -> (usize, u64) {
+      let attr_index = attr_index as usize;
+      (attr_index / Self::BITS, 1 << (attr_index % Self::BITS))
+   }
+
+   pub fn contains(&self, attr_index: AttrIndex) -> bool {
+      let (block, mask) = Self::position(attr_index);
+      self.0.get(block).is_some_and(|n| n & mask != 0)
+   }
+
+   /// Returns `false` if the attr index is already in the set.
+   pub fn insert(&mut self, attr_index: AttrIndex) -> bool {
+      let (block, mask) = Self::position(attr_index);
+      if block >= self.0.len() {
+         self.0.resize(block + 1, 0);
+      }
+      let is_new = self.0[block] & mask == 0;
+      self.0[block] |= mask;
+      is_new
+   }
+
+   pub fn remove(&mut self, attr_index: AttrIndex) {
+      let (block, mask) = Self::position(attr_index);
+      if let Some(n) = self.0.get_mut(block) {
+         *n &= !mask;
+      }
+   }
+
+   pub fn set(&mut self, attr_index: AttrIndex, value: bool) {
+      if value {
+         self.insert(attr_index);
+      } else {
+         self.remove(attr_index);
+      }
+   }
+
+   pub fn clear(&mut self) {
+      self.0.clear();
+   }
+
+   pub fn is_empty(&self) -> bool {
+      self.0.iter().all(|n| *n == 0)
+   }
+
+   pub fn iter(&self) -> impl Iterator<Item = AttrIndex> + '_ {
+      self.0.iter().enumerate().flat_map(|(block, bits)| {
+         (0..Self::BITS)
+            .filter(move |n| bits & (1 << n) != 0)
+            .map(move |n| (block * Self::BITS + n) as AttrIndex)
+      })
+   }
+}
+
+pub type AttrSetBits = AttrBits;
+pub type AttrInitBits = AttrBits;
 
 #[derive(Component, Clone)]
 pub struct ElementEntityExtraData {
    pub element_type: &'static dyn ElementTypeUnTyped<BevyRenderer>,
    pub attr_is_set: AttrSetBits,
-   pub attr_is_init: AttrSetBits,
+   pub attr_is_init: AttrInitBits,
 }
 
 impl ElementEntityExtraData {
    pub fn new(element_type: &'static dyn ElementTypeUnTyped<BevyRenderer>) -> Self {
       Self {
          element_type,
-         attr_is_set: 0,
-         attr_is_init: 0,
+         attr_is_set: AttrSetBits::default(),
+         attr_is_init: AttrInitBits::default(),
       }
    }
 
    pub fn set_attr(&mut self, attr_index: AttrIndex, is_set: bool) {
-      if attr_index == 0 {
-         return;
-      }
-      if is_set {
-         self.attr_is_set |= 1 << attr_index;
-      } else {
-         self.attr_is_set &= !(1 << attr_index);
-      }
+      self.attr_is_set.set(attr_index, is_set);
    }
+
    pub fn init_attr(&mut self, attr_index: AttrIndex, is_init: bool) {
-      if attr_index == 0 {
-         return;
-      }
-      if is_init {
-         self.attr_is_init |= 1 << attr_index;
-      } else {
-         self.attr_is_init &= !(1 << attr_index);
-      }
+      self.attr_is_init.set(attr_index, is_init);
    }
 
    pub fn is_set_attr(&self, attr_index: AttrIndex) -> bool {
-      Self::static_is_set_attr(self.attr_is_set, attr_index)
+      self.attr_is_set.contains(attr_index)
    }
 
    pub fn is_init_attr(&self, attr_index: AttrIndex) -> bool {
-      (self.attr_is_init >> attr_index) & 1 == 1
+      self.attr_is_init.contains(attr_index)
+   }
+
+   pub fn static_is_set_attr(attr_is_set: &AttrSetBits, attr_index: AttrIndex) -> bool {
+      attr_is_set.contains(attr_index)
    }
+}
+
+/// The states of an element, set by the `disabled`, `checked` and `selected` attrs.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ElementStates {
+   pub disabled: bool,
+   pub checked: bool,
+   pub selected: bool,
+   /// Set by the drag and drop while an accepted payload is dragged over a drop target.
+   pub drag_over: bool,
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
 
-   pub fn static_is_set_attr(attr_is_set: AttrSetBits, attr_index: AttrIndex) -> bool {
-      (attr_is_set >> attr_index) & 1 == 1
+   #[test]
+   fn attr_bits() {
+      let mut bits = AttrBits::default();
+      assert!(bits.insert(0));
+      assert!(bits.insert(300));
+      assert!(!bits.insert(300));
+      assert!(bits.contains(0) && bits.contains(300) && !bits.contains(64));
+      assert_eq!(bits.iter().collect::<Vec<_>>(), vec![0, 300]);
+      bits.remove(300);
+      bits.remove(1000);
+      assert!(!bits.contains(300));
+      bits.set(0, false);
+      assert!(bits.is_empty());
    }
 }
@@ -151,23 +151,33 @@ fn add_bubble_event<T>(
          world.node_state_scoped(
             &node_id,
             |world, system_ids: &mut BubbleEventSystemIds<T>| {
-               for (system_id, data) in system_ids.iter() {
-                  if let Some(data) = data {
-                     if !event_data.is_match(data) {
-                        return;
-                     }
-                  }
-                  let err = world.run_system(*system_id);
-                  if let Err(err) = err {
-                     error!("run system error: {:?}", err);
-                  }
-               }
+               run_matching_systems(world, &event_data, &system_ids.0);
             },
          );
       }));
    }
 }
 
+/// Runs the systems of the listeners whose data matches the event, the listeners without data
+/// always run.
+fn run_matching_systems<T>(
+   world: &mut World,
+   event_data: &T,
+   system_ids: &[(SystemId, Option<T::Data>)],
+) where
+   T: EventIsMatch,
+{
+   for (system_id, data) in system_ids {
+      if data.as_ref().is_some_and(|data| !event_data.is_match(data)) {
+         continue;
+      }
+      let err = world.run_system(*system_id);
+      if let Err(err) = err {
+         error!("run system error: {:?}", err);
+      }
+   }
+}
+
 pub trait FocusEventWorldExt {
    fn add_focus_event(
       &mut self,
@@ -754,3 +764,40 @@ pub fn x_pointer_drag_leave() -> BubblePointerEvent {
 pub fn x_pointer_drop() -> BubblePointerEvent {
    BubblePointerEvent::Drop(None)
 }
+
+#[cfg(test)]
+mod tests {
+   use bevy_ecs::prelude::ResMut;
+
+   use super::*;
+
+   struct Pressed(u8);
+
+   impl EventIsMatch for Pressed {
+      type Data = u8;
+
+      fn is_match(&self, other: &Self::Data) -> bool {
+         self.0 == *other
+      }
+   }
+
+   #[derive(Resource, Default)]
+   struct Runs(Vec<&'static str>);
+
+   #[test]
+   fn non_matching_listener_does_not_skip_the_next_ones() {
+      let mut world = World::new();
+      world.init_resource::<Runs>();
+      let first = world.register_system(|mut runs: ResMut<Runs>| runs.0.push("first"));
+      let second = world.register_system(|mut runs: ResMut<Runs>| runs.0.push("second"));
+      let any = world.register_system(|mut runs: ResMut<Runs>| runs.0.push("any"));
+
+      run_matching_systems(
+         &mut world,
+         &Pressed(2),
+         &[(first, Some(1)), (second, Some(2)), (any, None)],
+      );
+
+      assert_eq!(world.resource::<Runs>().0, vec!["second", "any"]);
+   }
+}
@@ -1,23 +1,36 @@
 use bevy_app::{Plugin, PostUpdate, PreUpdate};
 use bevy_ecs::prelude::{
-   DetectChangesMut, Entity, Local, Query, RemovedComponents, Res, ResMut, Resource,
+   Added, Changed, DetectChangesMut, Entity, Has, Local, Query, RemovedComponents, Res, ResMut,
+   Resource, With,
 };
 use bevy_ecs::{
    component::Component, prelude::resource_changed, schedule::IntoSystemConfigs,
    system::SystemParam,
 };
+use bevy_hierarchy::{Children, HierarchyQueryExt, Parent};
 use bevy_reflect::Reflect;
 use bevy_ui::UiSystem;
 
+use crate::ElementStates;
+
 #[derive(Resource, Default, Debug, Copy, Clone, PartialEq, Eq)]
 pub struct FocusedEntity(pub Option<Entity>);
 
+/// Whether the focus of [`FocusedEntity`] has been reached through keyboard navigation,
+/// similar to the `:focus-visible` css pseudo-class.
+#[derive(Resource, Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FocusVisible(pub bool);
+
 pub struct FocusablePlugin;
 
 impl Plugin for FocusablePlugin {
    fn build(&self, app: &mut bevy_app::App) {
       app.register_type::<Focusable>()
+         .register_type::<FocusScope>()
+         .register_type::<InitialFocus>()
          .init_resource::<FocusedEntity>()
+         .init_resource::<FocusVisible>()
+         .init_resource::<FocusScopes>()
          .add_systems(
             PreUpdate,
             FocusableSystemParam::update_focused_state
@@ -27,8 +40,35 @@ impl Plugin for FocusablePlugin {
          )
          .add_systems(
             PostUpdate,
-            check_focus.run_if(|removed: RemovedComponents<Focusable>| !removed.is_empty()),
+            (
+               check_focus.run_if(|removed: RemovedComponents<Focusable>| !removed.is_empty()),
+               blur_disabled,
+               exit_focus_scopes
+                  .run_if(|removed: RemovedComponents<FocusScope>| !removed.is_empty()),
+               enter_focus_scopes.after(exit_focus_scopes),
+            ),
          );
+
+      #[cfg(feature = "xy_reactive")]
+      app.add_systems(
+         PreUpdate,
+         crate::update_focus_signals
+            .after(UiSystem::Focus)
+            .run_if(resource_changed::<FocusedEntity>),
+      );
+   }
+}
+
+/// A focused entity loses the focus when it gets disabled.
+pub(crate) fn blur_disabled(
+   mut focus: ResMut<FocusedEntity>,
+   states: Query<&ElementStates, Changed<ElementStates>>,
+) {
+   let Some(entity) = focus.0 else {
+      return;
+   };
+   if states.get(entity).is_ok_and(|n| n.disabled) {
+      focus.0 = None;
    }
 }
 
@@ -40,6 +80,121 @@ fn check_focus(mut focus: ResMut<FocusedEntity>, mut removed: RemovedComponents<
    }
 }
 
+/// Marks a container as a focus scope, see [`x_focus_scope`](crate::x_focus_scope).
+///
+/// The settings are read when the scope is added.
+#[derive(Reflect, Component, Clone, Debug, Eq, PartialEq)]
+pub struct FocusScope {
+   /// Tab and directional navigation only move within the scope, like in a modal dialog.
+   pub trap: bool,
+   /// Focuses the [`InitialFocus`] descendant, or the first focusable descendant, when the scope is added.
+   pub autofocus: bool,
+   /// Focuses the previously focused entity again when the scope is removed.
+   pub restore_focus: bool,
+}
+
+impl Default for FocusScope {
+   fn default() -> Self {
+      Self {
+         trap: true,
+         autofocus: true,
+         restore_focus: true,
+      }
+   }
+}
+
+/// The descendant of a [`FocusScope`] that gets the focus when the scope is added.
+#[derive(Reflect, Component, Clone, Debug, Default, Eq, PartialEq)]
+pub struct InitialFocus;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct FocusScopeEntry {
+   scope: Entity,
+   previous_focus: Option<Entity>,
+   trap: bool,
+   restore_focus: bool,
+}
+
+/// The stack of the added [`FocusScope`]s, the last one being the innermost.
+#[derive(Resource, Default, Debug)]
+pub struct FocusScopes(Vec<FocusScopeEntry>);
+
+impl FocusScopes {
+   /// The last added scope that traps the focus.
+   pub fn trap(&self) -> Option<Entity> {
+      self.0.iter().rev().find(|n| n.trap).map(|n| n.scope)
+   }
+
+   /// Whether the entity can get the focus with the current trap.
+   pub fn allows(&self, entity: Entity, parents: &Query<&Parent>) -> bool {
+      match self.trap() {
+         None => true,
+         Some(scope) => entity == scope || parents.iter_ancestors(entity).any(|n| n == scope),
+      }
+   }
+}
+
+fn enter_focus_scopes(
+   mut focus_scopes: ResMut<FocusScopes>,
+   mut focus: ResMut<FocusedEntity>,
+   added_scopes: Query<(Entity, &FocusScope), Added<FocusScope>>,
+   children: Query<&Children>,
+   parents: Query<&Parent>,
+   focusables: Query<Has<InitialFocus>, With<Focusable>>,
+) {
+   for (entity, scope) in added_scopes.iter() {
+      focus_scopes.0.push(FocusScopeEntry {
+         scope: entity,
+         previous_focus: focus.0,
+         trap: scope.trap,
+         restore_focus: scope.restore_focus,
+      });
+      // e.g. focused by the `autofocus` attr
+      let focus_inside = focus
+         .0
+         .is_some_and(|focus| parents.iter_ancestors(focus).any(|n| n == entity));
+      if !scope.autofocus || focus_inside {
+         continue;
+      }
+      let descendants = || {
+         children
+            .iter_descendants(entity)
+            .filter_map(|n| focusables.get(n).ok().map(|initial| (n, initial)))
+      };
+      let initial_focus = descendants()
+         .find(|(_, initial)| *initial)
+         .or_else(|| descendants().next());
+      if let Some((initial_focus, _)) = initial_focus {
+         focus.set_if_neq(FocusedEntity(Some(initial_focus)));
+      }
+   }
+}
+
+fn exit_focus_scopes(
+   mut focus_scopes: ResMut<FocusScopes>,
+   mut focus: ResMut<FocusedEntity>,
+   mut removed: RemovedComponents<FocusScope>,
+   focusables: Query<(), With<Focusable>>,
+) {
+   for entity in removed.read() {
+      let Some(index) = focus_scopes.0.iter().position(|n| n.scope == entity) else {
+         continue;
+      };
+      let entry = focus_scopes.0.remove(index);
+      if let Some(next) = focus_scopes.0.get_mut(index) {
+         // an inner scope is still there, it restores the focus from before this scope
+         next.previous_focus = entry.previous_focus;
+         continue;
+      }
+      if entry.restore_focus {
+         let previous_focus = entry
+            .previous_focus
+            .filter(|entity| focusables.contains(*entity));
+         focus.set_if_neq(FocusedEntity(previous_focus));
+      }
+   }
+}
+
 #[derive(SystemParam)]
 struct FocusableSystemParam<'w, 's> {
    query: Query<'w, 's, &'static mut Focusable>,
@@ -115,3 +270,123 @@ pub enum FocusState {
        // visible: bool,
    } */
 }
+
+#[cfg(test)]
+mod tests {
+   use bevy_ecs::schedule::Schedule;
+   use bevy_ecs::system::RunSystemOnce;
+   use bevy_ecs::world::Mut;
+   use bevy_ecs::world::World;
+   use bevy_hierarchy::BuildWorldChildren;
+
+   use super::*;
+
+   fn focus_world() -> (World, Entity) {
+      let mut world = World::new();
+      let mut schedule = Schedule::default();
+      schedule.add_systems((exit_focus_scopes, enter_focus_scopes).chain());
+      world.insert_resource(Scopes(schedule));
+      world.init_resource::<FocusedEntity>();
+      world.init_resource::<FocusScopes>();
+      let outside = world.spawn(Focusable::default()).id();
+      world.resource_mut::<FocusedEntity>().0 = Some(outside);
+      (world, outside)
+   }
+
+   // the systems keep their last run tick, `Added` only matches the new scopes
+   #[derive(Resource)]
+   struct Scopes(Schedule);
+
+   fn update_scopes(world: &mut World) {
+      world.resource_scope(|world, mut scopes: Mut<Scopes>| scopes.0.run(world));
+      world.clear_trackers();
+   }
+
+   fn allows(world: &mut World, entity: Entity) -> bool {
+      world.run_system_once(move |scopes: Res<FocusScopes>, parents: Query<&Parent>| {
+         scopes.allows(entity, &parents)
+      })
+   }
+
+   #[test]
+   fn scope_traps_and_focuses_the_initial_focus() {
+      let (mut world, outside) = focus_world();
+      let scope = world.spawn(FocusScope::default()).id();
+      let first = world.spawn(Focusable::default()).set_parent(scope).id();
+      let initial = world
+         .spawn((Focusable::default(), InitialFocus))
+         .set_parent(scope)
+         .id();
+      update_scopes(&mut world);
+
+      assert_eq!(world.resource::<FocusedEntity>().0, Some(initial));
+      assert_eq!(world.resource::<FocusScopes>().trap(), Some(scope));
+      assert!(allows(&mut world, first));
+      assert!(!allows(&mut world, outside));
+   }
+
+   #[test]
+   fn untrapped_scope_allows_everything() {
+      let (mut world, outside) = focus_world();
+      world.spawn(FocusScope {
+         trap: false,
+         autofocus: false,
+         restore_focus: true,
+      });
+      update_scopes(&mut world);
+
+      assert_eq!(world.resource::<FocusedEntity>().0, Some(outside));
+      assert_eq!(world.resource::<FocusScopes>().trap(), None);
+      assert!(allows(&mut world, outside));
+   }
+
+   #[test]
+   fn removed_scope_restores_the_previous_focus() {
+      let (mut world, outside) = focus_world();
+      let scope = world.spawn(FocusScope::default()).id();
+      world.spawn(Focusable::default()).set_parent(scope);
+      update_scopes(&mut world);
+      assert_ne!(world.resource::<FocusedEntity>().0, Some(outside));
+
+      world.despawn(scope);
+      update_scopes(&mut world);
+
+      assert_eq!(world.resource::<FocusedEntity>().0, Some(outside));
+      assert_eq!(world.resource::<FocusScopes>().trap(), None);
+   }
+
+   #[test]
+   fn nested_scopes_restore_the_focus_from_before_the_outer_scope() {
+      let (mut world, outside) = focus_world();
+      let outer = world.spawn(FocusScope::default()).id();
+      world.spawn(Focusable::default()).set_parent(outer);
+      update_scopes(&mut world);
+      let inner = world.spawn(FocusScope::default()).id();
+      let inner_child = world.spawn(Focusable::default()).set_parent(inner).id();
+      update_scopes(&mut world);
+      assert_eq!(world.resource::<FocusScopes>().trap(), Some(inner));
+
+      // the outer scope goes away first, the inner one takes over its previous focus
+      world.despawn(outer);
+      update_scopes(&mut world);
+      assert_eq!(world.resource::<FocusedEntity>().0, Some(inner_child));
+
+      world.despawn(inner);
+      update_scopes(&mut world);
+      assert_eq!(world.resource::<FocusedEntity>().0, Some(outside));
+   }
+
+   #[test]
+   fn despawned_previous_focus_is_not_restored() {
+      let (mut world, outside) = focus_world();
+      let scope = world.spawn(FocusScope::default()).id();
+      world.spawn(Focusable::default()).set_parent(scope);
+      update_scopes(&mut world);
+
+      world.despawn(outside);
+      world.despawn(scope);
+      update_scopes(&mut world);
+
+      assert_eq!(world.resource::<FocusedEntity>().0, None);
+   }
+}
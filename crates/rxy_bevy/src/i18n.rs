@@ -0,0 +1,624 @@
+use core::fmt::{Display, Formatter, Write};
+use std::borrow::Cow;
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_asset::io::Reader;
+use bevy_asset::{
+   Asset, AssetApp, AssetEvent, AssetLoader, AssetServer, Assets, AsyncReadExt, Handle, LoadContext,
+};
+use bevy_ecs::prelude::{on_event, EventReader, IntoSystemConfigs, Res, ResMut, Resource};
+use bevy_reflect::TypePath;
+use bevy_utils::{BoxedFuture, HashMap};
+
+/// Loads the `.ftl` message bundles of the [`Locale`].
+pub struct RxyI18nPlugin;
+
+impl Plugin for RxyI18nPlugin {
+   fn build(&self, app: &mut App) {
+      app.init_asset::<LocaleBundle>()
+         .init_asset_loader::<LocaleBundleLoader>()
+         .init_resource::<Locale>()
+         .add_systems(
+            PreUpdate,
+            update_locale_bundles.run_if(on_event::<AssetEvent<LocaleBundle>>()),
+         );
+   }
+}
+
+/// An argument of a message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LocaleValue {
+   String(Cow<'static, str>),
+   Number(f64),
+}
+
+impl Display for LocaleValue {
+   fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+      match self {
+         LocaleValue::String(value) => f.write_str(value),
+         LocaleValue::Number(value) => write!(f, "{value}"),
+      }
+   }
+}
+
+impl From<&'static str> for LocaleValue {
+   fn from(value: &'static str) -> Self {
+      LocaleValue::String(value.into())
+   }
+}
+
+impl From<String> for LocaleValue {
+   fn from(value: String) -> Self {
+      LocaleValue::String(value.into())
+   }
+}
+
+impl From<Cow<'static, str>> for LocaleValue {
+   fn from(value: Cow<'static, str>) -> Self {
+      LocaleValue::String(value)
+   }
+}
+
+macro_rules! impl_locale_number_values {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for LocaleValue {
+                fn from(value: $ty) -> Self {
+                    LocaleValue::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_locale_number_values!(i8, i16, i32, i64, u8, u16, u32, u64, usize, isize, f32, f64);
+
+pub type LocaleArgs = Vec<(Cow<'static, str>, LocaleValue)>;
+
+#[derive(Clone, Debug, PartialEq)]
+enum PatternElement {
+   Text(String),
+   Var(String),
+   Select {
+      var: String,
+      variants: Vec<(String, Pattern)>,
+      default: usize,
+   },
+}
+
+type Pattern = Vec<PatternElement>;
+
+/// Messages in a subset of the [Fluent](https://projectfluent.org) syntax:
+///
+/// ```ftl
+/// # comment
+/// hello = Hello, { $name }!
+/// items = { $count ->
+///     [0] You have no items
+///     [one] You have one item
+///    *[other] You have { $count } items
+/// }
+/// ```
+///
+/// The variant keys are numbers or the plural categories of the language.
+#[derive(Asset, TypePath, Clone, Debug, Default, PartialEq)]
+pub struct LocaleBundle {
+   messages: HashMap<String, Pattern>,
+}
+
+impl LocaleBundle {
+   pub fn parse(source: &str) -> Result<Self, LocaleBundleError> {
+      let mut bundle = LocaleBundle::default();
+      let mut lines = source.lines().enumerate().peekable();
+      while let Some((line_index, line)) = lines.next() {
+         if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+         }
+         let parse_error = |message: &str| LocaleBundleError::Parse {
+            line: line_index + 1,
+            message: message.to_string(),
+         };
+         if line.starts_with(char::is_whitespace) {
+            return Err(parse_error("unexpected indentation"));
+         }
+         let Some((key, value)) = line.split_once('=') else {
+            return Err(parse_error("expected `key = value`"));
+         };
+         let key = key.trim();
+         if key.is_empty()
+            || !key
+               .chars()
+               .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+         {
+            return Err(parse_error("invalid message key"));
+         }
+
+         // the indented lines and closing braces continue the message
+         let mut source = value.trim_start().to_string();
+         while let Some((_, line)) = lines
+            .next_if(|(_, line)| line.starts_with(char::is_whitespace) || line.starts_with('}'))
+         {
+            source.push('\n');
+            source.push_str(line.trim());
+         }
+
+         let mut parser = PatternParser {
+            chars: source.chars().collect(),
+            pos: 0,
+         };
+         let pattern = parser.parse_pattern(false).map_err(parse_error)?;
+         if parser.pos < parser.chars.len() {
+            return Err(parse_error("unexpected `}`"));
+         }
+         bundle.messages.insert(key.to_string(), pattern);
+      }
+      Ok(bundle)
+   }
+
+   pub fn contains(&self, key: &str) -> bool {
+      self.messages.contains_key(key)
+   }
+
+   fn format(
+      &self,
+      language: &str,
+      key: &str,
+      args: &[(Cow<'static, str>, LocaleValue)],
+   ) -> Option<String> {
+      let pattern = self.messages.get(key)?;
+      let mut result = String::new();
+      format_pattern(&mut result, pattern, language, args);
+      Some(result)
+   }
+}
+
+fn format_pattern(
+   result: &mut String,
+   pattern: &Pattern,
+   language: &str,
+   args: &[(Cow<'static, str>, LocaleValue)],
+) {
+   let get_arg = |name: &str| args.iter().find(|n| n.0 == name).map(|n| &n.1);
+   for element in pattern {
+      match element {
+         PatternElement::Text(text) => result.push_str(text),
+         PatternElement::Var(name) => match get_arg(name) {
+            Some(value) => {
+               let _ = write!(result, "{value}");
+            }
+            None => {
+               let _ = write!(result, "{{${name}}}");
+            }
+         },
+         PatternElement::Select {
+            var,
+            variants,
+            default,
+         } => {
+            let value = get_arg(var);
+            let index = variants
+               .iter()
+               .position(|(key, _)| match value {
+                  Some(LocaleValue::Number(n)) => {
+                     key.parse::<f64>().ok() == Some(*n) || key == plural_category(language, *n)
+                  }
+                  Some(LocaleValue::String(s)) => key == s,
+                  None => false,
+               })
+               .unwrap_or(*default);
+            format_pattern(result, &variants[index].1, language, args);
+         }
+      }
+   }
+}
+
+/// The cardinal plural category of a number, for the common languages.
+pub fn plural_category(language: &str, n: f64) -> &'static str {
+   let language = language.split(['-', '_']).next().unwrap_or(language);
+   let is_integer = n.fract() == 0.;
+   match language {
+      "ja" | "zh" | "ko" | "vi" | "th" | "id" => "other",
+      "fr" | "pt" => {
+         if (0. ..2.).contains(&n) {
+            "one"
+         } else {
+            "other"
+         }
+      }
+      "ru" | "uk" | "be" | "pl" | "cs" | "sk" if is_integer => {
+         let (n10, n100) = (n as i64 % 10, n as i64 % 100);
+         match language {
+            "cs" | "sk" => match n as i64 {
+               1 => "one",
+               2..=4 => "few",
+               _ => "other",
+            },
+            _ if n10 == 1 && n100 != 11 && language != "pl" => "one",
+            "pl" if n == 1. => "one",
+            _ if (2..=4).contains(&n10) && !(12..=14).contains(&n100) => "few",
+            _ => "many",
+         }
+      }
+      "ru" | "uk" | "be" | "pl" | "cs" | "sk" => "other",
+      _ => {
+         if n == 1. {
+            "one"
+         } else {
+            "other"
+         }
+      }
+   }
+}
+
+struct PatternParser {
+   chars: Vec<char>,
+   pos: usize,
+}
+
+impl PatternParser {
+   fn peek(&self) -> Option<char> {
+      self.chars.get(self.pos).cloned()
+   }
+
+   fn skip_whitespace(&mut self) {
+      while self.peek().is_some_and(char::is_whitespace) {
+         self.pos += 1;
+      }
+   }
+
+   fn expect(&mut self, c: char, message: &'static str) -> Result<(), &'static str> {
+      if self.peek() != Some(c) {
+         return Err(message);
+      }
+      self.pos += 1;
+      Ok(())
+   }
+
+   fn parse_identifier(&mut self) -> Result<String, &'static str> {
+      let start = self.pos;
+      while self
+         .peek()
+         .is_some_and(|c| c.is_alphanumeric() || c == '-' || c == '_')
+      {
+         self.pos += 1;
+      }
+      if start == self.pos {
+         return Err("expected an identifier");
+      }
+      Ok(self.chars[start..self.pos].iter().collect())
+   }
+
+   /// Parses until the end, or until the end of the variant in a select expression.
+   fn parse_pattern(&mut self, in_variant: bool) -> Result<Pattern, &'static str> {
+      let mut pattern = vec![];
+      let mut text = String::new();
+      while let Some(c) = self.peek() {
+         match c {
+            '{' => {
+               self.pos += 1;
+               if !text.is_empty() {
+                  pattern.push(PatternElement::Text(core::mem::take(&mut text)));
+               }
+               pattern.push(self.parse_placeable()?);
+               continue;
+            }
+            '}' => break,
+            '\n' if in_variant => {
+               let next = self.chars.get(self.pos + 1);
+               if matches!(next, Some('[' | '*' | '}')) {
+                  break;
+               }
+               text.push(c);
+            }
+            c => text.push(c),
+         }
+         self.pos += 1;
+      }
+      if in_variant {
+         let len = text.trim_end().len();
+         text.truncate(len);
+      }
+      if !text.is_empty() {
+         pattern.push(PatternElement::Text(text));
+      }
+      Ok(pattern)
+   }
+
+   fn parse_placeable(&mut self) -> Result<PatternElement, &'static str> {
+      self.skip_whitespace();
+      let element = match self.peek() {
+         Some('$') => {
+            self.pos += 1;
+            PatternElement::Var(self.parse_identifier()?)
+         }
+         Some('"') => {
+            self.pos += 1;
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c != '"') {
+               self.pos += 1;
+            }
+            let text = self.chars[start..self.pos].iter().collect();
+            self.expect('"', "unterminated string literal")?;
+            PatternElement::Text(text)
+         }
+         _ => return Err("expected a variable or a string literal"),
+      };
+      self.skip_whitespace();
+      if self.peek() == Some('-') {
+         let PatternElement::Var(var) = element else {
+            return Err("only variables can be selected");
+         };
+         self.pos += 1;
+         self.expect('>', "expected `->`")?;
+         return self.parse_select(var);
+      }
+      self.expect('}', "expected `}`")?;
+      Ok(element)
+   }
+
+   fn parse_select(&mut self, var: String) -> Result<PatternElement, &'static str> {
+      let mut variants = vec![];
+      let mut default = None;
+      loop {
+         self.skip_whitespace();
+         match self.peek() {
+            Some('}') => {
+               self.pos += 1;
+               break;
+            }
+            Some('*') => {
+               self.pos += 1;
+               if default.replace(variants.len()).is_some() {
+                  return Err("more than one default variant");
+               }
+            }
+            None => return Err("unterminated select expression"),
+            _ => {}
+         }
+         self.expect('[', "expected a variant")?;
+         self.skip_whitespace();
+         let key = self.parse_identifier()?;
+         self.skip_whitespace();
+         self.expect(']', "expected `]`")?;
+         while self.peek().is_some_and(|c| c == ' ' || c == '\t') {
+            self.pos += 1;
+         }
+         variants.push((key, self.parse_pattern(true)?));
+      }
+      let Some(default) = default else {
+         return Err("missing the default variant");
+      };
+      Ok(PatternElement::Select {
+         var,
+         variants,
+         default,
+      })
+   }
+}
+
+#[derive(Debug)]
+pub enum LocaleBundleError {
+   Io(std::io::Error),
+   Utf8(core::str::Utf8Error),
+   Parse { line: usize, message: String },
+}
+
+impl Display for LocaleBundleError {
+   fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+      match self {
+         LocaleBundleError::Io(err) => write!(f, "could not read locale bundle: {err}"),
+         LocaleBundleError::Utf8(err) => write!(f, "locale bundle is not utf-8: {err}"),
+         LocaleBundleError::Parse { line, message } => {
+            write!(f, "invalid locale bundle at line {line}: {message}")
+         }
+      }
+   }
+}
+
+impl std::error::Error for LocaleBundleError {}
+
+impl From<std::io::Error> for LocaleBundleError {
+   fn from(value: std::io::Error) -> Self {
+      LocaleBundleError::Io(value)
+   }
+}
+
+impl From<core::str::Utf8Error> for LocaleBundleError {
+   fn from(value: core::str::Utf8Error) -> Self {
+      LocaleBundleError::Utf8(value)
+   }
+}
+
+#[derive(Default)]
+pub struct LocaleBundleLoader;
+
+impl AssetLoader for LocaleBundleLoader {
+   type Asset = LocaleBundle;
+   type Settings = ();
+   type Error = LocaleBundleError;
+
+   fn load<'a>(
+      &'a self,
+      reader: &'a mut Reader,
+      _settings: &'a Self::Settings,
+      _load_context: &'a mut LoadContext,
+   ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+      Box::pin(async move {
+         let mut bytes = Vec::new();
+         reader.read_to_end(&mut bytes).await?;
+         LocaleBundle::parse(core::str::from_utf8(&bytes)?)
+      })
+   }
+
+   fn extensions(&self) -> &[&str] {
+      &["ftl"]
+   }
+}
+
+/// The current language and the message bundles of the languages.
+///
+/// Views using [`t!`](crate::t) re-render when the locale changes, e.g. by [`Locale::set_language`]
+/// or when a bundle file is reloaded.
+#[derive(Resource, Debug)]
+pub struct Locale {
+   language: Cow<'static, str>,
+   fallback: Option<Cow<'static, str>>,
+   bundles: HashMap<Cow<'static, str>, LocaleBundle>,
+   bundle_handles: Vec<(Cow<'static, str>, Handle<LocaleBundle>)>,
+}
+
+impl Default for Locale {
+   fn default() -> Self {
+      Self::new("en-US")
+   }
+}
+
+impl Locale {
+   pub fn new(language: impl Into<Cow<'static, str>>) -> Self {
+      Self {
+         language: language.into(),
+         fallback: None,
+         bundles: Default::default(),
+         bundle_handles: vec![],
+      }
+   }
+
+   pub fn language(&self) -> &str {
+      &self.language
+   }
+
+   pub fn set_language(&mut self, language: impl Into<Cow<'static, str>>) {
+      self.language = language.into();
+   }
+
+   /// The language used for the messages missing in the current language.
+   pub fn set_fallback(&mut self, language: impl Into<Cow<'static, str>>) {
+      self.fallback = Some(language.into());
+   }
+
+   pub fn insert_bundle(&mut self, language: impl Into<Cow<'static, str>>, bundle: LocaleBundle) {
+      self.bundles.insert(language.into(), bundle);
+   }
+
+   /// Loads the `.ftl` bundle of the language, it is used once loaded and updated when the file changes.
+   pub fn load_bundle(
+      &mut self,
+      asset_server: &AssetServer,
+      language: impl Into<Cow<'static, str>>,
+      path: impl Into<String>,
+   ) {
+      let handle = asset_server.load(path.into());
+      self.bundle_handles.push((language.into(), handle));
+   }
+
+   /// Formats the message of the current language, or of the fallback language.
+   /// Returns the key itself if no bundle has the message.
+   pub fn format(&self, key: &str, args: &[(Cow<'static, str>, LocaleValue)]) -> String {
+      [Some(&self.language), self.fallback.as_ref()]
+         .into_iter()
+         .flatten()
+         .find_map(|language| self.bundles.get(language)?.format(language, key, args))
+         .unwrap_or_else(|| key.to_string())
+   }
+
+   pub fn t(&self, key: &str) -> String {
+      self.format(key, &[])
+   }
+}
+
+fn update_locale_bundles(
+   mut events: EventReader<AssetEvent<LocaleBundle>>,
+   bundles: Res<Assets<LocaleBundle>>,
+   mut locale: ResMut<Locale>,
+) {
+   for event in events.read() {
+      let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = event else {
+         continue;
+      };
+      let Some(bundle) = bundles.get(*id) else {
+         continue;
+      };
+      let languages = locale
+         .bundle_handles
+         .iter()
+         .filter(|(_, handle)| handle.id() == *id)
+         .map(|(language, _)| language.clone())
+         .collect::<Vec<_>>();
+      for language in languages {
+         locale.insert_bundle(language, bundle.clone());
+      }
+   }
+}
+
+/// A text source of the message of the [`Locale`], usable anywhere `span` takes content.
+/// It re-renders when the locale changes.
+///
+/// ```ignore
+/// span(t!("hello", name = "Ferris"))
+/// span(t!("items", count = 3))
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::t!($key, ::std::vec::Vec::new())
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::t!($key, ::std::vec![$((
+            ::std::borrow::Cow::Borrowed(stringify!($name)),
+            $crate::LocaleValue::from($value),
+        )),+])
+    };
+    ($key:expr, $args:expr) => {{
+        let key: ::std::borrow::Cow<'static, str> = ::core::convert::Into::into($key);
+        let args: $crate::LocaleArgs = $args;
+        $crate::x_res(move |locale: &$crate::Locale| locale.format(&key, &args))
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn format_messages() {
+      let bundle = LocaleBundle::parse(
+         r#"
+# items in the bag
+hello = Hello, { $name }!
+items = { $count ->
+    [0] You have no items
+    [one] You have one item
+   *[other] You have { $count } items
+}
+quoted = { "{" } braces
+"#,
+      )
+      .unwrap();
+      let mut locale = Locale::new("en");
+      locale.insert_bundle("en", bundle);
+
+      let format = |key: &str, args: LocaleArgs| locale.format(key, &args);
+      assert_eq!(
+         format("hello", vec![("name".into(), "Ferris".into())]),
+         "Hello, Ferris!"
+      );
+      assert_eq!(
+         format("items", vec![("count".into(), 0.into())]),
+         "You have no items"
+      );
+      assert_eq!(
+         format("items", vec![("count".into(), 1.into())]),
+         "You have one item"
+      );
+      assert_eq!(
+         format("items", vec![("count".into(), 5.into())]),
+         "You have 5 items"
+      );
+      assert_eq!(format("quoted", vec![]), "{ braces");
+      assert_eq!(format("missing", vec![]), "missing");
+
+      assert!(LocaleBundle::parse("items = { $count ->\n [one] one\n}").is_err());
+      assert_eq!(plural_category("ru", 22.), "few");
+      assert_eq!(plural_category("ru", 11.), "many");
+   }
+}
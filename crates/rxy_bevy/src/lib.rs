@@ -1,13 +1,19 @@
 #![allow(clippy::type_complexity)]
 
+pub use animation::*;
 pub use cmd::*;
 pub use command::*;
+pub use dialog::*;
+pub use drag_drop::*;
 pub use entity_extra_data::*;
 pub use focusable::*;
+pub use i18n::*;
 pub use plugin::*;
 pub use renderer::*;
 pub use res::*;
 pub use res_change_observe::*;
+pub use shape::*;
+pub use transition::*;
 use rxy_core::{
    CloneableSchemaSlot, FnSchema, IntoViewSchemaFnWrapper, RebuildFnReceiver, RenderSchemaCtx,
    RendererSchemaView, SchemaSlot,
@@ -16,17 +22,23 @@ pub use view::*;
 pub use view_member::*;
 pub use world_ext::*;
 
+mod animation;
 mod cmd;
 mod command;
+mod dialog;
+mod drag_drop;
 mod entity_extra_data;
 pub mod event;
 mod focusable;
+mod i18n;
 pub mod navigation;
 mod nest;
 mod plugin;
 mod renderer;
 mod res;
 mod res_change_observe;
+mod shape;
+mod transition;
 pub mod vec_data_source;
 mod view;
 mod view_member;
@@ -52,11 +64,12 @@ pub mod prelude {
    pub use rxy_bevy_macro::{ElementSchema, Schema};
 
    pub use crate::elements::prelude::*;
+   pub use crate::elements::{ImageSlice, UiImageScaleMode};
    pub use crate::renderer::common_renderer::*;
    #[cfg(feature = "style")]
    pub use crate::renderer::style::ElementViewStyleExt;
    pub use crate::renderer::BevyElement;
-   pub use crate::x_res_once;
+   pub use crate::{t, x_res_once};
 
    pub use super::all_attrs::{CommonAttrsElementViewBuilder, CommonAttrsViewBuilder};
    pub use super::renderer::event::*;
@@ -68,9 +81,13 @@ pub mod prelude {
    #[cfg(feature = "style")]
    pub use super::style::prelude::*;
    pub use super::{
-      event::*, system_once, x_res, BevyRenderer, CloneableSlot, CmdReceiver, CmdSender,
-      FnSchemaView, Focusable, ReceiverProp, ResChangeWorldExt, RxyPlugin, RxyViewSpawner,
-      SchemaCtx, Slot,
+      draggable, drop_target, event::*, system_once, x_animate, x_focus_handle, x_focus_scope,
+      x_initial_focus, x_res, x_transition, AnimationEvent, AnimationEventKind, AnimationRepeat,
+      AttrKeyframes, BevyRenderer, BoxShadow, CloneableSlot, CmdReceiver, CmdSender, Dialogs,
+      DragDrop, DragState, Easing, FnSchemaView, FocusHandle, FocusScope, Focusable, FocusedEntity,
+      LinearGradient, Locale, LocaleArgs, MemberOwnerAnimateExt, MemberOwnerTransitionExt,
+      ReceiverProp, ResChangeWorldExt, RichText, RichTextFonts, RxyPlugin, RxyViewSpawner,
+      SchemaCtx, Slot, TextRun, XTransition,
    };
    pub use super::{ElementViewCompositeAttrs, MemberOwnerCompositeAttrs};
 }
@@ -3,12 +3,17 @@ use bevy_ecs::query::With;
 use bevy_ecs::schedule::IntoSystemConfigs;
 use bevy_ecs::system::{Query, Res, ResMut};
 use bevy_ecs::{change_detection::DetectChangesMut, entity::Entity, prelude::Resource};
+use bevy_hierarchy::Parent;
 use bevy_input::prelude::KeyCode;
 use bevy_input::{ButtonInput, InputSystem};
 use bevy_render::view::ViewVisibility;
 use bevy_ui::{Interaction, UiStack, UiSystem};
 
-use crate::{Focusable, FocusedEntity};
+use crate::{ElementStates, FocusScopes, FocusVisible, Focusable, FocusedEntity};
+
+pub use spatial::*;
+
+mod spatial;
 
 #[derive(Default, Debug)]
 pub struct RxyKeyboardNavigationPlugin {}
@@ -62,13 +67,18 @@ pub(crate) fn tab_pressed(
 
 /// The system updates the [`Focus`] resource when the user uses keyboard navigation with <kbd>tab</kbd> or <kbd>shift</kbd> + <kbd>tab</kbd>.
 ///
-/// Entities can be focused if [`ComputedVisibility`] is visible and they have the [`Focusable`] component.
+/// Entities can be focused if [`ComputedVisibility`] is visible, they have the [`Focusable`] component and are not disabled.
+/// Only the entities within the trapping [`FocusScope`](crate::FocusScope) can be focused, if any.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn keyboard_navigation_system(
    mut focus: ResMut<FocusedEntity>,
+   mut focus_visible: ResMut<FocusVisible>,
    mut interactions: Query<&mut Interaction>,
-   focusables: Query<&ViewVisibility, With<Focusable>>,
+   focusables: Query<(&ViewVisibility, Option<&ElementStates>), With<Focusable>>,
    keyboard_input: Res<ButtonInput<KeyCode>>,
    ui_stack: Res<UiStack>,
+   focus_scopes: Res<FocusScopes>,
+   parents: Query<&Parent>,
 ) {
    let reverse_order =
       keyboard_input.pressed(KeyCode::ShiftLeft) || keyboard_input.pressed(KeyCode::ShiftRight);
@@ -76,7 +86,10 @@ pub(crate) fn keyboard_navigation_system(
    let can_focus = |entity: &&Entity| {
       focusables
          .get(**entity)
-         .map_or(false, |computed_visibility| computed_visibility.get())
+         .map_or(false, |(computed_visibility, states)| {
+            computed_visibility.get() && !states.is_some_and(|n| n.disabled)
+         })
+         && focus_scopes.allows(**entity, &parents)
    };
 
    let ui_nodes = &ui_stack.uinodes;
@@ -104,6 +117,16 @@ pub(crate) fn keyboard_navigation_system(
       wrapped.find(can_focus).copied()
    };
 
+   set_navigation_focus(&mut focus, &mut focus_visible, &mut interactions, new_focus);
+}
+
+/// Moves the [`FocusedEntity`] to `new_focus` as a result of navigation, so the focus is visible.
+pub(crate) fn set_navigation_focus(
+   focus: &mut ResMut<FocusedEntity>,
+   focus_visible: &mut ResMut<FocusVisible>,
+   interactions: &mut Query<&mut Interaction>,
+   new_focus: Option<Entity>,
+) {
    // Reset the clicked state
    if new_focus != focus.0 {
       if let Some(mut interaction) = focus.0.and_then(|entity| interactions.get_mut(entity).ok()) {
@@ -114,12 +137,9 @@ pub(crate) fn keyboard_navigation_system(
    }
 
    if focus.0 != new_focus {
-      *focus = FocusedEntity(new_focus);
+      **focus = FocusedEntity(new_focus);
    }
-   // focus.set_if_neq(Focus {
-   //     entity: new_focus,
-   //     focus_visible: true,
-   // });
+   focus_visible.set_if_neq(FocusVisible(new_focus.is_some()));
 }
 
 /// Should the [`keyboard_click`] system run?
@@ -127,10 +147,17 @@ pub(crate) fn trigger_click(keyboard_input: Res<ButtonInput<KeyCode>>) -> bool {
    keyboard_input.just_pressed(KeyCode::Space) || keyboard_input.just_pressed(KeyCode::Enter)
 }
 
-/// Trigger the [`Focus`] entity to be clicked.
-pub(crate) fn keyboard_click(mut interactions: Query<&mut Interaction>, focus: Res<FocusedEntity>) {
-   if let Some(mut interaction) = focus.0.and_then(|entity| interactions.get_mut(entity).ok()) {
-      interaction.set_if_neq(Interaction::Pressed);
+/// Trigger the [`Focus`] entity to be clicked, unless it is disabled.
+pub(crate) fn keyboard_click(
+   mut interactions: Query<(&mut Interaction, Option<&ElementStates>)>,
+   focus: Res<FocusedEntity>,
+) {
+   if let Some((mut interaction, states)) =
+      focus.0.and_then(|entity| interactions.get_mut(entity).ok())
+   {
+      if !states.is_some_and(|n| n.disabled) {
+         interaction.set_if_neq(Interaction::Pressed);
+      }
    }
 }
 
@@ -222,3 +249,65 @@ mod test {
     }
 }
  */
+
+#[cfg(test)]
+mod tests {
+   use bevy_ecs::system::RunSystemOnce;
+   use bevy_ecs::world::World;
+
+   use crate::focusable::blur_disabled;
+
+   use super::*;
+
+   fn navigation_world() -> (World, Vec<Entity>) {
+      let mut world = World::new();
+      world.init_resource::<FocusedEntity>();
+      world.init_resource::<FocusScopes>();
+      world.init_resource::<FocusVisible>();
+      world.init_resource::<ButtonInput<KeyCode>>();
+      let mut visibility = ViewVisibility::default();
+      visibility.set();
+      let entities = (0..3)
+         .map(|_| {
+            world
+               .spawn((visibility, Focusable::default(), Interaction::None))
+               .id()
+         })
+         .collect::<Vec<_>>();
+      world.insert_resource(UiStack {
+         uinodes: entities.clone(),
+      });
+      (world, entities)
+   }
+
+   fn disable(world: &mut World, entity: Entity) {
+      world.entity_mut(entity).insert(ElementStates {
+         disabled: true,
+         ..Default::default()
+      });
+   }
+
+   #[test]
+   fn tab_skips_disabled() {
+      let (mut world, entities) = navigation_world();
+      disable(&mut world, entities[1]);
+      world.resource_mut::<FocusedEntity>().0 = Some(entities[0]);
+      world.run_system_once(keyboard_navigation_system);
+      assert_eq!(world.resource::<FocusedEntity>().0, Some(entities[2]));
+   }
+
+   #[test]
+   fn disabled_focus_is_not_clicked_and_blurs() {
+      let (mut world, entities) = navigation_world();
+      world.resource_mut::<FocusedEntity>().0 = Some(entities[0]);
+      disable(&mut world, entities[0]);
+      world.run_system_once(keyboard_click);
+      assert_eq!(
+         *world.get::<Interaction>(entities[0]).unwrap(),
+         Interaction::None
+      );
+
+      world.run_system_once(blur_disabled);
+      assert_eq!(world.resource::<FocusedEntity>().0, None);
+   }
+}
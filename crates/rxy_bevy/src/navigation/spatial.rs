@@ -0,0 +1,535 @@
+use core::time::Duration;
+
+use bevy_app::{Plugin, PreUpdate};
+use bevy_ecs::prelude::{
+   on_event, Component, Entity, Event, EventReader, EventWriter, IntoSystemConfigs, Local, Query,
+   Res, ResMut, Resource, With,
+};
+use bevy_hierarchy::{HierarchyQueryExt, Parent};
+use bevy_input::gamepad::{
+   GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, Gamepads,
+};
+use bevy_input::prelude::KeyCode;
+use bevy_input::{Axis, ButtonInput, InputSystem};
+use bevy_reflect::Reflect;
+use bevy_render::view::ViewVisibility;
+use bevy_time::Time;
+use bevy_transform::prelude::GlobalTransform;
+use bevy_ui::{Interaction, Node, UiStack, UiSystem};
+use glam::Vec2;
+
+use super::{end_keyboard_click, keyboard_click, set_navigation_focus};
+use crate::{ElementStates, FocusScopes, FocusVisible, Focusable, FocusedEntity};
+
+/// Moves the focus to the nearest [`Focusable`] in the direction of the D-pad, the left stick
+/// or the arrow keys if [`GamepadNavigationInput::arrow_keys`] is set, and clicks the focused entity with the south button.
+#[derive(Default, Debug)]
+pub struct RxyGamepadNavigationPlugin {}
+
+impl Plugin for RxyGamepadNavigationPlugin {
+   fn build(&self, app: &mut bevy_app::App) {
+      app.register_type::<NavigationTargets>()
+         .register_type::<NavigationScope>()
+         .init_resource::<GamepadNavigationInput>()
+         .add_event::<NavigationRequest>()
+         .add_systems(
+            PreUpdate,
+            (
+               gamepad_navigation_input
+                  .after(InputSystem)
+                  .before(UiSystem::Focus),
+               spatial_navigation_system
+                  .in_set(UiSystem::Focus)
+                  .run_if(on_event::<NavigationRequest>()),
+            ),
+         )
+         .add_systems(
+            PreUpdate,
+            keyboard_click
+               .after(UiSystem::Focus)
+               .run_if(gamepad_confirm_pressed),
+         )
+         .add_systems(
+            PreUpdate,
+            end_keyboard_click
+               .after(UiSystem::Focus)
+               .run_if(gamepad_confirm_released),
+         );
+   }
+}
+
+#[derive(Reflect, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NavDirection {
+   Up,
+   Down,
+   Left,
+   Right,
+}
+
+impl NavDirection {
+   /// The unit vector of the direction in ui coordinates, where y goes down.
+   pub fn as_vec2(&self) -> Vec2 {
+      match self {
+         NavDirection::Up => Vec2::NEG_Y,
+         NavDirection::Down => Vec2::Y,
+         NavDirection::Left => Vec2::NEG_X,
+         NavDirection::Right => Vec2::X,
+      }
+   }
+}
+
+/// Requests to move the focus in a direction, sent by the gamepad and arrow keys input
+/// or by the app to navigate programmatically.
+#[derive(Event, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NavigationRequest(pub NavDirection);
+
+/// Resource for the configuration of the directional navigation.
+#[derive(Resource, Debug, Clone)]
+pub struct GamepadNavigationInput {
+   pub enabled: bool,
+   /// Whether the arrow keys navigate too. Off by default, since widgets like sliders and lists
+   /// handle the arrow keys themselves.
+   pub arrow_keys: bool,
+   /// Whether the focus moves to the other side when there is nothing in the pressed direction.
+   /// A [`NavigationScope`] has its own setting.
+   pub wrap_around: bool,
+   /// How far the left stick has to be tilted to navigate.
+   pub stick_threshold: f32,
+   /// How long a direction is held before it repeats.
+   pub repeat_delay: Duration,
+   pub repeat_interval: Duration,
+}
+
+impl Default for GamepadNavigationInput {
+   fn default() -> Self {
+      Self {
+         enabled: true,
+         arrow_keys: false,
+         wrap_around: false,
+         stick_threshold: 0.5,
+         repeat_delay: Duration::from_millis(400),
+         repeat_interval: Duration::from_millis(120),
+      }
+   }
+}
+
+/// Overrides the entities that get the focus when navigating from this entity.
+#[derive(Reflect, Component, Clone, Debug, Default, Eq, PartialEq)]
+pub struct NavigationTargets {
+   pub up: Option<Entity>,
+   pub down: Option<Entity>,
+   pub left: Option<Entity>,
+   pub right: Option<Entity>,
+}
+
+impl NavigationTargets {
+   pub fn up(mut self, entity: Entity) -> Self {
+      self.up = Some(entity);
+      self
+   }
+
+   pub fn down(mut self, entity: Entity) -> Self {
+      self.down = Some(entity);
+      self
+   }
+
+   pub fn left(mut self, entity: Entity) -> Self {
+      self.left = Some(entity);
+      self
+   }
+
+   pub fn right(mut self, entity: Entity) -> Self {
+      self.right = Some(entity);
+      self
+   }
+
+   pub fn get(&self, direction: NavDirection) -> Option<Entity> {
+      match direction {
+         NavDirection::Up => self.up,
+         NavDirection::Down => self.down,
+         NavDirection::Left => self.left,
+         NavDirection::Right => self.right,
+      }
+   }
+}
+
+/// The directional navigation from a descendant stays within the nearest ancestor with this component,
+/// e.g. a menu or an inventory grid.
+#[derive(Reflect, Component, Clone, Debug, Default, Eq, PartialEq)]
+pub struct NavigationScope {
+   pub wrap_around: bool,
+}
+
+/// Should the [`keyboard_click`] system run for the gamepad?
+pub(crate) fn gamepad_confirm_pressed(
+   gamepads: Res<Gamepads>,
+   buttons: Res<ButtonInput<GamepadButton>>,
+   input: Res<GamepadNavigationInput>,
+) -> bool {
+   input.enabled
+      && gamepads
+         .iter()
+         .any(|gamepad| buttons.just_pressed(GamepadButton::new(gamepad, GamepadButtonType::South)))
+}
+
+/// Should the [`end_keyboard_click`] system run for the gamepad?
+pub(crate) fn gamepad_confirm_released(
+   gamepads: Res<Gamepads>,
+   buttons: Res<ButtonInput<GamepadButton>>,
+) -> bool {
+   gamepads
+      .iter()
+      .any(|gamepad| buttons.just_released(GamepadButton::new(gamepad, GamepadButtonType::South)))
+}
+
+#[derive(Default)]
+pub(crate) struct NavigationRepeat {
+   direction: Option<NavDirection>,
+   next: Duration,
+}
+
+/// Sends a [`NavigationRequest`] when a direction is pressed, and repeatedly while it is held.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gamepad_navigation_input(
+   input: Res<GamepadNavigationInput>,
+   gamepads: Res<Gamepads>,
+   buttons: Res<ButtonInput<GamepadButton>>,
+   axes: Res<Axis<GamepadAxis>>,
+   keyboard_input: Res<ButtonInput<KeyCode>>,
+   time: Res<Time>,
+   mut repeat: Local<NavigationRepeat>,
+   mut requests: EventWriter<NavigationRequest>,
+) {
+   if !input.enabled {
+      repeat.direction = None;
+      return;
+   }
+
+   let held = |direction: NavDirection| {
+      let (button, key) = match direction {
+         NavDirection::Up => (GamepadButtonType::DPadUp, KeyCode::ArrowUp),
+         NavDirection::Down => (GamepadButtonType::DPadDown, KeyCode::ArrowDown),
+         NavDirection::Left => (GamepadButtonType::DPadLeft, KeyCode::ArrowLeft),
+         NavDirection::Right => (GamepadButtonType::DPadRight, KeyCode::ArrowRight),
+      };
+      (input.arrow_keys && keyboard_input.pressed(key))
+         || gamepads.iter().any(|gamepad| {
+            if buttons.pressed(GamepadButton::new(gamepad, button)) {
+               return true;
+            }
+            let axis = |axis_type| {
+               axes
+                  .get(GamepadAxis::new(gamepad, axis_type))
+                  .unwrap_or_default()
+            };
+            let stick = Vec2::new(
+               axis(GamepadAxisType::LeftStickX),
+               // the stick y goes up
+               -axis(GamepadAxisType::LeftStickY),
+            );
+            stick.length() >= input.stick_threshold
+               && stick.normalize().dot(direction.as_vec2()) > core::f32::consts::FRAC_1_SQRT_2
+         })
+   };
+
+   let direction = [
+      NavDirection::Up,
+      NavDirection::Down,
+      NavDirection::Left,
+      NavDirection::Right,
+   ]
+   .into_iter()
+   .find(|direction| held(*direction));
+
+   let now = time.elapsed();
+   match direction {
+      None => repeat.direction = None,
+      Some(direction) if repeat.direction != Some(direction) => {
+         repeat.direction = Some(direction);
+         repeat.next = now + input.repeat_delay;
+         requests.send(NavigationRequest(direction));
+      }
+      Some(direction) if now >= repeat.next => {
+         repeat.next = now + input.repeat_interval;
+         requests.send(NavigationRequest(direction));
+      }
+      Some(_) => {}
+   }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct NavRect {
+   min: Vec2,
+   max: Vec2,
+}
+
+impl NavRect {
+   fn new(node: &Node, transform: &GlobalTransform) -> Self {
+      let center = transform.translation().truncate();
+      let half_size = node.size() / 2.;
+      Self {
+         min: center - half_size,
+         max: center + half_size,
+      }
+   }
+
+   fn center(&self) -> Vec2 {
+      (self.min + self.max) / 2.
+   }
+
+   /// The distance from `self` to `other` along the direction, and the gap between them across it.
+   fn distances(&self, other: &NavRect, direction: NavDirection) -> (f32, f32) {
+      let (along, min, max, other_min, other_max) = match direction {
+         NavDirection::Up => (
+            self.min.y - other.max.y,
+            self.min.x,
+            self.max.x,
+            other.min.x,
+            other.max.x,
+         ),
+         NavDirection::Down => (
+            other.min.y - self.max.y,
+            self.min.x,
+            self.max.x,
+            other.min.x,
+            other.max.x,
+         ),
+         NavDirection::Left => (
+            self.min.x - other.max.x,
+            self.min.y,
+            self.max.y,
+            other.min.y,
+            other.max.y,
+         ),
+         NavDirection::Right => (
+            other.min.x - self.max.x,
+            self.min.y,
+            self.max.y,
+            other.min.y,
+            other.max.y,
+         ),
+      };
+      let across = (other_min - max).max(min - other_max).max(0.);
+      (along, across)
+   }
+}
+
+/// Picks the nearest focusable in the direction of the [`NavigationRequest`],
+/// unless the focused entity has [`NavigationTargets`] for it.
+///
+/// Only the enabled focusables in the same [`NavigationScope`] as the focused entity,
+/// and within the trapping [`FocusScope`](crate::FocusScope) if any, are candidates.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn spatial_navigation_system(
+   mut requests: EventReader<NavigationRequest>,
+   input: Res<GamepadNavigationInput>,
+   mut focus: ResMut<FocusedEntity>,
+   mut focus_visible: ResMut<FocusVisible>,
+   mut interactions: Query<&mut Interaction>,
+   focusables: Query<
+      (
+         &Node,
+         &GlobalTransform,
+         &ViewVisibility,
+         Option<&NavigationTargets>,
+         Option<&ElementStates>,
+      ),
+      With<Focusable>,
+   >,
+   parents: Query<&Parent>,
+   scopes: Query<&NavigationScope>,
+   ui_stack: Res<UiStack>,
+   focus_scopes: Res<FocusScopes>,
+) {
+   let can_focus = |entity: Entity| {
+      focusables
+         .get(entity)
+         .is_ok_and(|(_, _, visibility, _, states)| {
+            visibility.get() && !states.is_some_and(|n| n.disabled)
+         })
+         && focus_scopes.allows(entity, &parents)
+   };
+   let scope_of = |entity: Entity| parents.iter_ancestors(entity).find(|n| scopes.contains(*n));
+
+   let mut new_focus = focus.0;
+   for NavigationRequest(direction) in requests.read() {
+      let Some(current) = new_focus.filter(|entity| can_focus(*entity)) else {
+         // Start with the first focusable, like the keyboard navigation
+         new_focus = ui_stack
+            .uinodes
+            .iter()
+            .copied()
+            .find(|entity| can_focus(*entity));
+         continue;
+      };
+      let (node, transform, _, targets, _) = focusables.get(current).unwrap();
+
+      if let Some(target) = targets
+         .and_then(|targets| targets.get(*direction))
+         .filter(|entity| can_focus(*entity))
+      {
+         new_focus = Some(target);
+         continue;
+      }
+
+      let rect = NavRect::new(node, transform);
+      let scope = scope_of(current);
+      let candidates = ui_stack
+         .uinodes
+         .iter()
+         .copied()
+         .filter(|entity| *entity != current && can_focus(*entity) && scope_of(*entity) == scope)
+         .map(|entity| {
+            let (node, transform, ..) = focusables.get(entity).unwrap();
+            (entity, NavRect::new(node, transform))
+         })
+         .collect::<Vec<_>>();
+
+      let offset = |other: &NavRect| (other.center() - rect.center()).dot(direction.as_vec2());
+      let nearest = candidates
+         .iter()
+         .filter(|(_, other)| offset(other) > 0.)
+         .map(|(entity, other)| {
+            let (along, across) = rect.distances(other, *direction);
+            // prefer the entities in the same row or column
+            (*entity, along.max(0.) + across * 2.)
+         })
+         .min_by(|a, b| a.1.total_cmp(&b.1))
+         .map(|(entity, _)| entity);
+
+      let wrap_around = match scope.and_then(|scope| scopes.get(scope).ok()) {
+         Some(scope) => scope.wrap_around,
+         None => input.wrap_around,
+      };
+      new_focus = nearest
+         .or_else(|| {
+            if !wrap_around {
+               return None;
+            }
+            // the farthest entity on the other side, in the same row or column if any
+            candidates
+               .iter()
+               .filter(|(_, other)| offset(other) < 0.)
+               .map(|(entity, other)| (*entity, rect.distances(other, *direction).1, offset(other)))
+               .min_by(|a, b| a.1.total_cmp(&b.1).then(a.2.total_cmp(&b.2)))
+               .map(|(entity, ..)| entity)
+         })
+         .or(Some(current));
+   }
+
+   set_navigation_focus(&mut focus, &mut focus_visible, &mut interactions, new_focus);
+}
+
+#[cfg(test)]
+mod tests {
+   use bevy_ecs::event::Events;
+   use bevy_ecs::system::RunSystemOnce;
+   use bevy_ecs::world::World;
+   use glam::Vec3;
+
+   use super::*;
+
+   fn navigation_world() -> World {
+      let mut world = World::new();
+      world.init_resource::<GamepadNavigationInput>();
+      world.init_resource::<Events<NavigationRequest>>();
+      world.init_resource::<FocusedEntity>();
+      world.init_resource::<FocusScopes>();
+      world.init_resource::<FocusVisible>();
+      world.init_resource::<UiStack>();
+      world
+   }
+
+   fn spawn_focusable(world: &mut World, x: f32, y: f32) -> Entity {
+      let mut visibility = ViewVisibility::default();
+      visibility.set();
+      let entity = world
+         .spawn((
+            Node::default(),
+            GlobalTransform::from_translation(Vec3::new(x, y, 0.)),
+            visibility,
+            Focusable::default(),
+            Interaction::default(),
+         ))
+         .id();
+      world.resource_mut::<UiStack>().uinodes.push(entity);
+      entity
+   }
+
+   fn navigate(world: &mut World, from: Entity, direction: NavDirection) -> Option<Entity> {
+      world.resource_mut::<FocusedEntity>().0 = Some(from);
+      world.resource_mut::<Events<NavigationRequest>>().clear();
+      world.send_event(NavigationRequest(direction));
+      world.run_system_once(spatial_navigation_system);
+      world.resource::<FocusedEntity>().0
+   }
+
+   #[test]
+   fn prefers_the_same_row() {
+      let mut world = navigation_world();
+      let current = spawn_focusable(&mut world, 0., 0.);
+      let behind = spawn_focusable(&mut world, -50., 0.);
+      let diagonal = spawn_focusable(&mut world, 60., 40.);
+      let same_row = spawn_focusable(&mut world, 100., 0.);
+
+      assert_eq!(
+         navigate(&mut world, current, NavDirection::Right),
+         Some(same_row)
+      );
+      assert_eq!(
+         navigate(&mut world, current, NavDirection::Left),
+         Some(behind)
+      );
+      assert_eq!(
+         navigate(&mut world, current, NavDirection::Down),
+         Some(diagonal)
+      );
+      // nothing above, the focus stays
+      assert_eq!(
+         navigate(&mut world, current, NavDirection::Up),
+         Some(current)
+      );
+   }
+
+   #[test]
+   fn wraps_around_to_the_farthest() {
+      let mut world = navigation_world();
+      let first = spawn_focusable(&mut world, 0., 0.);
+      spawn_focusable(&mut world, 100., 0.);
+      let last = spawn_focusable(&mut world, 200., 0.);
+      spawn_focusable(&mut world, 0., 100.);
+
+      assert_eq!(navigate(&mut world, last, NavDirection::Right), Some(last));
+
+      world.resource_mut::<GamepadNavigationInput>().wrap_around = true;
+      assert_eq!(navigate(&mut world, last, NavDirection::Right), Some(first));
+      assert_eq!(navigate(&mut world, first, NavDirection::Left), Some(last));
+   }
+
+   #[test]
+   fn navigation_targets_override_the_nearest() {
+      let mut world = navigation_world();
+      let current = spawn_focusable(&mut world, 0., 0.);
+      let nearest = spawn_focusable(&mut world, 100., 0.);
+      let target = spawn_focusable(&mut world, 0., 300.);
+      world
+         .entity_mut(current)
+         .insert(NavigationTargets::default().right(target).left(current));
+
+      assert_eq!(
+         navigate(&mut world, current, NavDirection::Right),
+         Some(target)
+      );
+      assert_eq!(
+         navigate(&mut world, current, NavDirection::Left),
+         Some(current)
+      );
+
+      // a target that can not get the focus falls back to the nearest
+      *world.get_mut::<ViewVisibility>(target).unwrap() = ViewVisibility::HIDDEN;
+      assert_eq!(
+         navigate(&mut world, current, NavDirection::Right),
+         Some(nearest)
+      );
+   }
+}
@@ -7,8 +7,11 @@ use bevy_ui::prelude::NodeBundle;
 use bevy_ui::widget::TextFlags;
 use bevy_ui::Style;
 
-use crate::elements::ElementTypeRegisterAppExt;
-use crate::{handle_schedule_event, CommandChannelPlugin, FocusablePlugin, ScheduleSystemAdds};
+use crate::elements::{ElementTypeRegisterAppExt, RxyImagePlugin};
+use crate::{
+   handle_schedule_event, CommandChannelPlugin, FocusablePlugin, RxyAnimationPlugin,
+   RxyDialogPlugin, RxyDragDropPlugin, RxyI18nPlugin, RxyShapePlugin, ScheduleSystemAdds,
+};
 
 #[derive(Resource)]
 pub struct RxyContainerEntity {
@@ -55,6 +58,14 @@ impl Plugin for RxyPlugin {
       }));
 
       app.add_plugins((DefaultPickingPlugins, CommandChannelPlugin, FocusablePlugin))
+         .add_plugins((
+            RxyAnimationPlugin,
+            RxyShapePlugin,
+            RxyImagePlugin,
+            RxyI18nPlugin,
+            RxyDragDropPlugin,
+            RxyDialogPlugin,
+         ))
          .insert_resource(root_entity)
          .register_type::<TextFlags>()
          .register_type::<PickingInteraction>()
@@ -1,350 +1,1461 @@
-// use bevy_render::prelude::Color;
-// use bevy_render::view::Visibility;
-// use bevy_text::{BreakLineOn, TextAlignment};
-// use bevy_ui::Display;
-// use bevy_ui::{
-//     AlignContent, AlignItems, AlignSelf, BackgroundColor, BorderColor, FlexDirection, FlexWrap,
-//     JustifyContent, JustifyItems, JustifySelf, OverflowAxis, PositionType,
-// };
-// use bevy_ui::{Direction, Val};
-//
-// use crate::into_attr_value::{OptionalOverflow, UiOptionalRect};
-//
-// pub fn parse_color(class: &str) -> Option<Color> {
-//     // Split the class into components
-//     let components: Vec<&str> = class.split('/').collect();
-//     let color_and_variant: Vec<&str> = components[0].split('-').collect();
-//
-//     // If there's an alpha channel specified, get it
-//     let alpha = if components.len() > 1 {
-//         match components[1].parse::<u16>() {
-//             // convert from 100 to 255k
-//             Ok(a) => (a * 255 / 100) as u8,
-//             Err(_) => return None, // Invalid alpha
-//         }
-//     } else {
-//         255 // Default alpha
-//     };
-//
-//     // Handle special colors
-//     if color_and_variant.len() == 1 {
-//         return match color_and_variant[0] {
-//             "transparent" => Some(Color::rgba_u8(0, 0, 0, 0)),
-//             "white" => Some(Color::rgba_u8(255, 255, 255, alpha)),
-//             "black" => Some(Color::rgba_u8(0, 0, 0, alpha)),
-//             _ => None, // _ => COLORS.get(color_and_variant[0]).map(|c| {
-//                        //     let (_, variant) = c.iter().next().unwrap();
-//                        //     Color::rgba_u8(variant[0], variant[1], variant[2], alpha)
-//                        // }),
-//         };
-//     }
-//
-//     None
-//     // Handle regular colors
-//     // let color = color_and_variant[0];
-//     // let variant = color_and_variant[1];
-//     //
-//     // COLORS.get(color).and_then(|variants| {
-//     //     variants
-//     //         .get(variant)
-//     //         .map(|&[r, g, b, _]| Color::rgba_u8(r, g, b, alpha))
-//     // })
-// }
-//
-// pub fn parse_size_val(text: &str) -> Val {
-//     match text {
-//         "full" => Val::Percent(100.),
-//         "auto" => Val::Auto,
-//         class => {
-//             if class.ends_with('%') {
-//                 Val::Percent(
-//                     class
-//                         .strip_suffix('%')
-//                         .unwrap()
-//                         .parse::<f32>()
-//                         .unwrap_or(0.0)
-//                         / 100.0,
-//                 )
-//             } else if class.ends_with("px") {
-//                 Val::Px(class.parse::<f32>().unwrap_or(0.0))
-//             } else {
-//                 class.parse::<f32>().map(Val::Px).unwrap_or_else(|_| {
-//                     // todo: tracing
-//                     // warn!("Invalid size value: {}", class);
-//                     Val::Auto
-//                 })
-//             }
-//         }
-//     }
-// }
-//
-// pub trait MyFromStr: Sized {
-//     fn from_str(s: &str) -> Option<Self>;
-// }
-//
-// pub fn from_str<T: MyFromStr>(s: &str) -> Option<T> {
-//     T::from_str(s)
-// }
-//
-// impl MyFromStr for BorderColor {
-//     fn from_str(s: &str) -> Option<Self> {
-//         from_str::<Color>(s).map(BorderColor)
-//     }
-// }
-//
-// impl MyFromStr for BackgroundColor {
-//     fn from_str(s: &str) -> Option<Self> {
-//         from_str::<Color>(s).map(BackgroundColor)
-//     }
-// }
-//
-// impl MyFromStr for Color {
-//     fn from_str(s: &str) -> Option<Self> {
-//         parse_color(s)
-//     }
-// }
-//
-// impl MyFromStr for UiOptionalRect {
-//     fn from_str(s: &str) -> Option<Self> {
-//         let mut split = s.split_whitespace().map(parse_size_val).collect::<Vec<_>>();
-//         match split.len() {
-//             1 => Some(UiOptionalRect::all(split.pop().unwrap())),
-//             2 => {
-//                 let first = split.pop().unwrap();
-//                 let second = split.pop().unwrap();
-//                 Some(UiOptionalRect::axes(second, first))
-//             }
-//             3 => {
-//                 let first = split.pop().unwrap();
-//                 let second = split.pop().unwrap();
-//                 let three = split.pop().unwrap();
-//                 Some(UiOptionalRect::new(second, second, first, three))
-//             }
-//             4 => {
-//                 let first = split.pop().unwrap();
-//                 let second = split.pop().unwrap();
-//                 let three = split.pop().unwrap();
-//                 let four = split.pop().unwrap();
-//                 Some(UiOptionalRect::new(four, second, first, three))
-//             }
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for Display {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "flex" => Some(Display::Flex),
-//             "grid" => Some(Display::Grid),
-//             "none" => Some(Display::None),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for PositionType {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "relative" => Some(PositionType::Relative),
-//             "absolute" => Some(PositionType::Absolute),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for OptionalOverflow {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "visible" | "visible visible" => Some(OptionalOverflow {
-//                 x: Some(OverflowAxis::Visible),
-//                 y: Some(OverflowAxis::Visible),
-//             }),
-//             "visible clip" => Some(OptionalOverflow {
-//                 x: Some(OverflowAxis::Clip),
-//                 y: Some(OverflowAxis::Clip),
-//             }),
-//             "clip" | "clip clip" => Some(OptionalOverflow {
-//                 x: Some(OverflowAxis::Clip),
-//                 y: Some(OverflowAxis::Clip),
-//             }),
-//             "clip visible" => Some(OptionalOverflow {
-//                 x: Some(OverflowAxis::Clip),
-//                 y: Some(OverflowAxis::Clip),
-//             }),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for Direction {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "ltr" => Some(Direction::LeftToRight),
-//             "rtl" => Some(Direction::RightToLeft),
-//             "inherit" => Some(Direction::Inherit),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for AlignItems {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "default" => Some(AlignItems::Default),
-//             "start" => Some(AlignItems::Start),
-//             "end" => Some(AlignItems::End),
-//             "flex-start" => Some(AlignItems::FlexStart),
-//             "flex-end" => Some(AlignItems::FlexEnd),
-//             "center" => Some(AlignItems::Center),
-//             "baseline" => Some(AlignItems::Baseline),
-//             "stretch" => Some(AlignItems::Stretch),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for JustifyItems {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "default" => Some(JustifyItems::Default),
-//             "start" => Some(JustifyItems::Start),
-//             "end" => Some(JustifyItems::End),
-//             "center" => Some(JustifyItems::Center),
-//             "baseline" => Some(JustifyItems::Baseline),
-//             "stretch" => Some(JustifyItems::Stretch),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for AlignSelf {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "auto" => Some(AlignSelf::Auto),
-//             "start" => Some(AlignSelf::Start),
-//             "end" => Some(AlignSelf::End),
-//             "flex-start" => Some(AlignSelf::FlexStart),
-//             "flex-end" => Some(AlignSelf::FlexEnd),
-//             "center" => Some(AlignSelf::Center),
-//             "baseline" => Some(AlignSelf::Baseline),
-//             "stretch" => Some(AlignSelf::Stretch),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for JustifySelf {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "auto" => Some(JustifySelf::Auto),
-//             "start" => Some(JustifySelf::Start),
-//             "end" => Some(JustifySelf::End),
-//             "center" => Some(JustifySelf::Center),
-//             "baseline" => Some(JustifySelf::Baseline),
-//             "stretch" => Some(JustifySelf::Stretch),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for AlignContent {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "default" => Some(AlignContent::Default),
-//             "start" => Some(AlignContent::Start),
-//             "end" => Some(AlignContent::End),
-//             "flex-start" => Some(AlignContent::FlexStart),
-//             "flex-end" => Some(AlignContent::FlexEnd),
-//             "center" => Some(AlignContent::Center),
-//             "stretch" => Some(AlignContent::Stretch),
-//             "space-evenly" => Some(AlignContent::SpaceEvenly),
-//             "space-between" => Some(AlignContent::SpaceBetween),
-//             "space-around" => Some(AlignContent::SpaceAround),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for JustifyContent {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "default" => Some(JustifyContent::Default),
-//             "start" => Some(JustifyContent::Start),
-//             "end" => Some(JustifyContent::End),
-//             "flex-start" => Some(JustifyContent::FlexStart),
-//             "flex-end" => Some(JustifyContent::FlexEnd),
-//             "center" => Some(JustifyContent::Center),
-//             "space-evenly" => Some(JustifyContent::SpaceEvenly),
-//             "space-between" => Some(JustifyContent::SpaceBetween),
-//             "space-around" => Some(JustifyContent::SpaceAround),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for FlexDirection {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "row" => Some(FlexDirection::Row),
-//             "column" => Some(FlexDirection::Column),
-//             "row-reverse" => Some(FlexDirection::RowReverse),
-//             "column-reverse" => Some(FlexDirection::ColumnReverse),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for FlexWrap {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "no-wrap" => Some(FlexWrap::NoWrap),
-//             "wrap" => Some(FlexWrap::Wrap),
-//             "wrap-reverse" => Some(FlexWrap::WrapReverse),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for Visibility {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "visible" => Some(Visibility::Visible),
-//             "hidden" => Some(Visibility::Hidden),
-//             "inherit" => Some(Visibility::Inherited),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for BreakLineOn {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "word-boundary" => Some(BreakLineOn::WordBoundary),
-//             "any-character" => Some(BreakLineOn::AnyCharacter),
-//             "no-wrap" => Some(BreakLineOn::NoWrap),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for TextAlignment {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "left" => Some(TextAlignment::Left),
-//             "center" => Some(TextAlignment::Center),
-//             "right" => Some(TextAlignment::Right),
-//             _ => None,
-//         }
-//     }
-// }
-//
-// impl MyFromStr for OverflowAxis {
-//     fn from_str(s: &str) -> Option<Self> {
-//         match s {
-//             "visible" => Some(OverflowAxis::Visible),
-//             "clip" => Some(OverflowAxis::Clip),
-//             _ => None,
-//         }
-//     }
-// }
+use std::borrow::Cow;
+
+use bevy_ecs::prelude::{Entity, World};
+use bevy_render::prelude::Color;
+use bevy_render::view::Visibility;
+use bevy_text::{BreakLineOn, JustifyText};
+use bevy_ui::{
+   AlignContent, AlignItems, AlignSelf, Direction, Display, FlexDirection, FlexWrap, GridAutoFlow,
+   GridPlacement, JustifyContent, JustifyItems, JustifySelf, OverflowAxis, PositionType,
+   RepeatedGridTrack, Val, ZIndex,
+};
+use bevy_utils::tracing::warn;
+use glam::{Quat, Vec2, Vec3};
+
+use rxy_core::{
+   smallbox, AttrIndex, AttrValue, ElementAttrType, ElementAttrUntyped, HasIndex, NodeTree,
+   SmallBox, S1,
+};
+
+use crate::all_attrs as attrs;
+use crate::elements::{ImageSlice, UiImageScaleMode};
+use crate::renderer::tailwind_colors::tailwind_color;
+use crate::{BevyRenderer, BoxShadow, LinearGradient, RichText};
+
+/// An attribute value produced by a class, e.g. `p-4` produces four padding items.
+#[derive(Debug, Clone)]
+pub struct ClassItem {
+   pub attr_id: AttrIndex,
+   pub value: SmallBox<dyn AttrValue, S1>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClassVariant {
+   Hover,
+   Active,
+   Focus,
+   FocusVisible,
+   Disabled,
+   Checked,
+   Selected,
+   DragOver,
+}
+
+impl ClassVariant {
+   pub fn from_prefix(prefix: &str) -> Option<Self> {
+      match prefix {
+         "hover" => Some(ClassVariant::Hover),
+         "active" => Some(ClassVariant::Active),
+         "focus" => Some(ClassVariant::Focus),
+         "focus-visible" => Some(ClassVariant::FocusVisible),
+         "disabled" => Some(ClassVariant::Disabled),
+         "checked" => Some(ClassVariant::Checked),
+         "selected" => Some(ClassVariant::Selected),
+         "drag-over" => Some(ClassVariant::DragOver),
+         _ => None,
+      }
+   }
+}
+
+/// The parsed items of a class string, grouped by interaction variant.
+#[derive(Default, Debug, Clone)]
+pub struct ClassItems {
+   pub base: Vec<ClassItem>,
+   pub hover: Vec<ClassItem>,
+   pub active: Vec<ClassItem>,
+   pub focus: Vec<ClassItem>,
+   pub focus_visible: Vec<ClassItem>,
+   pub disabled: Vec<ClassItem>,
+   pub checked: Vec<ClassItem>,
+   pub selected: Vec<ClassItem>,
+   pub drag_over: Vec<ClassItem>,
+}
+
+impl ClassItems {
+   /// Parses a whitespace separated list of Tailwind utility classes,
+   /// e.g. `"flex p-4 bg-red-500/50 hover:bg-red-600 w-full"`.
+   ///
+   /// Unknown classes are skipped with a warning. When several classes set the same attribute,
+   /// the last one wins.
+   pub fn parse(classes: &str) -> Self {
+      let mut r = ClassItems::default();
+      for class in classes.split_whitespace() {
+         let (variant, utility) = match class.split_once(':') {
+            Some((prefix, utility)) => {
+               let Some(variant) = ClassVariant::from_prefix(prefix) else {
+                  warn!("unsupported class variant: {}", class);
+                  continue;
+               };
+               (Some(variant), utility)
+            }
+            None => (None, class),
+         };
+         if !parse_class(utility, r.items_mut(variant)) {
+            warn!("unsupported class: {}", class);
+         }
+      }
+      r
+   }
+
+   pub fn items_mut(&mut self, variant: Option<ClassVariant>) -> &mut Vec<ClassItem> {
+      match variant {
+         None => &mut self.base,
+         Some(ClassVariant::Hover) => &mut self.hover,
+         Some(ClassVariant::Active) => &mut self.active,
+         Some(ClassVariant::Focus) => &mut self.focus,
+         Some(ClassVariant::FocusVisible) => &mut self.focus_visible,
+         Some(ClassVariant::Disabled) => &mut self.disabled,
+         Some(ClassVariant::Checked) => &mut self.checked,
+         Some(ClassVariant::Selected) => &mut self.selected,
+         Some(ClassVariant::DragOver) => &mut self.drag_over,
+      }
+   }
+
+   pub fn iter_variants(&self) -> impl Iterator<Item = (Option<ClassVariant>, &[ClassItem])> {
+      [
+         (None, self.base.as_slice()),
+         (Some(ClassVariant::Hover), self.hover.as_slice()),
+         (Some(ClassVariant::Active), self.active.as_slice()),
+         (Some(ClassVariant::Focus), self.focus.as_slice()),
+         (
+            Some(ClassVariant::FocusVisible),
+            self.focus_visible.as_slice(),
+         ),
+         (Some(ClassVariant::Disabled), self.disabled.as_slice()),
+         (Some(ClassVariant::Checked), self.checked.as_slice()),
+         (Some(ClassVariant::Selected), self.selected.as_slice()),
+         (Some(ClassVariant::DragOver), self.drag_over.as_slice()),
+      ]
+      .into_iter()
+   }
+
+   pub fn is_empty(&self) -> bool {
+      self.iter_variants().all(|(_, items)| items.is_empty())
+   }
+}
+
+fn push_item<A>(items: &mut Vec<ClassItem>, _attr: A, value: impl Into<A::Value>)
+where
+   A: ElementAttrType<BevyRenderer>,
+{
+   items.retain(|n| n.attr_id != A::INDEX);
+   items.push(ClassItem {
+      attr_id: A::INDEX,
+      value: smallbox!(value.into()),
+   });
+}
+
+/// Parses a single utility class without variant prefix into `items`.
+/// Returns `false` if the class is not supported.
+pub fn parse_class(class: &str, items: &mut Vec<ClassItem>) -> bool {
+   if parse_keyword_class(class, items) {
+      return true;
+   }
+   let (negative, class) = match class.strip_prefix('-') {
+      Some(class) => (true, class),
+      None => (false, class),
+   };
+   let Some((utility, value)) = split_utility(class) else {
+      return false;
+   };
+   let sign = if negative { -1. } else { 1. };
+   let length = |value: &str| parse_size_val(value).map(|n| negate_val(n, sign));
+
+   match utility {
+      "p" | "px" | "py" | "pt" | "pr" | "pb" | "pl" if !negative => {
+         let Some(val) = parse_size_val(value) else {
+            return false;
+         };
+         if matches!(utility, "p" | "px" | "pl") {
+            push_item(items, attrs::padding_left, val);
+         }
+         if matches!(utility, "p" | "px" | "pr") {
+            push_item(items, attrs::padding_right, val);
+         }
+         if matches!(utility, "p" | "py" | "pt") {
+            push_item(items, attrs::padding_top, val);
+         }
+         if matches!(utility, "p" | "py" | "pb") {
+            push_item(items, attrs::padding_bottom, val);
+         }
+      }
+      "m" | "mx" | "my" | "mt" | "mr" | "mb" | "ml" => {
+         let Some(val) = length(value) else {
+            return false;
+         };
+         if matches!(utility, "m" | "mx" | "ml") {
+            push_item(items, attrs::margin_left, val);
+         }
+         if matches!(utility, "m" | "mx" | "mr") {
+            push_item(items, attrs::margin_right, val);
+         }
+         if matches!(utility, "m" | "my" | "mt") {
+            push_item(items, attrs::margin_top, val);
+         }
+         if matches!(utility, "m" | "my" | "mb") {
+            push_item(items, attrs::margin_bottom, val);
+         }
+      }
+      "inset" | "inset-x" | "inset-y" | "top" | "right" | "bottom" | "left" => {
+         let Some(val) = length(value) else {
+            return false;
+         };
+         if matches!(utility, "inset" | "inset-x" | "left") {
+            push_item(items, attrs::left, val);
+         }
+         if matches!(utility, "inset" | "inset-x" | "right") {
+            push_item(items, attrs::right, val);
+         }
+         if matches!(utility, "inset" | "inset-y" | "top") {
+            push_item(items, attrs::top, val);
+         }
+         if matches!(utility, "inset" | "inset-y" | "bottom") {
+            push_item(items, attrs::bottom, val);
+         }
+      }
+      "gap" | "gap-x" | "gap-y" if !negative => {
+         let Some(val) = parse_size_val(value) else {
+            return false;
+         };
+         if matches!(utility, "gap" | "gap-x") {
+            push_item(items, attrs::column_gap, val);
+         }
+         if matches!(utility, "gap" | "gap-y") {
+            push_item(items, attrs::row_gap, val);
+         }
+      }
+      "w" | "min-w" | "max-w" | "h" | "min-h" | "max-h" | "size" | "basis" if !negative => {
+         let val = match (utility, value) {
+            ("w" | "min-w" | "max-w", "screen") => Some(Val::Vw(100.)),
+            ("h" | "min-h" | "max-h", "screen") => Some(Val::Vh(100.)),
+            ("max-w", value) => parse_max_width_val(value).or_else(|| parse_size_val(value)),
+            (_, value) => parse_size_val(value),
+         };
+         let Some(val) = val else {
+            return false;
+         };
+         match utility {
+            "w" => push_item(items, attrs::width, val),
+            "min-w" => push_item(items, attrs::min_width, val),
+            "max-w" => push_item(items, attrs::max_width, val),
+            "h" => push_item(items, attrs::height, val),
+            "min-h" => push_item(items, attrs::min_height, val),
+            "max-h" => push_item(items, attrs::max_height, val),
+            "basis" => push_item(items, attrs::flex_basis, val),
+            _ => {
+               push_item(items, attrs::width, val);
+               push_item(items, attrs::height, val);
+            }
+         }
+      }
+      "border" | "border-x" | "border-y" | "border-t" | "border-r" | "border-b" | "border-l"
+         if !negative =>
+      {
+         if let Some(val) = parse_px_val(value) {
+            if matches!(utility, "border" | "border-x" | "border-l") {
+               push_item(items, attrs::border_left, val);
+            }
+            if matches!(utility, "border" | "border-x" | "border-r") {
+               push_item(items, attrs::border_right, val);
+            }
+            if matches!(utility, "border" | "border-y" | "border-t") {
+               push_item(items, attrs::border_top, val);
+            }
+            if matches!(utility, "border" | "border-y" | "border-b") {
+               push_item(items, attrs::border_bottom, val);
+            }
+         } else if let ("border", Some(color)) = (utility, parse_color(value)) {
+            push_item(items, attrs::border_color, color);
+         } else {
+            return false;
+         }
+      }
+      "outline" if !negative => {
+         if value == "none" {
+            push_item(items, attrs::outline_width, Val::Px(0.));
+         } else if let Some(val) = parse_px_val(value) {
+            push_item(items, attrs::outline_width, val);
+         } else if let Some(color) = parse_color(value) {
+            push_item(items, attrs::outline_color, color);
+         } else {
+            return false;
+         }
+      }
+      "outline-offset" => {
+         let Some(val) = parse_px_val(value) else {
+            return false;
+         };
+         push_item(items, attrs::outline_offset, negate_val(val, sign));
+      }
+      "rounded" if !negative => {
+         let Some(radius) = parse_radius_val(value) else {
+            return false;
+         };
+         push_item(items, attrs::border_radius, radius);
+      }
+      "shadow" if !negative => {
+         let shadow = match value {
+            "sm" => BoxShadow::SM,
+            "md" => BoxShadow::MD,
+            "lg" => BoxShadow::LG,
+            "xl" => BoxShadow::XL,
+            "2xl" => BoxShadow::XL2,
+            "none" => BoxShadow::NONE,
+            value => {
+               // keeps the shape of the shadow, e.g. `shadow-lg shadow-red-500/50`
+               let Some(color) = parse_color(value) else {
+                  return false;
+               };
+               let shadow = find_item_value::<BoxShadow>(items, attrs::box_shadow::INDEX)
+                  .unwrap_or(BoxShadow::BASE);
+               BoxShadow { color, ..shadow }
+            }
+         };
+         push_item(items, attrs::box_shadow, shadow);
+      }
+      "bg-gradient-to" if !negative => {
+         let direction = match value {
+            "t" => "top",
+            "tr" => "top right",
+            "r" => "right",
+            "br" => "bottom right",
+            "b" => "bottom",
+            "bl" => "bottom left",
+            "l" => "left",
+            "tl" => "top left",
+            _ => return false,
+         };
+         let mut gradient =
+            find_item_value::<LinearGradient>(items, attrs::bg_gradient::INDEX).unwrap_or_default();
+         gradient.angle = gradient_direction_angle(direction).unwrap();
+         push_item(items, attrs::bg_gradient, gradient);
+      }
+      "from" | "to" if !negative => {
+         let Some(color) = parse_color(value) else {
+            return false;
+         };
+         let mut gradient =
+            find_item_value::<LinearGradient>(items, attrs::bg_gradient::INDEX).unwrap_or_default();
+         if utility == "from" {
+            gradient.start = color;
+         } else {
+            gradient.end = color;
+         }
+         push_item(items, attrs::bg_gradient, gradient);
+      }
+      "bg" if !negative => {
+         let Some(color) = parse_color(value) else {
+            return false;
+         };
+         push_item(items, attrs::bg_color, color);
+      }
+      "text" if !negative => {
+         if let Some(size) = parse_font_size(value) {
+            push_item(items, attrs::font_size, size);
+         } else if let Some(align) = from_str::<JustifyText>(value) {
+            push_item(items, attrs::text_align, align);
+         } else if let Some(color) = parse_color(value) {
+            push_item(items, attrs::text_color, color);
+         } else {
+            return false;
+         }
+      }
+      "z" => {
+         let z = match value {
+            "auto" => 0,
+            value => match value.parse::<i32>() {
+               Ok(z) => z,
+               Err(_) => return false,
+            },
+         };
+         push_item(
+            items,
+            attrs::z_index,
+            ZIndex::Local(if negative { -z } else { z }),
+         );
+      }
+      "aspect" if !negative => {
+         let ratio = match value {
+            "auto" => None,
+            "square" => Some(1.),
+            "video" => Some(16. / 9.),
+            value => match arbitrary_value(value).and_then(parse_fraction) {
+               Some(ratio) => Some(ratio),
+               None => return false,
+            },
+         };
+         push_item(items, attrs::aspect_ratio, ratio);
+      }
+      "items" if !negative => {
+         let Some(value) = from_str::<AlignItems>(flex_alias(value)) else {
+            return false;
+         };
+         push_item(items, attrs::align_items, value);
+      }
+      "justify" if !negative => {
+         let Some(value) = from_str::<JustifyContent>(flex_alias(value)) else {
+            return false;
+         };
+         push_item(items, attrs::justify_content, value);
+      }
+      "content" if !negative => {
+         let Some(value) = from_str::<AlignContent>(flex_alias(value)) else {
+            return false;
+         };
+         push_item(items, attrs::align_content, value);
+      }
+      "self" if !negative => {
+         let Some(value) = from_str::<AlignSelf>(flex_alias(value)) else {
+            return false;
+         };
+         push_item(items, attrs::align_self, value);
+      }
+      "justify-items" if !negative => {
+         let Some(value) = from_str::<JustifyItems>(value) else {
+            return false;
+         };
+         push_item(items, attrs::justify_items, value);
+      }
+      "justify-self" if !negative => {
+         let Some(value) = from_str::<JustifySelf>(value) else {
+            return false;
+         };
+         push_item(items, attrs::justify_self, value);
+      }
+      "flex" if !negative => {
+         let (grow, shrink, basis) = match value {
+            "1" => (1., 1., Val::Percent(0.)),
+            "auto" => (1., 1., Val::Auto),
+            "initial" => (0., 1., Val::Auto),
+            "none" => (0., 0., Val::Auto),
+            _ => return false,
+         };
+         push_item(items, attrs::flex_grow, grow);
+         push_item(items, attrs::flex_shrink, shrink);
+         push_item(items, attrs::flex_basis, basis);
+      }
+      "grow" | "shrink" if !negative => {
+         let Some(factor) = value
+            .parse::<f32>()
+            .ok()
+            .or_else(|| arbitrary_value(value)?.parse().ok())
+         else {
+            return false;
+         };
+         if utility == "grow" {
+            push_item(items, attrs::flex_grow, factor);
+         } else {
+            push_item(items, attrs::flex_shrink, factor);
+         }
+      }
+      "overflow" | "overflow-x" | "overflow-y" if !negative => {
+         let Some(overflow) = from_str::<OverflowAxis>(value) else {
+            return false;
+         };
+         if matches!(utility, "overflow" | "overflow-x") {
+            push_item(items, attrs::overflow_x, overflow);
+         }
+         if matches!(utility, "overflow" | "overflow-y") {
+            push_item(items, attrs::overflow_y, overflow);
+         }
+      }
+      "grid-cols" | "grid-rows" if !negative => {
+         let tracks = match value {
+            "none" => vec![],
+            value => match value.parse::<u16>() {
+               Ok(count) if count > 0 => vec![RepeatedGridTrack::flex(count, 1.)],
+               _ => return false,
+            },
+         };
+         if utility == "grid-cols" {
+            push_item(items, attrs::grid_template_columns, tracks);
+         } else {
+            push_item(items, attrs::grid_template_rows, tracks);
+         }
+      }
+      "col-span" | "row-span" if !negative => {
+         let placement = match value {
+            "full" => GridPlacement::start_end(1, -1),
+            value => match value.parse::<u16>() {
+               Ok(span) if span > 0 => GridPlacement::span(span),
+               _ => return false,
+            },
+         };
+         if utility == "col-span" {
+            push_item(items, attrs::grid_column, placement);
+         } else {
+            push_item(items, attrs::grid_row, placement);
+         }
+      }
+      "scale" => {
+         let Ok(percent) = value.parse::<f32>() else {
+            return false;
+         };
+         push_item(items, attrs::scale, Vec3::splat(sign * percent / 100.));
+      }
+      "rotate" => {
+         let degrees = match value.parse::<f32>() {
+            Ok(degrees) => degrees,
+            Err(_) => match arbitrary_value(value).and_then(|n| n.strip_suffix("deg")) {
+               Some(degrees) => match degrees.parse::<f32>() {
+                  Ok(degrees) => degrees,
+                  Err(_) => return false,
+               },
+               None => return false,
+            },
+         };
+         // tailwind rotates clockwise, while positive rotations of bevy ui are counterclockwise
+         push_item(
+            items,
+            attrs::rotation,
+            Quat::from_rotation_z(-sign * degrees.to_radians()),
+         );
+      }
+      _ => return false,
+   }
+   true
+}
+
+fn parse_keyword_class(class: &str, items: &mut Vec<ClassItem>) -> bool {
+   match class {
+      "flex" | "grid" | "hidden" => {
+         let value = match class {
+            "flex" => Display::Flex,
+            "grid" => Display::Grid,
+            _ => Display::None,
+         };
+         push_item(items, attrs::display, value);
+      }
+      "flex-row" | "flex-row-reverse" | "flex-col" | "flex-col-reverse" => {
+         let value = match class {
+            "flex-row" => FlexDirection::Row,
+            "flex-row-reverse" => FlexDirection::RowReverse,
+            "flex-col" => FlexDirection::Column,
+            _ => FlexDirection::ColumnReverse,
+         };
+         push_item(items, attrs::flex_direction, value);
+      }
+      "flex-wrap" | "flex-wrap-reverse" | "flex-nowrap" => {
+         let value = match class {
+            "flex-wrap" => FlexWrap::Wrap,
+            "flex-wrap-reverse" => FlexWrap::WrapReverse,
+            _ => FlexWrap::NoWrap,
+         };
+         push_item(items, attrs::flex_wrap, value);
+      }
+      "grow" => push_item(items, attrs::flex_grow, 1.),
+      "shrink" => push_item(items, attrs::flex_shrink, 1.),
+      "relative" => push_item(items, attrs::position_type, PositionType::Relative),
+      "absolute" => push_item(items, attrs::position_type, PositionType::Absolute),
+      "visible" => push_item(items, attrs::visibility, Visibility::Visible),
+      "invisible" => push_item(items, attrs::visibility, Visibility::Hidden),
+      "border" => {
+         push_item(items, attrs::border_left, Val::Px(1.));
+         push_item(items, attrs::border_right, Val::Px(1.));
+         push_item(items, attrs::border_top, Val::Px(1.));
+         push_item(items, attrs::border_bottom, Val::Px(1.));
+      }
+      "outline" => push_item(items, attrs::outline_width, Val::Px(1.)),
+      "rounded" => push_item(items, attrs::border_radius, Val::Px(4.)),
+      "shadow" => push_item(items, attrs::box_shadow, BoxShadow::BASE),
+      "whitespace-nowrap" => push_item(items, attrs::text_linebreak, BreakLineOn::NoWrap),
+      "break-words" | "break-normal" => {
+         push_item(items, attrs::text_linebreak, BreakLineOn::WordBoundary)
+      }
+      "break-all" => push_item(items, attrs::text_linebreak, BreakLineOn::AnyCharacter),
+      "grid-flow-row" => push_item(items, attrs::grid_auto_flow, GridAutoFlow::Row),
+      "grid-flow-col" => push_item(items, attrs::grid_auto_flow, GridAutoFlow::Column),
+      "grid-flow-row-dense" => push_item(items, attrs::grid_auto_flow, GridAutoFlow::RowDense),
+      "grid-flow-col-dense" => push_item(items, attrs::grid_auto_flow, GridAutoFlow::ColumnDense),
+      "ltr" => push_item(items, attrs::direction, Direction::LeftToRight),
+      "rtl" => push_item(items, attrs::direction, Direction::RightToLeft),
+      _ => return false,
+   }
+   true
+}
+
+/// Utilities taking a value, more specific prefixes first.
+const UTILITIES: &[&str] = &[
+   "bg-gradient-to",
+   "outline-offset",
+   "justify-items",
+   "justify-self",
+   "overflow-x",
+   "overflow-y",
+   "grid-cols",
+   "grid-rows",
+   "col-span",
+   "row-span",
+   "border-x",
+   "border-y",
+   "border-t",
+   "border-r",
+   "border-b",
+   "border-l",
+   "inset-x",
+   "inset-y",
+   "min-w",
+   "min-h",
+   "max-w",
+   "max-h",
+   "gap-x",
+   "gap-y",
+   "overflow",
+   "outline",
+   "rounded",
+   "justify",
+   "content",
+   "border",
+   "aspect",
+   "bottom",
+   "inset",
+   "right",
+   "basis",
+   "shrink",
+   "rotate",
+   "shadow",
+   "scale",
+   "items",
+   "self",
+   "flex",
+   "grow",
+   "size",
+   "text",
+   "left",
+   "from",
+   "top",
+   "gap",
+   "bg",
+   "to",
+   "px",
+   "py",
+   "pt",
+   "pr",
+   "pb",
+   "pl",
+   "mx",
+   "my",
+   "mt",
+   "mr",
+   "mb",
+   "ml",
+   "p",
+   "m",
+   "w",
+   "h",
+   "z",
+];
+
+fn split_utility(class: &str) -> Option<(&str, &str)> {
+   UTILITIES.iter().find_map(|utility| {
+      let value = class.strip_prefix(utility)?.strip_prefix('-')?;
+      (!value.is_empty()).then_some((*utility, value))
+   })
+}
+
+/// Maps tailwind flexbox aliases like `between` to their css names.
+fn flex_alias(value: &str) -> &str {
+   match value {
+      "start" => "flex-start",
+      "end" => "flex-end",
+      "between" => "space-between",
+      "around" => "space-around",
+      "evenly" => "space-evenly",
+      value => value,
+   }
+}
+
+/// The value of an item pushed before, e.g. the gradient set by `from-*` for `to-*`.
+fn find_item_value<T: AttrValue + Copy>(items: &[ClassItem], attr_id: AttrIndex) -> Option<T> {
+   items
+      .iter()
+      .find(|n| n.attr_id == attr_id)
+      .and_then(|n| n.value.as_any().downcast_ref::<T>().copied())
+}
+
+/// Parses the tailwind border radius like `lg` for `rounded-lg`.
+fn parse_radius_val(value: &str) -> Option<Val> {
+   let px = match value {
+      "none" => 0.,
+      "sm" => 2.,
+      "md" => 6.,
+      "lg" => 8.,
+      "xl" => 12.,
+      "2xl" => 16.,
+      "3xl" => 24.,
+      "full" => 9999.,
+      value => return parse_css_val(arbitrary_value(value)?),
+   };
+   Some(Val::Px(px))
+}
+
+/// The css angle of a gradient direction like `top right` in `to top right`.
+fn gradient_direction_angle(direction: &str) -> Option<f32> {
+   let angle = match direction {
+      "top" => 0.,
+      "top right" | "right top" => 45.,
+      "right" => 90.,
+      "bottom right" | "right bottom" => 135.,
+      "bottom" => 180.,
+      "bottom left" | "left bottom" => 225.,
+      "left" => 270.,
+      "top left" | "left top" => 315.,
+      _ => return None,
+   };
+   Some(angle)
+}
+
+fn negate_val(val: Val, sign: f32) -> Val {
+   match val {
+      Val::Px(n) => Val::Px(n * sign),
+      Val::Percent(n) => Val::Percent(n * sign),
+      Val::Vw(n) => Val::Vw(n * sign),
+      Val::Vh(n) => Val::Vh(n * sign),
+      Val::VMin(n) => Val::VMin(n * sign),
+      Val::VMax(n) => Val::VMax(n * sign),
+      Val::Auto => Val::Auto,
+   }
+}
+
+fn arbitrary_value(value: &str) -> Option<&str> {
+   value.strip_prefix('[')?.strip_suffix(']')
+}
+
+fn parse_fraction(value: &str) -> Option<f32> {
+   let (numerator, denominator) = value.split_once('/')?;
+   let denominator = denominator.parse::<f32>().ok()?;
+   (denominator != 0.).then_some(numerator.parse::<f32>().ok()? / denominator)
+}
+
+/// Parses a css length like `12px`, `50%`, `10vw` or `1.5rem`. Unitless numbers are pixels.
+pub fn parse_css_val(value: &str) -> Option<Val> {
+   let number = |suffix: &str| value.strip_suffix(suffix)?.parse::<f32>().ok();
+   if value == "auto" {
+      Some(Val::Auto)
+   } else if let Some(n) = number("px") {
+      Some(Val::Px(n))
+   } else if let Some(n) = number("%") {
+      Some(Val::Percent(n))
+   } else if let Some(n) = number("vw") {
+      Some(Val::Vw(n))
+   } else if let Some(n) = number("vh") {
+      Some(Val::Vh(n))
+   } else if let Some(n) = number("vmin") {
+      Some(Val::VMin(n))
+   } else if let Some(n) = number("vmax") {
+      Some(Val::VMax(n))
+   } else if let Some(n) = number("rem") {
+      Some(Val::Px(n * 16.))
+   } else {
+      value.parse::<f32>().ok().map(Val::Px)
+   }
+}
+
+/// Parses a value of the tailwind spacing scale, where `1` is `4px`.
+/// Also accepts `px`, `auto`, `full`, fractions like `1/2` and arbitrary values like `[12px]`.
+pub fn parse_size_val(value: &str) -> Option<Val> {
+   match value {
+      "px" => Some(Val::Px(1.)),
+      "auto" => Some(Val::Auto),
+      "full" => Some(Val::Percent(100.)),
+      value => {
+         if let Some(value) = arbitrary_value(value) {
+            parse_css_val(value)
+         } else if let Some(fraction) = parse_fraction(value) {
+            Some(Val::Percent(fraction * 100.))
+         } else {
+            value.parse::<f32>().ok().map(|n| Val::Px(n * 4.))
+         }
+      }
+   }
+}
+
+/// Border and outline widths are in pixels, e.g. `border-2` is `2px`.
+fn parse_px_val(value: &str) -> Option<Val> {
+   match arbitrary_value(value) {
+      Some(value) => parse_css_val(value),
+      None => value.parse::<f32>().ok().map(Val::Px),
+   }
+}
+
+fn parse_max_width_val(value: &str) -> Option<Val> {
+   let px = match value {
+      "none" => return Some(Val::Auto),
+      "xs" => 320.,
+      "sm" => 384.,
+      "md" => 448.,
+      "lg" => 512.,
+      "xl" => 576.,
+      "2xl" => 672.,
+      "3xl" => 768.,
+      "4xl" => 896.,
+      "5xl" => 1024.,
+      "6xl" => 1152.,
+      "7xl" => 1280.,
+      _ => return None,
+   };
+   Some(Val::Px(px))
+}
+
+fn parse_font_size(value: &str) -> Option<f32> {
+   match value {
+      "xs" => Some(12.),
+      "sm" => Some(14.),
+      "base" => Some(16.),
+      "lg" => Some(18.),
+      "xl" => Some(20.),
+      "2xl" => Some(24.),
+      "3xl" => Some(30.),
+      "4xl" => Some(36.),
+      "5xl" => Some(48.),
+      "6xl" => Some(60.),
+      "7xl" => Some(72.),
+      "8xl" => Some(96.),
+      "9xl" => Some(128.),
+      value => match parse_css_val(arbitrary_value(value)?)? {
+         Val::Px(n) => Some(n),
+         _ => None,
+      },
+   }
+}
+
+/// Parses a color of the tailwind palette like `red-500`, `white`, `transparent`
+/// or an arbitrary hex color like `[#1e293b]`, with an optional opacity like `red-500/50`.
+pub fn parse_color(class: &str) -> Option<Color> {
+   let (color, alpha) = match class.split_once('/') {
+      Some((color, alpha)) => {
+         let alpha = match arbitrary_value(alpha) {
+            Some(alpha) => alpha.parse::<f32>().ok()?,
+            None => alpha.parse::<f32>().ok()? / 100.,
+         };
+         (color, alpha.clamp(0., 1.))
+      }
+      None => (class, 1.),
+   };
+
+   let color = if let Some(hex) = arbitrary_value(color) {
+      Color::hex(hex).ok()?
+   } else {
+      match color {
+         "transparent" => Color::NONE,
+         "white" => Color::WHITE,
+         "black" => Color::BLACK,
+         color => {
+            let (color_name, shade) = color.rsplit_once('-')?;
+            tailwind_color(color_name, shade.parse().ok()?)?
+         }
+      }
+   };
+   let alpha = color.a() * alpha;
+   Some(color.with_a(alpha))
+}
+
+/// Parses a css color like `#1e293b` or a color of the tailwind palette like `red-500`.
+pub fn parse_css_color(value: &str) -> Option<Color> {
+   match value.strip_prefix('#') {
+      Some(hex) => Color::hex(hex).ok(),
+      None => parse_color(value),
+   }
+}
+
+/// Parses a css value for the attribute, e.g. `12px` for `width` or `#ff0000` for `bg_color`.
+/// The parser is chosen by the value type of the attribute.
+pub fn parse_attr_value(
+   attr: &dyn ElementAttrUntyped<BevyRenderer>,
+   value: &str,
+) -> Option<SmallBox<dyn AttrValue, S1>> {
+   let default_value = attr.default_value();
+   let default_value = default_value.as_any();
+
+   macro_rules! parse_as {
+      ($($ty:ty => $parse:expr),* $(,)?) => {
+         $(
+            if default_value.is::<$ty>() {
+               let parse: fn(&str) -> Option<$ty> = $parse;
+               return parse(value).map(|n| smallbox!(n));
+            }
+         )*
+      };
+   }
+
+   parse_as!(
+      Val => parse_css_val,
+      Color => parse_css_color,
+      f32 => |n| n.strip_suffix("px").unwrap_or(n).parse().ok(),
+      bool => |n| n.parse().ok(),
+      usize => |n| n.parse().ok(),
+      Option<f32> => |n| match n {
+         "none" | "auto" => Some(None),
+         n => n.parse().ok().map(Some),
+      },
+      Cow<'static, str> => |n| Some(n.trim_matches('"').to_string().into()),
+      RichText => |n| Some(RichText::parse(n.trim_matches('"'))),
+      ZIndex => |n| n.parse().ok().map(ZIndex::Local),
+      Vec3 => |n| {
+         let values = n
+            .split([' ', ','])
+            .filter(|n| !n.is_empty())
+            .map(|n| n.parse::<f32>().ok())
+            .collect::<Option<Vec<_>>>()?;
+         match values[..] {
+            [n] => Some(Vec3::splat(n)),
+            [x, y, z] => Some(Vec3::new(x, y, z)),
+            _ => None,
+         }
+      },
+      Quat => |n| {
+         let degrees = n.strip_suffix("deg").unwrap_or(n).parse::<f32>().ok()?;
+         Some(Quat::from_rotation_z(degrees.to_radians()))
+      },
+      Display => from_str,
+      PositionType => from_str,
+      Direction => from_str,
+      AlignItems => from_str,
+      JustifyItems => from_str,
+      AlignSelf => from_str,
+      JustifySelf => from_str,
+      AlignContent => from_str,
+      JustifyContent => from_str,
+      FlexDirection => from_str,
+      FlexWrap => from_str,
+      Visibility => from_str,
+      BreakLineOn => from_str,
+      JustifyText => from_str,
+      OverflowAxis => from_str,
+      BoxShadow => from_str,
+      LinearGradient => from_str,
+      UiImageScaleMode => from_str,
+      ImageSlice => from_str,
+   );
+   None
+}
+
+pub(crate) trait MyFromStr: Sized {
+   fn from_str(s: &str) -> Option<Self>;
+}
+
+pub(crate) fn from_str<T: MyFromStr>(s: &str) -> Option<T> {
+   T::from_str(s)
+}
+
+impl MyFromStr for Color {
+   fn from_str(s: &str) -> Option<Self> {
+      parse_color(s)
+   }
+}
+
+/// Parses a css `box-shadow` like `0 4px 6px -1px #0000001a`.
+impl MyFromStr for BoxShadow {
+   fn from_str(s: &str) -> Option<Self> {
+      if s == "none" {
+         return Some(BoxShadow::NONE);
+      }
+      let mut lengths = vec![];
+      let mut color = Color::BLACK;
+      for token in s.split_whitespace() {
+         match token.strip_suffix("px").unwrap_or(token).parse::<f32>() {
+            Ok(n) => lengths.push(n),
+            Err(_) => color = parse_css_color(token)?,
+         }
+      }
+      let (x, y, blur, spread) = match lengths[..] {
+         [x, y] => (x, y, 0., 0.),
+         [x, y, blur] => (x, y, blur, 0.),
+         [x, y, blur, spread] => (x, y, blur, spread),
+         _ => return None,
+      };
+      Some(BoxShadow::new(color, Vec2::new(x, y), blur, spread))
+   }
+}
+
+/// Parses a css `linear-gradient` without the function, like `90deg, #ffffff, #000000` or `to right, red-500, blue-500`.
+impl MyFromStr for LinearGradient {
+   fn from_str(s: &str) -> Option<Self> {
+      if s == "none" {
+         return Some(LinearGradient::default());
+      }
+      let parts = s.split(',').map(str::trim).collect::<Vec<_>>();
+      let (angle, colors) = match parts[..] {
+         [angle, start, end] => {
+            let angle = match angle.strip_prefix("to ") {
+               Some(direction) => gradient_direction_angle(direction)?,
+               None => angle.strip_suffix("deg")?.parse().ok()?,
+            };
+            (angle, [start, end])
+         }
+         [start, end] => (180., [start, end]),
+         _ => return None,
+      };
+      Some(LinearGradient::new(
+         angle,
+         parse_css_color(colors[0])?,
+         parse_css_color(colors[1])?,
+      ))
+   }
+}
+
+impl MyFromStr for UiImageScaleMode {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "fill" => Some(UiImageScaleMode::Fill),
+         "contain" => Some(UiImageScaleMode::Contain),
+         "cover" => Some(UiImageScaleMode::Cover),
+         "tile" => Some(UiImageScaleMode::Tile),
+         "sliced" => Some(UiImageScaleMode::Sliced),
+         _ => None,
+      }
+   }
+}
+
+/// Parses the borders like css `border-image-slice`, e.g. `16` or `8 16` or `8 16 8 16` for top, right, bottom and left.
+impl MyFromStr for ImageSlice {
+   fn from_str(s: &str) -> Option<Self> {
+      let values = s
+         .split_whitespace()
+         .map(|n| n.strip_suffix("px").unwrap_or(n).parse::<f32>().ok())
+         .collect::<Option<Vec<_>>>()?;
+      let (top, right, bottom, left) = match values[..] {
+         [n] => (n, n, n, n),
+         [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+         [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+         [top, right, bottom, left] => (top, right, bottom, left),
+         _ => return None,
+      };
+      Some(ImageSlice {
+         left,
+         right,
+         top,
+         bottom,
+      })
+   }
+}
+
+impl MyFromStr for Display {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "flex" => Some(Display::Flex),
+         "grid" => Some(Display::Grid),
+         "none" => Some(Display::None),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for PositionType {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "relative" => Some(PositionType::Relative),
+         "absolute" => Some(PositionType::Absolute),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for Direction {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "ltr" => Some(Direction::LeftToRight),
+         "rtl" => Some(Direction::RightToLeft),
+         "inherit" => Some(Direction::Inherit),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for AlignItems {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "default" => Some(AlignItems::Default),
+         "start" => Some(AlignItems::Start),
+         "end" => Some(AlignItems::End),
+         "flex-start" => Some(AlignItems::FlexStart),
+         "flex-end" => Some(AlignItems::FlexEnd),
+         "center" => Some(AlignItems::Center),
+         "baseline" => Some(AlignItems::Baseline),
+         "stretch" => Some(AlignItems::Stretch),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for JustifyItems {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "default" => Some(JustifyItems::Default),
+         "start" => Some(JustifyItems::Start),
+         "end" => Some(JustifyItems::End),
+         "center" => Some(JustifyItems::Center),
+         "baseline" => Some(JustifyItems::Baseline),
+         "stretch" => Some(JustifyItems::Stretch),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for AlignSelf {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "auto" => Some(AlignSelf::Auto),
+         "start" => Some(AlignSelf::Start),
+         "end" => Some(AlignSelf::End),
+         "flex-start" => Some(AlignSelf::FlexStart),
+         "flex-end" => Some(AlignSelf::FlexEnd),
+         "center" => Some(AlignSelf::Center),
+         "baseline" => Some(AlignSelf::Baseline),
+         "stretch" => Some(AlignSelf::Stretch),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for JustifySelf {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "auto" => Some(JustifySelf::Auto),
+         "start" => Some(JustifySelf::Start),
+         "end" => Some(JustifySelf::End),
+         "center" => Some(JustifySelf::Center),
+         "baseline" => Some(JustifySelf::Baseline),
+         "stretch" => Some(JustifySelf::Stretch),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for AlignContent {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "default" => Some(AlignContent::Default),
+         "start" => Some(AlignContent::Start),
+         "end" => Some(AlignContent::End),
+         "flex-start" => Some(AlignContent::FlexStart),
+         "flex-end" => Some(AlignContent::FlexEnd),
+         "center" => Some(AlignContent::Center),
+         "stretch" => Some(AlignContent::Stretch),
+         "space-evenly" => Some(AlignContent::SpaceEvenly),
+         "space-between" => Some(AlignContent::SpaceBetween),
+         "space-around" => Some(AlignContent::SpaceAround),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for JustifyContent {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "default" => Some(JustifyContent::Default),
+         "start" => Some(JustifyContent::Start),
+         "end" => Some(JustifyContent::End),
+         "flex-start" => Some(JustifyContent::FlexStart),
+         "flex-end" => Some(JustifyContent::FlexEnd),
+         "center" => Some(JustifyContent::Center),
+         "space-evenly" => Some(JustifyContent::SpaceEvenly),
+         "space-between" => Some(JustifyContent::SpaceBetween),
+         "space-around" => Some(JustifyContent::SpaceAround),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for FlexDirection {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "row" => Some(FlexDirection::Row),
+         "column" => Some(FlexDirection::Column),
+         "row-reverse" => Some(FlexDirection::RowReverse),
+         "column-reverse" => Some(FlexDirection::ColumnReverse),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for FlexWrap {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "no-wrap" => Some(FlexWrap::NoWrap),
+         "wrap" => Some(FlexWrap::Wrap),
+         "wrap-reverse" => Some(FlexWrap::WrapReverse),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for Visibility {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "visible" => Some(Visibility::Visible),
+         "hidden" => Some(Visibility::Hidden),
+         "inherit" => Some(Visibility::Inherited),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for BreakLineOn {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "word-boundary" => Some(BreakLineOn::WordBoundary),
+         "any-character" => Some(BreakLineOn::AnyCharacter),
+         "no-wrap" => Some(BreakLineOn::NoWrap),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for JustifyText {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "left" => Some(JustifyText::Left),
+         "center" => Some(JustifyText::Center),
+         "right" => Some(JustifyText::Right),
+         _ => None,
+      }
+   }
+}
+
+impl MyFromStr for OverflowAxis {
+   fn from_str(s: &str) -> Option<Self> {
+      match s {
+         "visible" => Some(OverflowAxis::Visible),
+         "clip" | "hidden" => Some(OverflowAxis::Clip),
+         _ => None,
+      }
+   }
+}
+
+#[cfg(feature = "style")]
+mod class_style_sheets {
+   use bevy_utils::tracing::error;
+   use rxy_core::style::{
+      AppliedStyleSheet, ApplyStyleSheetsMemberState, StyleInteraction, StyleItemValue,
+      StyleSheetCtx, StyleSheetDefinition, StyleSheets, StyleSheetsInfo, StyledNodeTree,
+   };
+
+   use super::*;
+
+   /// The inline style sheet slots reserved for the classes of the node.
+   pub(super) struct ClassStyleSheetsState(pub ApplyStyleSheetsMemberState);
+
+   impl From<ClassVariant> for StyleInteraction {
+      fn from(value: ClassVariant) -> Self {
+         match value {
+            ClassVariant::Hover => StyleInteraction::Hover,
+            ClassVariant::Active => StyleInteraction::Active,
+            ClassVariant::Focus => StyleInteraction::Focus,
+            ClassVariant::FocusVisible => StyleInteraction::FocusVisible,
+            ClassVariant::Disabled => StyleInteraction::Disabled,
+            ClassVariant::Checked => StyleInteraction::Checked,
+            ClassVariant::Selected => StyleInteraction::Selected,
+            ClassVariant::DragOver => StyleInteraction::DragOver,
+         }
+      }
+   }
+
+   impl StyleSheets<BevyRenderer> for ClassItems {
+      fn style_sheets(
+         self,
+         _ctx: StyleSheetCtx<BevyRenderer>,
+      ) -> (
+         impl Iterator<Item = AppliedStyleSheet<BevyRenderer>> + Send + 'static,
+         StyleSheetsInfo,
+      ) {
+         // empty style sheets are not stored on the node, so they must not be counted either
+         let style_sheets = self
+            .iter_variants()
+            .filter(|(_, items)| !items.is_empty())
+            .map(|(variant, items)| StyleSheetDefinition {
+               interaction: variant.map(Into::into),
+               items: items
+                  .iter()
+                  .map(|item| StyleItemValue {
+                     attr_id: item.attr_id,
+                     value: item.value.clone(),
+                  })
+                  .collect(),
+            })
+            .collect::<Vec<_>>();
+         let info = StyleSheetsInfo {
+            inline_style_sheet_count: style_sheets.len() as _,
+            shared_style_sheet_count: 0,
+         };
+         (
+            style_sheets.into_iter().map(AppliedStyleSheet::Inline),
+            info,
+         )
+      }
+   }
+
+   pub(crate) fn apply_classes(world: &mut World, node_id: Entity, classes: &str) {
+      let previous = world
+         .take_node_state::<ClassStyleSheetsState>(&node_id)
+         .map(|ClassStyleSheetsState(member_state)| member_state);
+      if let Some(member_state) = previous {
+         if let Err(err) = world.unbuild_style_sheet(node_id, member_state) {
+            error!("unbuild class style sheets error: {:?}", err);
+            return;
+         }
+      }
+      let items = ClassItems::parse(classes);
+      let inline_sheet_count = items
+         .iter_variants()
+         .filter(|(_, items)| !items.is_empty())
+         .count() as _;
+      // the previous slots are reused while the classes fit in them, so the style sheets of the
+      // node grow at most up to the number of variants
+      let reserved = previous.filter(|n| inline_sheet_count <= n.inline_sheet_count);
+      if reserved.is_none() && items.is_empty() {
+         return;
+      }
+      let member_state = reserved.map(|n| ApplyStyleSheetsMemberState {
+         inline_sheet_count,
+         ..n
+      });
+      match world.build_style_sheets(node_id, items, member_state) {
+         Ok(member_state) => {
+            world.set_node_state(
+               &node_id,
+               ClassStyleSheetsState(reserved.unwrap_or(member_state)),
+            );
+         }
+         Err(err) => {
+            error!("build class style sheets error: {:?}", err);
+            if let Some(reserved) = reserved {
+               world.set_node_state(&node_id, ClassStyleSheetsState(reserved));
+            }
+         }
+      }
+   }
+}
+
+#[cfg(feature = "style")]
+pub(crate) use class_style_sheets::apply_classes;
+
+#[cfg(not(feature = "style"))]
+#[derive(Default)]
+struct ClassAttrsState(Vec<AttrIndex>);
+
+/// Without style sheets, base classes are applied as attribute values directly
+/// and interaction variants are ignored.
+#[cfg(not(feature = "style"))]
+pub(crate) fn apply_classes(world: &mut World, node_id: Entity, classes: &str) {
+   let items = ClassItems::parse(classes);
+   if items.iter_variants().skip(1).any(|(_, n)| !n.is_empty()) {
+      warn!(
+         "interaction classes require the `style` feature: {}",
+         classes
+      );
+   }
+   let previous = world
+      .take_node_state::<ClassAttrsState>(&node_id)
+      .unwrap_or_default();
+   for attr_id in previous.0 {
+      if !items.base.iter().any(|n| n.attr_id == attr_id) {
+         crate::attrs::get_attr_by_index(attr_id).set_value(world, node_id, None);
+      }
+   }
+   let attr_ids = items.base.iter().map(|n| n.attr_id).collect();
+   for item in items.base {
+      crate::attrs::get_attr_by_index(item.attr_id).set_value(world, node_id, Some(item.value));
+   }
+   world.set_node_state(&node_id, ClassAttrsState(attr_ids));
+}
+
+#[cfg(test)]
+mod tests {
+   use rxy_core::HasIndex;
+
+   use super::*;
+
+   #[test]
+   fn parse_values() {
+      assert_eq!(parse_size_val("4"), Some(Val::Px(16.)));
+      assert_eq!(parse_size_val("0.5"), Some(Val::Px(2.)));
+      assert_eq!(parse_size_val("1/2"), Some(Val::Percent(50.)));
+      assert_eq!(parse_size_val("full"), Some(Val::Percent(100.)));
+      assert_eq!(parse_size_val("[12px]"), Some(Val::Px(12.)));
+      assert_eq!(parse_size_val("[10vw]"), Some(Val::Vw(10.)));
+      assert_eq!(parse_size_val("huge"), None);
+
+      assert_eq!(
+         parse_color("red-500"),
+         Some(Color::rgba_u8(0xef, 0x44, 0x44, 255))
+      );
+      assert_eq!(
+         parse_color("slate-950"),
+         Some(Color::rgba_u8(0x02, 0x06, 0x17, 255))
+      );
+      assert_eq!(parse_color("white/50"), Some(Color::WHITE.with_a(0.5)));
+      assert_eq!(parse_color("[#ff0000]"), Some(Color::rgb(1., 0., 0.)));
+      assert_eq!(parse_color("red-550"), None);
+
+      assert_eq!(
+         from_str::<ImageSlice>("8 16"),
+         Some(ImageSlice::axes(16., 8.))
+      );
+      assert_eq!(from_str::<ImageSlice>("1 2 3 4 5"), None);
+   }
+
+   #[test]
+   fn parse_classes() {
+      let items = ClassItems::parse("flex p-4 bg-red-500/50 hover:bg-red-600 w-full -mt-2 foo");
+      let attr_ids = items.base.iter().map(|n| n.attr_id).collect::<Vec<_>>();
+      assert_eq!(
+         attr_ids,
+         [
+            attrs::display::INDEX,
+            attrs::padding_left::INDEX,
+            attrs::padding_right::INDEX,
+            attrs::padding_top::INDEX,
+            attrs::padding_bottom::INDEX,
+            attrs::bg_color::INDEX,
+            attrs::width::INDEX,
+            attrs::margin_top::INDEX,
+         ]
+      );
+      assert_eq!(items.hover.len(), 1);
+      assert_eq!(items.hover[0].attr_id, attrs::bg_color::INDEX);
+      assert!(items.active.is_empty() && items.focus.is_empty());
+
+      let items = ClassItems::parse("p-2 px-3");
+      assert_eq!(items.base.len(), 4);
+      assert_eq!(
+         items.base.last().unwrap().attr_id,
+         attrs::padding_right::INDEX
+      );
+   }
+
+   #[test]
+   fn parse_shape_classes() {
+      let items =
+         ClassItems::parse("rounded-lg shadow-md bg-gradient-to-r from-red-500 to-white top-2");
+      assert_eq!(
+         find_item_value::<Val>(&items.base, attrs::border_radius::INDEX),
+         Some(Val::Px(8.))
+      );
+      assert_eq!(
+         find_item_value::<BoxShadow>(&items.base, attrs::box_shadow::INDEX),
+         Some(BoxShadow::MD)
+      );
+      assert_eq!(
+         find_item_value::<LinearGradient>(&items.base, attrs::bg_gradient::INDEX),
+         Some(LinearGradient::new(
+            90.,
+            parse_color("red-500").unwrap(),
+            Color::WHITE
+         ))
+      );
+      assert_eq!(
+         find_item_value::<Val>(&items.base, attrs::top::INDEX),
+         Some(Val::Px(8.))
+      );
+
+      assert_eq!(
+         from_str::<BoxShadow>("0 4px 6px -1px #000000"),
+         Some(BoxShadow::new(Color::BLACK, Vec2::new(0., 4.), 6., -1.))
+      );
+      assert_eq!(
+         from_str::<LinearGradient>("to left, #ffffff, #000000"),
+         Some(LinearGradient::new(270., Color::WHITE, Color::BLACK))
+      );
+   }
+}
@@ -22,7 +22,11 @@ use rxy_core::{
 };
 
 pub use crate::prelude::no_preclude::ALL_ATTRS;
-use crate::{all_attrs, BevyRenderer, ElementStyleEntityExt, TextStyledElementEntityWorldMutExt};
+use crate::renderer::attr_parse::apply_classes;
+use crate::{
+   all_attrs, BevyRenderer, BoxShadow, ElementStates, ElementStyleEntityExt, FocusedEntity,
+   LinearGradient, ShapeStyle, TextStyledElementEntityWorldMutExt,
+};
 
 macro_rules! common_attrs_fn_define {
     ($($attr:ident)*) => {
@@ -103,7 +107,7 @@ macro_rules! element_attrs_fn_define {
 }
 
 common_attrs_fn_define! {
-    // class
+    class
     name
     z_index
     bg_color
@@ -168,32 +172,37 @@ common_attrs_fn_define! {
     grid_auto_columns
     grid_row
     grid_column
+    disabled
+    checked
+    selected
+    border_radius
+    box_shadow
+    bg_gradient
+    autofocus
 }
 define_attr_get_fn!(BevyRenderer);
 
-// #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
-// pub struct class;
-//
-// impl ElementAttrType<BevyRenderer> for class {
-//     type Value = Cow<'static, str>;
-//
-//     const NAME: &'static str = stringify!(class);
-//
-//     fn update_value(
-//         _world: &mut RendererWorld<BevyRenderer>,
-//         _node_id: RendererNodeId<BevyRenderer>,
-//         _value: impl Into<Self::Value>,
-//     ) {
-//         // todo:
-//         // let value = value.into();
-//         // handle_classes(context, value.as_ref());
-//         // if !context.entity_extra_data().interaction_classes.is_empty()
-//         //     && !world.contains::<Interaction>()
-//         // {
-//         //     world.insert(Interaction::default());
-//         // }
-//     }
-// }
+/// Tailwind utility classes, e.g. `"flex p-4 bg-red-500/50 hover:bg-red-600 w-full"`.
+/// See [`ClassItems::parse`](crate::ClassItems::parse) for the supported classes.
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub struct class;
+
+impl ElementAttrType<BevyRenderer> for class {
+   type Value = Cow<'static, str>;
+
+   const NAME: &'static str = stringify!(class);
+
+   fn update_value(
+      world: &mut RendererWorld<BevyRenderer>,
+      node_id: RendererNodeId<BevyRenderer>,
+      value: impl Into<Self::Value>,
+   ) {
+      if world.get_entity(node_id).is_none() {
+         return;
+      }
+      apply_classes(world, node_id, &value.into());
+   }
+}
 
 #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
 pub struct name;
@@ -1524,3 +1533,119 @@ define_style_attr_type! {
     grid_row: GridPlacement
     grid_column: GridPlacement
 }
+
+macro_rules! define_element_state_attr_type {
+    ($($ident:ident)*) => {
+        $(
+            #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+            pub struct $ident;
+
+            impl ElementAttrType<BevyRenderer> for $ident {
+                type Value = bool;
+
+                const NAME: &'static str = stringify!($ident);
+
+                fn update_value(
+                    world: &mut RendererWorld<BevyRenderer>,
+                    node_id: RendererNodeId<BevyRenderer>,
+                    value: impl Into<Self::Value>,
+                ) {
+                    let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else{
+                        return;
+                    };
+                    let value = value.into();
+                    match entity_world_mut.get_mut::<ElementStates>() {
+                        None => {
+                            entity_world_mut.insert(ElementStates {
+                                $ident: value,
+                                ..Default::default()
+                            });
+                        }
+                        Some(mut states) => {
+                            if states.$ident != value {
+                                states.$ident = value;
+                            }
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+define_element_state_attr_type! {
+    disabled
+    checked
+    selected
+}
+
+/// Focuses the element when the value becomes `true`.
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub struct autofocus;
+
+impl ElementAttrType<BevyRenderer> for autofocus {
+   type Value = bool;
+
+   const NAME: &'static str = stringify!(autofocus);
+
+   fn update_value(
+      world: &mut RendererWorld<BevyRenderer>,
+      node_id: RendererNodeId<BevyRenderer>,
+      value: impl Into<Self::Value>,
+   ) {
+      if !value.into() || world.get_entity(node_id).is_none() {
+         return;
+      }
+      let mut focus = world.resource_mut::<FocusedEntity>();
+      if focus.0 != Some(node_id) {
+         focus.0 = Some(node_id);
+      }
+   }
+}
+
+macro_rules! define_shape_attr_type {
+    ($($ident:ident($field:ident): $value_ty:ty)*) => {
+        $(
+            #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+            pub struct $ident;
+
+            impl ElementAttrType<BevyRenderer> for $ident {
+                type Value = $value_ty;
+
+                const NAME: &'static str = stringify!($ident);
+
+                fn update_value(
+                    world: &mut RendererWorld<BevyRenderer>,
+                    node_id: RendererNodeId<BevyRenderer>,
+                    value: impl Into<Self::Value>,
+                ) {
+                    let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else{
+                        return;
+                    };
+                    let value = value.into();
+                    match entity_world_mut.get_mut::<ShapeStyle>() {
+                        None => {
+                            if value != <$value_ty>::default() {
+                                entity_world_mut.insert(ShapeStyle {
+                                    $field: value,
+                                    ..Default::default()
+                                });
+                            }
+                        }
+                        Some(mut shape_style) => {
+                            if shape_style.$field != value {
+                                shape_style.$field = value;
+                            }
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+define_shape_attr_type! {
+    border_radius(border_radius): Val
+    box_shadow(box_shadow): BoxShadow
+    bg_gradient(gradient): LinearGradient
+}
@@ -1,13 +1,30 @@
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 
-use bevy_reflect::Reflect;
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{load_internal_asset, Asset, Assets, Handle};
+use bevy_ecs::prelude::{
+   Changed, Commands, Component, DetectChangesMut, Entity, IntoSystemConfigs, Or, Query, Res,
+   ResMut,
+};
+use bevy_reflect::{Reflect, TypePath};
+use bevy_render::color::Color;
+use bevy_render::render_resource::{AsBindGroup, Shader, ShaderRef};
+use bevy_render::texture::Image;
+use bevy_sprite::{TextureAtlas, TextureAtlasLayout};
 use bevy_ui::node_bundles::ImageBundle;
+use bevy_ui::widget::UiImageSize;
+use bevy_ui::{BackgroundColor, Node, UiImage, UiMaterial, UiMaterialPlugin, UiSystem};
+use glam::{Vec2, Vec4};
 
 use rxy_core::{ElementType, ElementTypeUnTyped, RendererNodeId, RendererWorld};
 
+use crate::renderer::impl_copy_attr_values;
 use crate::{BevyRenderer, BevyWorldExt};
 
+const IMAGE_SHADER_HANDLE: Handle<Shader> =
+   Handle::weak_from_u128(0x2b71_d04e_8c39_4f6a_9e15_73a8_c6f2_0d5b);
+
 #[derive(Reflect, Default, Debug, Clone, Copy)]
 pub struct element_img;
 
@@ -29,15 +46,214 @@ impl ElementType<BevyRenderer> for element_img {
    }
 }
 
+/// Draws the img nodes whose [`ImageStyle`] is not the default stretched image.
+pub struct RxyImagePlugin;
+
+impl Plugin for RxyImagePlugin {
+   fn build(&self, app: &mut App) {
+      load_internal_asset!(app, IMAGE_SHADER_HANDLE, "img.wgsl", Shader::from_wgsl);
+      app.add_plugins(UiMaterialPlugin::<ImageMaterial>::default())
+         .register_type::<ImageStyle>()
+         .add_systems(PostUpdate, sync_image_materials.after(UiSystem::Layout));
+   }
+}
+
+/// How the image fills the img node.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum UiImageScaleMode {
+   /// Stretches the image to the size of the node.
+   #[default]
+   Fill,
+   /// Scales the image to fit inside the node, keeping its aspect ratio.
+   Contain,
+   /// Scales the image to cover the node, keeping its aspect ratio.
+   Cover,
+   /// Repeats the image with its own size.
+   Tile,
+   /// Nine-slice scaling, the borders of [`ImageSlice`] keep their size and the center stretches.
+   Sliced,
+}
+
+/// The borders of a nine-slice image in pixels of the texture, like css `border-image-slice`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Reflect)]
+pub struct ImageSlice {
+   pub left: f32,
+   pub right: f32,
+   pub top: f32,
+   pub bottom: f32,
+}
+
+impl ImageSlice {
+   pub const fn all(value: f32) -> Self {
+      Self::axes(value, value)
+   }
+
+   pub const fn axes(horizontal: f32, vertical: f32) -> Self {
+      Self {
+         left: horizontal,
+         right: horizontal,
+         top: vertical,
+         bottom: vertical,
+      }
+   }
+}
+
+impl_copy_attr_values!(UiImageScaleMode, ImageSlice);
+
+/// The scale mode of an img node, set by the `scale_mode` and `slice_border` attrs.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Reflect)]
+pub struct ImageStyle {
+   pub scale_mode: UiImageScaleMode,
+   pub slice: ImageSlice,
+}
+
+pub use image_uniform::ImageUniform;
+
+// the `ShaderType` derive emits layout checks for each field that are never called
+#[allow(dead_code)]
+mod image_uniform {
+   use bevy_render::render_resource::ShaderType;
+   use glam::{Vec2, Vec4};
+
+   #[derive(ShaderType, Copy, Clone, Debug, Default, PartialEq)]
+   pub struct ImageUniform {
+      pub color: Vec4,
+      pub uv_min: Vec2,
+      pub uv_max: Vec2,
+      pub size: Vec2,
+      pub region_size: Vec2,
+      /// left, top, right, bottom
+      pub slice: Vec4,
+      pub mode: u32,
+      pub flip: u32,
+   }
+}
+
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug)]
+pub struct ImageMaterial {
+   #[uniform(0)]
+   pub image: ImageUniform,
+   #[texture(1)]
+   #[sampler(2)]
+   pub texture: Handle<Image>,
+}
+
+impl UiMaterial for ImageMaterial {
+   fn fragment_shader() -> ShaderRef {
+      IMAGE_SHADER_HANDLE.into()
+   }
+}
+
+/// The tint of the [`UiImage`], its [`BackgroundColor`], which is hidden while the [`ImageMaterial`] draws the image.
+#[derive(Component, Clone, Debug)]
+struct ImageMaterialState {
+   color: Color,
+}
+
+fn sync_image_materials(
+   mut commands: Commands,
+   mut materials: ResMut<Assets<ImageMaterial>>,
+   atlas_layouts: Res<Assets<TextureAtlasLayout>>,
+   mut query: Query<
+      (
+         Entity,
+         &ImageStyle,
+         &Node,
+         &UiImage,
+         &mut BackgroundColor,
+         &UiImageSize,
+         Option<&TextureAtlas>,
+         Option<&Handle<ImageMaterial>>,
+         Option<&mut ImageMaterialState>,
+      ),
+      Or<(
+         Changed<ImageStyle>,
+         Changed<Node>,
+         Changed<UiImage>,
+         Changed<BackgroundColor>,
+         Changed<UiImageSize>,
+         Changed<TextureAtlas>,
+      )>,
+   >,
+) {
+   for (entity, image_style, node, ui_image, mut tint, image_size, atlas, material, state) in
+      query.iter_mut()
+   {
+      if image_style.scale_mode == UiImageScaleMode::Fill {
+         let Some(state) = state else {
+            continue;
+         };
+         if tint.0 == Color::NONE {
+            tint.0 = state.color;
+         }
+         commands
+            .entity(entity)
+            .remove::<(Handle<ImageMaterial>, ImageMaterialState)>();
+         continue;
+      }
+
+      // the hidden image keeps a transparent tint, a new tint comes from the user
+      let color = match &state {
+         Some(state) if tint.0 == Color::NONE => state.color,
+         _ => core::mem::replace(&mut tint.bypass_change_detection().0, Color::NONE),
+      };
+      let (uv_min, uv_max) = atlas
+         .and_then(|atlas| {
+            let rect = atlas.texture_rect(&atlas_layouts)?;
+            let layout_size = atlas_layouts.get(&atlas.layout)?.size;
+            Some((rect.min / layout_size, rect.max / layout_size))
+         })
+         .unwrap_or((Vec2::ZERO, Vec2::ONE));
+      let slice = image_style.slice;
+      let image = ImageUniform {
+         color: Vec4::from_array(color.as_linear_rgba_f32()),
+         uv_min,
+         uv_max,
+         size: node.size(),
+         region_size: image_size.size(),
+         slice: Vec4::new(slice.left, slice.top, slice.right, slice.bottom),
+         mode: image_style.scale_mode as u32,
+         flip: ui_image.flip_x as u32 | (ui_image.flip_y as u32) << 1,
+      };
+
+      match state {
+         Some(mut state) => state.color = color,
+         None => {
+            commands.entity(entity).insert(ImageMaterialState { color });
+         }
+      }
+      match material.and_then(|n| materials.get_mut(n)) {
+         Some(material) => {
+            if material.image != image {
+               material.image = image;
+            }
+            if material.texture != ui_image.texture {
+               material.texture = ui_image.texture.clone();
+            }
+         }
+         None => {
+            let material = materials.add(ImageMaterial {
+               image,
+               texture: ui_image.texture.clone(),
+            });
+            commands.entity(entity).insert(material);
+         }
+      }
+   }
+}
+
 pub mod element_img_attrs {
    use bevy_asset::Handle;
    use bevy_render::texture::Image;
+   use bevy_sprite::{TextureAtlas, TextureAtlasLayout};
    use bevy_ui::UiImage;
 
    use rxy_core::{ElementAttrType, RendererNodeId, RendererWorld};
 
    use crate::BevyRenderer;
 
+   use super::{ImageSlice, ImageStyle, UiImageScaleMode};
+
    #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
    pub struct src;
 
@@ -94,4 +310,112 @@ pub mod element_img_attrs {
          }
       }
    }
+
+   macro_rules! define_image_style_attr_type {
+       ($($ident:ident($field:ident): $value_ty:ty)*) => {
+           $(
+               #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+               pub struct $ident;
+
+               impl ElementAttrType<BevyRenderer> for $ident {
+                   type Value = $value_ty;
+
+                   const NAME: &'static str = stringify!($ident);
+
+                   fn update_value(
+                       world: &mut RendererWorld<BevyRenderer>,
+                       node_id: RendererNodeId<BevyRenderer>,
+                       value: impl Into<Self::Value>,
+                   ) {
+                       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else{
+                           return;
+                       };
+                       let value = value.into();
+                       match entity_world_mut.get_mut::<ImageStyle>() {
+                           None => {
+                               if value != <$value_ty>::default() {
+                                   entity_world_mut.insert(ImageStyle {
+                                       $field: value,
+                                       ..Default::default()
+                                   });
+                               }
+                           }
+                           Some(mut image_style) => {
+                               if image_style.$field != value {
+                                   image_style.$field = value;
+                               }
+                           }
+                       }
+                   }
+               }
+           )*
+       };
+   }
+
+   define_image_style_attr_type! {
+       scale_mode(scale_mode): UiImageScaleMode
+       slice_border(slice): ImageSlice
+   }
+
+   #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+   pub struct atlas_layout;
+
+   impl ElementAttrType<BevyRenderer> for atlas_layout {
+      type Value = Handle<TextureAtlasLayout>;
+
+      const NAME: &'static str = stringify!(atlas_layout);
+
+      fn update_value(
+         world: &mut RendererWorld<BevyRenderer>,
+         node_id: RendererNodeId<BevyRenderer>,
+         value: impl Into<Self::Value>,
+      ) {
+         let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
+            return;
+         };
+         let layout = value.into();
+         match entity_world_mut.get_mut::<TextureAtlas>() {
+            None => {
+               entity_world_mut.insert(TextureAtlas { layout, index: 0 });
+            }
+            Some(mut atlas) => {
+               atlas.layout = layout;
+            }
+         }
+      }
+   }
+
+   /// The index of the texture in the [`atlas_layout`], e.g. a panel of a sprite sheet.
+   #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+   pub struct atlas_index;
+
+   impl ElementAttrType<BevyRenderer> for atlas_index {
+      type Value = usize;
+
+      const NAME: &'static str = stringify!(atlas_index);
+
+      fn update_value(
+         world: &mut RendererWorld<BevyRenderer>,
+         node_id: RendererNodeId<BevyRenderer>,
+         value: impl Into<Self::Value>,
+      ) {
+         let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
+            return;
+         };
+         let index = value.into();
+         match entity_world_mut.get_mut::<TextureAtlas>() {
+            None => {
+               entity_world_mut.insert(TextureAtlas {
+                  layout: Handle::default(),
+                  index,
+               });
+            }
+            Some(mut atlas) => {
+               if atlas.index != index {
+                  atlas.index = index;
+               }
+            }
+         }
+      }
+   }
 }
@@ -0,0 +1,77 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+struct ImageUniform {
+    color: vec4<f32>,
+    uv_min: vec2<f32>,
+    uv_max: vec2<f32>,
+    size: vec2<f32>,
+    region_size: vec2<f32>,
+    // left, top, right, bottom
+    slice: vec4<f32>,
+    mode: u32,
+    flip: u32,
+}
+
+@group(1) @binding(0) var<uniform> img: ImageUniform;
+@group(1) @binding(1) var img_texture: texture_2d<f32>;
+@group(1) @binding(2) var img_sampler: sampler;
+
+// maps a pixel of the node to a pixel of the texture region along one axis,
+// the borders keep their size and shrink only when the node is smaller than them
+fn slice_axis(p: f32, size: f32, region: f32, start: f32, end: f32) -> f32 {
+    let scale = min(1.0, size / max(start + end, 0.0001));
+    let start_px = start * scale;
+    let end_px = end * scale;
+    if p < start_px {
+        return p / scale;
+    }
+    if p > size - end_px {
+        return region - (size - p) / scale;
+    }
+    let center = max(size - start_px - end_px, 0.0001);
+    return start + (p - start_px) / center * (region - start - end);
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    if any(img.region_size <= vec2(0.0)) {
+        return vec4(0.0);
+    }
+    let p = in.uv * img.size;
+    var local = in.uv;
+    switch img.mode {
+        // contain, cover
+        case 1u, 2u: {
+            let ratio = img.size / img.region_size;
+            var scale = min(ratio.x, ratio.y);
+            if img.mode == 2u {
+                scale = max(ratio.x, ratio.y);
+            }
+            local = (p - img.size * 0.5) / (img.region_size * scale) + vec2(0.5);
+            if any(local < vec2(0.0)) || any(local > vec2(1.0)) {
+                return vec4(0.0);
+            }
+        }
+        // tile
+        case 3u: {
+            local = fract(p / img.region_size);
+        }
+        // sliced
+        case 4u: {
+            local = vec2(
+                slice_axis(p.x, img.size.x, img.region_size.x, img.slice.x, img.slice.z),
+                slice_axis(p.y, img.size.y, img.region_size.y, img.slice.y, img.slice.w),
+            ) / img.region_size;
+        }
+        default: {}
+    }
+    if (img.flip & 1u) != 0u {
+        local.x = 1.0 - local.x;
+    }
+    if (img.flip & 2u) != 0u {
+        local.y = 1.0 - local.y;
+    }
+    let uv = mix(img.uv_min, img.uv_max, local);
+    // the early returns make the control flow non-uniform, so no implicit derivatives
+    return textureSampleLevel(img_texture, img_sampler, uv, 0.0) * img.color;
+}
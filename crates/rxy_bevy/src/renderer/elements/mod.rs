@@ -27,6 +27,7 @@ pub mod prelude {
        [element_span]
        attrs = [
            content
+           rich_content
        ]
 
        [element_img]
@@ -34,6 +35,10 @@ pub mod prelude {
            src
            flip_x
            flip_y
+           scale_mode
+           slice_border
+           atlas_layout
+           atlas_index
        ]
    }
 }
@@ -3,13 +3,14 @@
 
 use bevy_ecs::prelude::*;
 use bevy_reflect::Reflect;
-use bevy_text::Text;
+use bevy_text::{Text, TextStyle};
 use bevy_ui::prelude::TextBundle;
 
 use rxy_core::{ElementAttrType, ElementType, ElementTypeUnTyped, RendererNodeId, RendererWorld};
 
 use crate::{
-   all_attrs, BevyRenderer, BevyWorldExt, ReflectTextStyledElementType, TextStyledElementType,
+   all_attrs, BevyRenderer, BevyWorldExt, ReflectTextStyledElementType, RichText, RichTextFonts,
+   TextStyledElementType,
 };
 
 #[derive(Reflect, Debug, Default, Clone, Copy)]
@@ -34,18 +35,60 @@ impl ElementType<BevyRenderer> for element_span {
    }
 }
 
+/// The text style set by the attrs of a span, and the rich text whose runs override parts of it.
+#[derive(Component, Clone, Debug, Default)]
+pub struct SpanTextStyle {
+   pub style: TextStyle,
+   pub rich_text: Option<RichText>,
+}
+
+impl SpanTextStyle {
+   fn apply(&self, text: &mut Text, fonts: &RichTextFonts) {
+      match &self.rich_text {
+         None => {
+            for section in text.sections.iter_mut() {
+               section.style = self.style.clone();
+            }
+         }
+         Some(rich_text) => {
+            for (section, run) in text.sections.iter_mut().zip(rich_text.runs.iter()) {
+               section.style = run.style(&self.style, fonts);
+            }
+         }
+      }
+   }
+}
+
+fn update_span_text_style(
+   entity_ref: &mut EntityWorldMut<'_>,
+   f: impl FnOnce(&mut SpanTextStyle, &mut Text, &RichTextFonts),
+) {
+   let fonts = entity_ref
+      .world()
+      .get_resource::<RichTextFonts>()
+      .cloned()
+      .unwrap_or_default();
+   let mut span_text_style = entity_ref
+      .get::<SpanTextStyle>()
+      .cloned()
+      .unwrap_or_default();
+   let Some(mut text) = entity_ref.get_mut::<Text>() else {
+      return;
+   };
+   f(&mut span_text_style, &mut text, &fonts);
+   entity_ref.insert(span_text_style);
+}
+
 impl TextStyledElementType for element_span {
    fn set_font(
       &self,
       entity_ref: &mut EntityWorldMut<'_>,
       value: <all_attrs::font as ElementAttrType<BevyRenderer>>::Value,
    ) {
-      let Some(mut t) = entity_ref.get_mut::<Text>() else {
-         return;
-      };
-      for section in t.sections.iter_mut() {
-         section.style.font = value.clone();
-      }
+      update_span_text_style(entity_ref, |span_text_style, text, fonts| {
+         span_text_style.style.font = value;
+         span_text_style.apply(text, fonts);
+      });
    }
 
    fn set_font_size(
@@ -53,12 +96,10 @@ impl TextStyledElementType for element_span {
       entity_ref: &mut EntityWorldMut<'_>,
       value: <all_attrs::font_size as ElementAttrType<BevyRenderer>>::Value,
    ) {
-      let Some(mut t) = entity_ref.get_mut::<Text>() else {
-         return;
-      };
-      for section in t.sections.iter_mut() {
-         section.style.font_size = value;
-      }
+      update_span_text_style(entity_ref, |span_text_style, text, fonts| {
+         span_text_style.style.font_size = value;
+         span_text_style.apply(text, fonts);
+      });
    }
 
    fn set_text_color(
@@ -66,12 +107,10 @@ impl TextStyledElementType for element_span {
       entity_ref: &mut EntityWorldMut<'_>,
       value: <all_attrs::text_color as ElementAttrType<BevyRenderer>>::Value,
    ) {
-      let Some(mut t) = entity_ref.get_mut::<Text>() else {
-         return;
-      };
-      for section in t.sections.iter_mut() {
-         section.style.color = value;
-      }
+      update_span_text_style(entity_ref, |span_text_style, text, fonts| {
+         span_text_style.style.color = value;
+         span_text_style.apply(text, fonts);
+      });
    }
 
    fn set_text_linebreak(
@@ -100,7 +139,7 @@ impl TextStyledElementType for element_span {
 pub mod element_span_attrs {
    use std::borrow::Cow;
 
-   use bevy_text::TextStyle;
+   use bevy_text::TextSection;
 
    use super::*;
 
@@ -144,19 +183,46 @@ pub mod element_span_attrs {
          value: impl Into<Self::Value>,
       ) {
          let value = value.into().to_string();
-         let Some(mut t) = world.get_mut::<Text>(node_id) else {
+         let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
             return;
          };
-         if t.sections.is_empty() {
-            t.sections = vec![bevy_text::TextSection::new(value, TextStyle::default())];
-         } else if t.sections.len() == 1 {
-            t.sections[0].value = value;
-         } else {
-            t.sections[0].value = value;
-            unsafe {
-               t.sections.set_len(1);
+         update_span_text_style(&mut entity_world_mut, |span_text_style, text, fonts| {
+            span_text_style.rich_text = None;
+            text.sections.truncate(1);
+            match text.sections.first_mut() {
+               None => text
+                  .sections
+                  .push(TextSection::new(value, TextStyle::default())),
+               Some(section) => section.value = value,
             }
-         }
+            span_text_style.apply(text, fonts);
+         });
+      }
+   }
+
+   /// The content of the span as [`RichText`], e.g. `RichText::parse("Deal **20** damage")`.
+   /// It replaces the `content` of the span.
+   #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+   pub struct rich_content;
+
+   impl ElementAttrType<BevyRenderer> for rich_content {
+      type Value = RichText;
+
+      const NAME: &'static str = stringify!(rich_content);
+
+      fn update_value(
+         world: &mut RendererWorld<BevyRenderer>,
+         node_id: RendererNodeId<BevyRenderer>,
+         value: impl Into<Self::Value>,
+      ) {
+         let rich_text = value.into();
+         let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
+            return;
+         };
+         update_span_text_style(&mut entity_world_mut, |span_text_style, text, fonts| {
+            text.sections = rich_text.sections(&span_text_style.style, fonts);
+            span_text_style.rich_text = Some(rich_text);
+         });
       }
    }
 }
@@ -7,23 +7,31 @@ use bevy_ecs::prelude::World;
 use bevy_reflect::Reflect;
 use bevy_tasks::Task;
 
+pub use attr_parse::{
+   parse_attr_value, parse_color, parse_css_color, parse_css_val, parse_size_val, ClassItem,
+   ClassItems, ClassVariant,
+};
 pub use composite_attrs::*;
+pub use rich_text::*;
 use rxy_core::{
    DeferredNodeTreeScoped, Element, ElementAttr, ElementTypeUnTyped, ElementViewChildren, Renderer,
    RendererWorld,
 };
 #[cfg(feature = "tailwind_aliases")]
 pub use tailwind_attrs::*;
+pub use tailwind_colors::{tailwind_color, TAILWIND_COLORS, TAILWIND_SHADES};
 pub use text_styled_element::*;
 
 use crate::elements::element_div;
 use crate::CmdSender;
 
+mod attr_parse;
 pub mod attrs;
 pub mod common_renderer;
 mod composite_attrs;
 pub mod elements;
 mod node_tree;
+mod rich_text;
 mod text_styled_element;
 mod view_key;
 
@@ -32,8 +40,37 @@ pub mod event;
 pub mod style;
 #[cfg(feature = "tailwind_aliases")]
 mod tailwind_attrs;
+mod tailwind_colors;
 pub mod view_builder_ext;
 
+/// Implements [`AttrValue`](rxy_core::AttrValue) for the copyable value types of this crate.
+macro_rules! impl_copy_attr_values {
+    ($($ty:ty),*) => {
+        $(
+            impl rxy_core::AttrValue for $ty {
+                fn clone_att_value(&self) -> rxy_core::SmallBox<dyn rxy_core::AttrValue, rxy_core::S1> {
+                    rxy_core::smallbox!(*self)
+                }
+
+                fn default_value() -> Self {
+                    <Self as Default>::default()
+                }
+
+                fn eq(&self, other: &Self) -> bool {
+                    *self == *other
+                }
+            }
+
+            impl From<$ty> for rxy_core::XValueWrapper<$ty> {
+                fn from(value: $ty) -> Self {
+                    rxy_core::XValueWrapper(value)
+                }
+            }
+        )*
+    };
+}
+pub(crate) use impl_copy_attr_values;
+
 #[inline]
 pub fn view_element_type() -> &'static dyn ElementTypeUnTyped<BevyRenderer> {
    &element_div
@@ -22,7 +22,7 @@ use rxy_core::{
 
 use crate::{
    BevyDeferredWorldScoped, BevyRenderer, BevyWorldExt, CmdSender, ElementEntityExtraData,
-   ElementEntityWorldMutExt, ElementStyleEntityExt, RendererState,
+   ElementEntityWorldMutExt, ElementStyleEntityExt, LeaveAction, RendererState, TransitionWorldExt,
 };
 
 impl NodeTree<BevyRenderer> for World {
@@ -283,6 +283,17 @@ impl NodeTree<BevyRenderer> for World {
       self.init_resource::<RecycleNodeContainer>();
       let recycle_node_container = self.resource::<RecycleNodeContainer>().0;
       self.set_node_state(&first_node, RecycledNode { placeholder });
+      // only single node keys can wait for a leave animation
+      if key.state_node_id() == Some(first_node)
+         && self.start_leave_transition(
+            first_node,
+            LeaveAction::Recycle {
+               container: recycle_node_container,
+            },
+         )
+      {
+         return;
+      }
       key.set_visibility(self, true);
       key.insert_before(self, Some(&recycle_node_container), None);
    }
@@ -297,6 +308,7 @@ impl NodeTree<BevyRenderer> for World {
          .placeholder;
       key.insert_before(self, None, Some(&placeholder));
       key.set_visibility(self, false);
+      self.restart_enter_transition(first_node);
    }
 }
 
@@ -0,0 +1,324 @@
+use std::borrow::Cow;
+
+use bevy_asset::Handle;
+use bevy_ecs::prelude::Resource;
+use bevy_reflect::Reflect;
+use bevy_render::color::Color;
+use bevy_text::{Font, TextSection, TextStyle};
+use bevy_utils::HashMap;
+
+use rxy_core::{smallbox, AttrValue, SmallBox, XValueWrapper, S1};
+
+use crate::parse_css_color;
+
+/// The fonts that the runs of a [`RichText`] refer to by name.
+/// `**bold**` uses the font named `bold`, `*italic*` uses `italic` and `[font=mono]` uses `mono`.
+///
+/// The names are resolved when the rich content is set, so insert the fonts before spawning the views.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct RichTextFonts(pub HashMap<Cow<'static, str>, Handle<Font>>);
+
+impl RichTextFonts {
+   pub fn insert(&mut self, name: impl Into<Cow<'static, str>>, font: Handle<Font>) -> &mut Self {
+      self.0.insert(name.into(), font);
+      self
+   }
+
+   pub fn get(&self, name: &str) -> Option<Handle<Font>> {
+      self.0.get(name).cloned()
+   }
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum TextRunFont {
+   Handle(Handle<Font>),
+   /// A font of the [`RichTextFonts`].
+   Name(Cow<'static, str>),
+}
+
+/// A run of a [`RichText`], the unset styles fall back to the style of the span.
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+pub struct TextRun {
+   pub text: String,
+   pub color: Option<Color>,
+   pub font_size: Option<f32>,
+   pub font: Option<TextRunFont>,
+}
+
+impl TextRun {
+   pub fn new(text: impl Into<String>) -> Self {
+      Self {
+         text: text.into(),
+         ..Default::default()
+      }
+   }
+
+   pub fn color(mut self, color: Color) -> Self {
+      self.color = Some(color);
+      self
+   }
+
+   pub fn font_size(mut self, font_size: f32) -> Self {
+      self.font_size = Some(font_size);
+      self
+   }
+
+   pub fn font(mut self, font: Handle<Font>) -> Self {
+      self.font = Some(TextRunFont::Handle(font));
+      self
+   }
+
+   pub fn font_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+      self.font = Some(TextRunFont::Name(name.into()));
+      self
+   }
+
+   pub fn bold(self) -> Self {
+      self.font_name("bold")
+   }
+
+   pub fn italic(self) -> Self {
+      self.font_name("italic")
+   }
+
+   fn has_same_style(&self, other: &Self) -> bool {
+      self.color == other.color && self.font_size == other.font_size && self.font == other.font
+   }
+
+   pub fn style(&self, base: &TextStyle, fonts: &RichTextFonts) -> TextStyle {
+      let font = match &self.font {
+         Some(TextRunFont::Handle(font)) => Some(font.clone()),
+         Some(TextRunFont::Name(name)) => fonts.get(name),
+         None => None,
+      };
+      TextStyle {
+         font: font.unwrap_or_else(|| base.font.clone()),
+         font_size: self.font_size.unwrap_or(base.font_size),
+         color: self.color.unwrap_or(base.color),
+      }
+   }
+}
+
+/// Text made of runs with their own color, size and font, set by the `rich_content` attr of `span`.
+/// Each run becomes a [`TextSection`].
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+pub struct RichText {
+   pub runs: Vec<TextRun>,
+}
+
+impl RichText {
+   pub fn new() -> Self {
+      Self::default()
+   }
+
+   pub fn run(mut self, run: TextRun) -> Self {
+      self.runs.push(run);
+      self
+   }
+
+   pub fn text(self, text: impl Into<String>) -> Self {
+      self.run(TextRun::new(text))
+   }
+
+   pub fn sections(&self, base: &TextStyle, fonts: &RichTextFonts) -> Vec<TextSection> {
+      self
+         .runs
+         .iter()
+         .map(|run| TextSection::new(run.text.clone(), run.style(base, fonts)))
+         .collect()
+   }
+
+   /// Parses a markup like `"Deal **20** [color=red]fire[/color] damage"`.
+   ///
+   /// - `**bold**` and `*italic*` use the fonts named `bold` and `italic` of the [`RichTextFonts`]
+   /// - `[color=red]..[/color]` takes a css color, a tailwind color like `red-500` or a basic color name
+   /// - `[size=32]..[/size]` sets the font size
+   /// - `[font=mono]..[/font]` uses a font of the [`RichTextFonts`]
+   ///
+   /// Tags can be nested, `\` escapes the next character and unknown tags are kept as text.
+   pub fn parse(markup: &str) -> Self {
+      let mut parser = MarkupParser::default();
+      let mut chars = markup.char_indices().peekable();
+      while let Some((i, c)) = chars.next() {
+         match c {
+            '\\' => {
+               if let Some((_, c)) = chars.next() {
+                  parser.text.push(c);
+               }
+            }
+            '*' => {
+               let tag = if chars.next_if(|(_, c)| *c == '*').is_some() {
+                  "bold"
+               } else {
+                  "italic"
+               };
+               if !parser.close(tag) {
+                  parser.open(MarkupFrame {
+                     tag,
+                     run: TextRun::default().font_name(tag),
+                  });
+               }
+            }
+            '[' => {
+               let Some(len) = markup[i..].find(']') else {
+                  parser.text.push(c);
+                  continue;
+               };
+               if !parser.tag(&markup[i + 1..i + len]) {
+                  parser.text.push(c);
+                  continue;
+               }
+               while chars.next_if(|(j, _)| *j < i + len + 1).is_some() {}
+            }
+            c => parser.text.push(c),
+         }
+      }
+      parser.flush();
+      parser.rich_text
+   }
+}
+
+impl AttrValue for RichText {
+   fn clone_att_value(&self) -> SmallBox<dyn AttrValue, S1> {
+      smallbox!(self.clone())
+   }
+
+   fn default_value() -> Self {
+      <Self as Default>::default()
+   }
+
+   fn eq(&self, other: &Self) -> bool {
+      self == other
+   }
+}
+
+impl From<RichText> for XValueWrapper<RichText> {
+   fn from(value: RichText) -> Self {
+      XValueWrapper(value)
+   }
+}
+
+struct MarkupFrame {
+   tag: &'static str,
+   run: TextRun,
+}
+
+#[derive(Default)]
+struct MarkupParser {
+   frames: Vec<MarkupFrame>,
+   text: String,
+   rich_text: RichText,
+}
+
+impl MarkupParser {
+   /// Handles the content of a `[..]` tag, returns `false` if it is not a known tag.
+   fn tag(&mut self, content: &str) -> bool {
+      if let Some(name) = content.strip_prefix('/') {
+         return match name {
+            "color" | "size" | "font" => self.close(name),
+            _ => false,
+         };
+      }
+      let Some((name, value)) = content.split_once('=') else {
+         return false;
+      };
+      let frame = match name {
+         "color" => parse_markup_color(value).map(|color| MarkupFrame {
+            tag: "color",
+            run: TextRun::default().color(color),
+         }),
+         "size" => value.parse().ok().map(|font_size| MarkupFrame {
+            tag: "size",
+            run: TextRun::default().font_size(font_size),
+         }),
+         "font" if !value.is_empty() => Some(MarkupFrame {
+            tag: "font",
+            run: TextRun::default().font_name(value.to_string()),
+         }),
+         _ => None,
+      };
+      let Some(frame) = frame else {
+         return false;
+      };
+      self.open(frame);
+      true
+   }
+
+   fn open(&mut self, frame: MarkupFrame) {
+      self.flush();
+      self.frames.push(frame);
+   }
+
+   /// Closes the innermost frame of the tag, returns `false` if no frame is open.
+   fn close(&mut self, tag: &str) -> bool {
+      let Some(index) = self.frames.iter().rposition(|n| n.tag == tag) else {
+         return false;
+      };
+      self.flush();
+      self.frames.remove(index);
+      true
+   }
+
+   fn flush(&mut self) {
+      if self.text.is_empty() {
+         return;
+      }
+      let text = core::mem::take(&mut self.text);
+      let mut run = TextRun::default();
+      for frame in self.frames.iter() {
+         run.color = frame.run.color.or(run.color);
+         run.font_size = frame.run.font_size.or(run.font_size);
+         run.font = frame.run.font.clone().or(run.font);
+      }
+      match self.rich_text.runs.last_mut() {
+         Some(last) if last.has_same_style(&run) => last.text.push_str(&text),
+         _ => self.rich_text.runs.push(TextRun { text, ..run }),
+      }
+   }
+}
+
+fn parse_markup_color(value: &str) -> Option<Color> {
+   parse_css_color(value).or(match value {
+      "white" => Some(Color::WHITE),
+      "black" => Some(Color::BLACK),
+      "gray" | "grey" => Some(Color::GRAY),
+      "red" => Some(Color::RED),
+      "green" => Some(Color::GREEN),
+      "blue" => Some(Color::BLUE),
+      "yellow" => Some(Color::YELLOW),
+      "orange" => Some(Color::ORANGE),
+      "purple" => Some(Color::PURPLE),
+      "pink" => Some(Color::PINK),
+      "cyan" => Some(Color::CYAN),
+      _ => None,
+   })
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn parse_markup() {
+      let rich_text =
+         RichText::parse("Deal **20** [color=red]fire [size=32]\\*hot\\*[/size][/color] damage");
+      assert_eq!(
+         rich_text,
+         RichText::new()
+            .text("Deal ")
+            .run(TextRun::new("20").bold())
+            .text(" ")
+            .run(TextRun::new("fire ").color(Color::RED))
+            .run(TextRun::new("*hot*").color(Color::RED).font_size(32.))
+            .text(" damage")
+      );
+
+      let rich_text = RichText::parse("[unknown]a[/color] *b");
+      assert_eq!(
+         rich_text,
+         RichText::new()
+            .text("[unknown]a[/color] ")
+            .run(TextRun::new("b").italic())
+      );
+   }
+}
@@ -8,17 +8,17 @@ use bevy_ui::Interaction;
 use rxy_core::prelude::EitherExt;
 use rxy_core::style::{
    IterExt, NodeInterStyleAttrInfos, NodeStyleAttrInfo, NodeStyleAttrInfos, NodeStyleItemId,
-   NodeStyleSheetId, PipeOp, StyleInteraction, StyleItemValue, StyleSheetDefinition,
-   StyleSheetLocation,
+   NodeStyleSheetId, PipeOp, StyleItemValue, StyleSheetDefinition, StyleSheetLocation,
 };
 use rxy_core::AttrIndex;
 
-use super::interaction_style::AttrSetBitsIterExt;
+use super::interaction_style::{node_style_interaction, AttrSetBitsIterExt};
 use super::node_style_state::NodeStyleSheetsState;
 use super::rxy_bevy_crate::{
-   AttrSetBits, ElementEntityExtraData, ElementEntityWorldMutExt, FocusedEntity, RendererState,
+   AttrSetBits, ElementEntityExtraData, ElementEntityWorldMutExt, ElementStates, FocusVisible,
+   FocusedEntity, RendererState,
 };
-use super::{interaction_to_style_interaction, EntityAttrSyncer, StyleEntityRefExt, StyleError};
+use super::{EntityAttrSyncer, StyleEntityRefExt, StyleError};
 use super::{EntityWorldRef, Result};
 
 pub(crate) trait StateOwner<'a, 's>: Sized {
@@ -202,22 +202,26 @@ impl<'a> EntityStyleAttrInfoIterArgs<'a> {
       mut entity_world_mut: EntityWorldMut, // strict_match: bool,
    ) -> Result {
       let focus = entity_world_mut.world().resource::<FocusedEntity>().0;
+      let focus_visible = entity_world_mut
+         .world()
+         .get_resource::<FocusVisible>()
+         .is_some_and(|n| n.0);
       let entity_ref = entity_world_mut.as_entity_mut();
       let item_ids = self
          .iter_match_attrs(
             unsafe { core::mem::transmute(entity_ref.as_readonly()) },
             focus,
+            focus_visible,
             false,
          )
          .map(|n| (n.0, n.1.top_item_id()))
          .collect::<Vec<_>>();
 
-      let mut attr_bits: AttrSetBits = 0;
+      let mut attr_bits = AttrSetBits::default();
       for (attr_index, top_item_id) in item_ids {
-         if (attr_bits >> attr_index) & 1 == 1 {
+         if !attr_bits.insert(attr_index) {
             continue;
          }
-         attr_bits |= 1 << attr_index;
          top_item_id.sync_attr_value_to_element(&mut entity_world_mut)?;
       }
 
@@ -228,11 +232,12 @@ impl<'a> EntityStyleAttrInfoIterArgs<'a> {
       self,
       entity_ref: EntityRef<'a>,
       focused_entity: Option<Entity>,
+      focus_visible: bool,
       strict_match: bool,
    ) -> impl Iterator<Item = (AttrIndex, &NodeStyleAttrInfo)> {
       let limit_attr_bits = entity_ref
          .get::<ElementEntityExtraData>()
-         .map(|n| n.attr_is_set);
+         .map(|n| n.attr_is_set.clone());
       let r = iter::empty();
 
       let attr_infos = || {
@@ -259,14 +264,12 @@ impl<'a> EntityStyleAttrInfoIterArgs<'a> {
                .get_ref::<RendererState<NodeInterStyleAttrInfos>>()
                .map(|n| n.into_inner()),
             |_, entity_inter_style_state| {
-               let mut node_interaction = entity_ref
-                  .get::<Interaction>()
-                  .cloned()
-                  .and_then(interaction_to_style_interaction)
-                  .unwrap_or(StyleInteraction::empty());
-               if focused_entity == Some(entity_ref.id()) {
-                  node_interaction |= StyleInteraction::Focus;
-               }
+               let node_interaction = node_style_interaction(
+                  entity_ref.get::<Interaction>().cloned(),
+                  entity_ref.get::<ElementStates>(),
+                  focused_entity == Some(entity_ref.id()),
+                  focus_visible,
+               );
 
                match self.limit_attr_ids {
                   Some(n) => n
@@ -1,13 +1,15 @@
-use bevy_ecs::entity::Entity;
 use bevy_ecs::prelude::{Query, Res};
 use bevy_ecs::system::{Commands, ResMut};
+use bevy_ui::Interaction;
 
 use rxy_core::style::{NodeInterStyleAttrInfos, NodeStyleAttrInfos, StyleInteraction};
 
 use super::attr_iter::{EntityStyleWorldQuery, StateOwnerWithNodeId};
-use super::interaction_style::{AttrSetBitsIterExt, SetAttrValuesCommand};
+use super::interaction_style::{node_style_interaction, AttrSetBitsIterExt, SetAttrValuesCommand};
 use super::node_style_state::NodeStyleSheetsState;
-use super::rxy_bevy_crate::{ElementEntityExtraData, FocusedEntity, RendererState};
+use super::rxy_bevy_crate::{
+   ElementEntityExtraData, ElementStates, FocusVisible, FocusedEntity, RendererState,
+};
 use super::Previous;
 
 pub fn update_focus_style(
@@ -17,8 +19,11 @@ pub fn update_focus_style(
       &ElementEntityExtraData,
       &RendererState<NodeInterStyleAttrInfos>,
       &RendererState<NodeStyleAttrInfos>,
+      Option<&Interaction>,
+      Option<&ElementStates>,
    )>,
    focus: Res<FocusedEntity>,
+   focus_visible: Res<FocusVisible>,
    mut previous_focus: ResMut<Previous<FocusedEntity>>,
 ) {
    let mut set_attrs_cmd = SetAttrValuesCommand::default();
@@ -27,107 +32,49 @@ pub fn update_focus_style(
    let previous_focus_entity = previous_focus.0 .0;
    *previous_focus = Previous(FocusedEntity(focus_entity));
 
-   do_f(
-      previous_focus_entity,
-      focus_entity,
-      &styled_query,
-      &mut set_attrs_cmd,
-      style_sheets_query,
-   );
-   commands.add(set_attrs_cmd);
-}
-
-fn do_f<'a, 'world, 'state>(
-   previous_focus_entity: Option<Entity>,
-   focus_entity: Option<Entity>,
-   styled_query: &Query<(
-      &ElementEntityExtraData,
-      &RendererState<NodeInterStyleAttrInfos>,
-      &RendererState<NodeStyleAttrInfos>,
-   )>,
-   set_attrs_cmd: &mut SetAttrValuesCommand,
-   style_sheets_query: Query<'world, 'state, &'a RendererState<NodeStyleSheetsState>>,
-) -> Query<'world, 'state, &'a RendererState<NodeStyleSheetsState>> {
-   match (previous_focus_entity, focus_entity) {
-      (None, Some(focus_entity)) => {
-         let Ok((entity_extra_data, RendererState(inter_attr_infos), _)) =
-            styled_query.get(focus_entity)
-         else {
-            return style_sheets_query;
-         };
-         let Some(focus_attr_infos) = inter_attr_infos.get(&StyleInteraction::Focus) else {
-            return style_sheets_query;
-         };
-
-         let entity_style_world_query = EntityStyleWorldQuery {
-            query: style_sheets_query,
-            current_entity: focus_entity,
-         };
-
-         for (attr_index, attr_info) in focus_attr_infos
-            .iter()
-            .map(|n| (*n.0, ()))
-            .filter_attr_already_set(entity_extra_data.attr_is_set)
-            .filter_map(|(attr_index, _)| {
-               focus_attr_infos
-                  .get(&attr_index)
-                  .map(|attr_info| (attr_index, attr_info))
-            })
-         {
-            let value = entity_style_world_query
-               .get_current_style_item_value(attr_info.top_item_id())
-               .unwrap();
-            set_attrs_cmd.add(focus_entity, attr_index, Some(value));
-         }
-         entity_style_world_query.query
-      }
-      (Some(previous_focus), None) => {
-         let Ok((entity_extra_data, RendererState(inter_attr_infos), RendererState(attr_infos))) =
-            styled_query.get(previous_focus)
-         else {
-            return style_sheets_query;
-         };
-         let Some(focus_attr_infos) = inter_attr_infos
-            .get(&StyleInteraction::Focus)
-            .map(|n| n.keys())
-         else {
-            return style_sheets_query;
-         };
+   let mut style_sheets_query = Some(style_sheets_query);
+   // blur the previous focused entity first, the focused entity may be the same one
+   for (entity, is_focused) in previous_focus_entity
+      .map(|n| (n, false))
+      .into_iter()
+      .chain(focus_entity.map(|n| (n, true)))
+   {
+      let Ok((
+         entity_extra_data,
+         RendererState(inter_attr_infos),
+         RendererState(attr_infos),
+         interaction,
+         states,
+      )) = styled_query.get(entity)
+      else {
+         continue;
+      };
+      let style_interaction =
+         node_style_interaction(interaction.cloned(), states, is_focused, focus_visible.0);
 
-         let entity_style_world_query = EntityStyleWorldQuery {
-            query: style_sheets_query,
-            current_entity: previous_focus,
-         };
+      let entity_style_world_query = EntityStyleWorldQuery {
+         query: style_sheets_query.take().unwrap(),
+         current_entity: entity,
+      };
 
-         for (attr_index, _) in focus_attr_infos
-            .map(|n| (*n, ()))
-            .filter_attr_already_set(entity_extra_data.attr_is_set)
-         {
-            let value = attr_infos.get(&attr_index).map(|attr_info| {
+      // the attrs of the focus and focus visible style sheets
+      for (attr_index, _) in inter_attr_infos
+         .iter()
+         .filter(|n| n.0.contains(StyleInteraction::Focus))
+         .flat_map(|n| n.1.keys().map(|n| (*n, ())))
+         .filter_attr_already_set(entity_extra_data.attr_is_set.clone())
+      {
+         let value = inter_attr_infos
+            .match_attr(attr_index, style_interaction, false)
+            .or_else(|| attr_infos.get(&attr_index))
+            .map(|attr_info| {
                entity_style_world_query
                   .get_current_style_item_value(attr_info.top_item_id())
                   .unwrap()
             });
-            set_attrs_cmd.add(previous_focus, attr_index, value);
-         }
-         entity_style_world_query.query
-      }
-      (Some(previous_focus_entity), Some(focus_entity)) => {
-         let style_sheets_query = do_f(
-            Some(previous_focus_entity),
-            None,
-            styled_query,
-            set_attrs_cmd,
-            style_sheets_query,
-         );
-         do_f(
-            None,
-            Some(focus_entity),
-            styled_query,
-            set_attrs_cmd,
-            style_sheets_query,
-         )
+         set_attrs_cmd.add(entity, attr_index, value);
       }
-      _ => style_sheets_query,
+      style_sheets_query = Some(entity_style_world_query.query);
    }
+   commands.add(set_attrs_cmd);
 }
@@ -1,4 +1,5 @@
 use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::change_detection::DetectChangesMut;
 use bevy_ecs::entity::{Entity, EntityHashMap};
 use bevy_ecs::prelude::{Changed, Commands, Query, With, World};
 use bevy_ecs::system::{Command, ResMut};
@@ -13,7 +14,9 @@ use crate::attrs::get_attr_by_index;
 
 use super::attr_iter::{EntityStyleWorldQuery, StateOwnerWithNodeId};
 use super::node_style_state::NodeStyleSheetsState;
-use super::rxy_bevy_crate::{AttrSetBits, ElementEntityExtraData, FocusedEntity, RendererState};
+use super::rxy_bevy_crate::{
+   AttrSetBits, ElementEntityExtraData, ElementStates, FocusVisible, FocusedEntity, RendererState,
+};
 use super::Previous;
 
 #[derive(Default, DerefMut, Deref, Debug)]
@@ -43,7 +46,8 @@ impl Command for SetAttrValuesCommand {
          let attr_is_set = entity_world_mut
             .get_mut::<ElementEntityExtraData>()
             .unwrap()
-            .attr_is_set;
+            .attr_is_set
+            .clone();
          let world = entity_world_mut.into_world_mut();
          for (attr_index, value) in changed.into_iter().filter_attr_already_set(attr_is_set) {
             get_attr_by_index(attr_index).set_value(world, entity, value);
@@ -60,6 +64,42 @@ pub fn interaction_to_style_interaction(interaction: Interaction) -> Option<Styl
    }
 }
 
+pub fn element_states_to_style_interaction(states: &ElementStates) -> StyleInteraction {
+   let mut style_interaction = StyleInteraction::empty();
+   style_interaction.set(StyleInteraction::Disabled, states.disabled);
+   style_interaction.set(StyleInteraction::Checked, states.checked);
+   style_interaction.set(StyleInteraction::Selected, states.selected);
+   style_interaction.set(StyleInteraction::DragOver, states.drag_over);
+   style_interaction
+}
+
+/// The interaction used to match the interaction style sheets of a node.
+/// A disabled node is neither hovered, active nor focused.
+pub fn node_style_interaction(
+   interaction: Option<Interaction>,
+   states: Option<&ElementStates>,
+   is_focused: bool,
+   focus_visible: bool,
+) -> StyleInteraction {
+   if let Some(states) = states.filter(|n| n.disabled) {
+      return element_states_to_style_interaction(states);
+   }
+   let mut style_interaction = interaction
+      .and_then(interaction_to_style_interaction)
+      .unwrap_or(StyleInteraction::empty());
+   if is_focused {
+      style_interaction |= if focus_visible {
+         StyleInteraction::FocusVisible
+      } else {
+         StyleInteraction::Focus
+      };
+   }
+   if let Some(states) = states {
+      style_interaction |= element_states_to_style_interaction(states);
+   }
+   style_interaction
+}
+
 pub trait AttrSetBitsIterExt<M> {
    fn filter_attr_already_set(
       self,
@@ -76,7 +116,7 @@ where
       attr_is_set: AttrSetBits,
    ) -> impl Iterator<Item = (AttrIndex, M)> {
       self.filter(move |(attr_index, _)| {
-         !ElementEntityExtraData::static_is_set_attr(attr_is_set, *attr_index)
+         !ElementEntityExtraData::static_is_set_attr(&attr_is_set, *attr_index)
       })
    }
 }
@@ -92,6 +132,7 @@ pub fn update_interaction_styles(
          &RendererState<NodeStyleAttrInfos>,
          &Interaction,
          &mut Previous<Interaction>,
+         Option<&ElementStates>,
       ),
       (
          Changed<Interaction>,
@@ -99,6 +140,7 @@ pub fn update_interaction_styles(
       ),
    >,
    mut focus: ResMut<FocusedEntity>,
+   mut focus_visible: ResMut<FocusVisible>,
 ) {
    if inter_styled_query.is_empty() {
       return;
@@ -106,7 +148,7 @@ pub fn update_interaction_styles(
    let mut set_attrs_cmd = SetAttrValuesCommand::default();
 
    let mut style_sheets_query = Some(style_sheets_query);
-   let mut attr_bits: AttrSetBits;
+   let mut attr_bits = AttrSetBits::default();
    for (
       entity,
       entity_extra_data,
@@ -114,25 +156,34 @@ pub fn update_interaction_styles(
       RendererState(entity_style_state),
       interaction,
       mut previous_interaction,
+      states,
    ) in inter_styled_query.iter_mut()
    {
       if entity_inter_style_state.is_empty() {
          continue;
       }
       let prev_interaction = previous_interaction.0.clone();
-      let interaction = interaction.clone();
+      // a disabled node keeps the styles of no interaction
+      let interaction = if states.is_some_and(|n| n.disabled) {
+         Interaction::None
+      } else {
+         interaction.clone()
+      };
       *previous_interaction = Previous(interaction);
 
       let entity_style_world_query = EntityStyleWorldQuery {
          query: style_sheets_query.take().unwrap(),
          current_entity: entity,
       };
-      attr_bits = 0;
+      attr_bits.clear();
 
       let style_interaction = interaction_to_style_interaction(interaction);
       let pre_style_interaction = interaction_to_style_interaction(prev_interaction);
 
       let is_focused = focus.0 == Some(entity);
+      // the interaction of the node without hover and active
+      let base_style_interaction =
+         node_style_interaction(None, states, is_focused, focus_visible.0);
       match (prev_interaction, interaction) {
          (_, Interaction::None) => {
             for (attr_index, _matched_interaction) in entity_inter_style_state
@@ -145,25 +196,19 @@ pub fn update_interaction_styles(
                   },
                   false,
                )
-               .filter_attr_already_set(entity_extra_data.attr_is_set | attr_bits)
+               .filter_attr_already_set(entity_extra_data.attr_is_set.clone())
             {
-               // todo: extract to type
-               if (attr_bits >> attr_index) & 1 == 1 {
+               if !attr_bits.insert(attr_index) {
                   continue;
                }
-               attr_bits |= 1 << attr_index;
-               let value = if is_focused {
-                  entity_inter_style_state
-                     .get_attr_info(StyleInteraction::Focus, attr_index)
-                     .or_else(|| entity_style_state.get(&attr_index))
-               } else {
-                  entity_style_state.get(&attr_index)
-               }
-               .map(|attr_info| {
-                  entity_style_world_query
-                     .get_current_style_item_value(attr_info.top_item_id())
-                     .unwrap()
-               });
+               let value = entity_inter_style_state
+                  .match_attr(attr_index, base_style_interaction, false)
+                  .or_else(|| entity_style_state.get(&attr_index))
+                  .map(|attr_info| {
+                     entity_style_world_query
+                        .get_current_style_item_value(attr_info.top_item_id())
+                        .unwrap()
+                  });
                set_attrs_cmd.add(entity, attr_index, value);
             }
          }
@@ -173,21 +218,24 @@ pub fn update_interaction_styles(
             // todo: code decoupling
             if interaction == Interaction::Pressed && focus.0 != Some(entity) {
                *focus = FocusedEntity(Some(entity));
+               focus_visible.set_if_neq(FocusVisible(false));
             }
 
-            for (attr_index, matched_interaction) in entity_inter_style_state
+            let node_style_interaction =
+               style_interaction.unwrap_or(StyleInteraction::empty()) | base_style_interaction;
+            for (attr_index, _matched_interaction) in entity_inter_style_state
                .iter_match_attr_ids(
                   style_interaction,
                   prev_interaction == Interaction::Pressed && interaction == Interaction::Hovered,
                )
-               .filter_attr_already_set(entity_extra_data.attr_is_set | attr_bits)
+               .filter_attr_already_set(entity_extra_data.attr_is_set.clone())
             {
-               if (attr_bits >> attr_index) & 1 == 1 {
+               if !attr_bits.insert(attr_index) {
                   continue;
                }
-               attr_bits |= 1 << attr_index;
+               // higher priority states such as disabled are kept
                let value = entity_inter_style_state
-                  .get_attr_info(matched_interaction, attr_index)
+                  .match_attr(attr_index, node_style_interaction, false)
                   .map(|attr_info| {
                      entity_style_world_query
                         .get_current_style_item_value(attr_info.top_item_id())
@@ -204,3 +252,29 @@ pub fn update_interaction_styles(
 
    commands.add(set_attrs_cmd);
 }
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn disabled_node_ignores_interaction_and_focus() {
+      let enabled = ElementStates {
+         checked: true,
+         ..Default::default()
+      };
+      assert_eq!(
+         node_style_interaction(Some(Interaction::Hovered), Some(&enabled), true, true),
+         StyleInteraction::Hover | StyleInteraction::FocusVisible | StyleInteraction::Checked
+      );
+
+      let disabled = ElementStates {
+         disabled: true,
+         ..enabled
+      };
+      assert_eq!(
+         node_style_interaction(Some(Interaction::Pressed), Some(&disabled), true, false),
+         StyleInteraction::Disabled | StyleInteraction::Checked
+      );
+   }
+}
@@ -1,17 +1,31 @@
 use std::any::TypeId;
 
+use bevy_ecs::entity::Entity;
+
 pub use attr_iter::EntityStyleAttrInfoIterArgs;
 pub(crate) use attr_iter::StateOwner;
 pub use attr_syncer::EntityAttrSyncer;
 pub use element_view_ext::*;
 pub use entity_world_ref::*;
-pub use interaction_style::interaction_to_style_interaction;
-pub use plugin::{Previous, RxyStyleSheetPlugin};
+pub use interaction_style::{
+   element_states_to_style_interaction, interaction_to_style_interaction, node_style_interaction,
+};
+pub use plugin::{Previous, RxyStyleSheetPlugin, TypedStyleNames};
 use rxy_bevy_crate::BevyRenderer;
 use rxy_core::style::{AppliedStyleSheet, StyleSheetCtx, StyleSheetsInfo};
+pub use selector_style::{
+   Combinator, CompoundSelector, Nth, PseudoClass, Selector, SelectorParseError,
+   SelectorStyleRule, SelectorStyleWorldExt, SelectorStyles,
+};
 pub use shared_style_sheets::SharedStyleState;
 pub use shared_style_view::*;
+pub use style_sheet_asset::{
+   AssetStyle, AssetStyleSheets, StyleSheetAsset, StyleSheetAssetError, StyleSheetAssetLoader,
+};
 pub use style_sheets::res;
+pub use theme::{
+   token, Theme, ThemeColors, ThemeFontSizes, ThemeMode, ThemeRadii, ThemeSpacing, ThemeTokens,
+};
 
 pub use crate as rxy_bevy_crate;
 
@@ -24,16 +38,21 @@ mod interaction_style;
 mod node_style_state;
 mod node_tree;
 mod plugin;
+mod selector_style;
 mod shared_style_sheets;
 mod shared_style_view;
+mod state_style;
+mod style_sheet_asset;
 mod style_sheets;
+mod theme;
 
 pub type Result<T = ()> = rxy_core::style::Result<BevyRenderer, T>;
 pub type StyleError = rxy_core::style::StyleError<BevyRenderer>;
 
 pub mod prelude {
    pub use super::{
-      res, typed_shared_style_sheets, RxyStyleSheetPlugin, SchemaCtxExt, StyleError,
+      res, token, typed_shared_style_sheets, RxyStyleSheetPlugin, SchemaCtxExt,
+      SelectorStyleWorldExt, StyleError, StyleSheetAsset, Theme, ThemeMode, ThemeTokens,
       TypedStyleLabel,
    };
 }
@@ -46,6 +65,17 @@ pub fn typed_shared_style_sheets(
    StyleSheetsInfo,
 ) {
    let entity = ctx.world.get_typed_entity(type_id).unwrap();
+   shared_style_sheets(entity, ctx)
+}
+
+/// Subscribes the node to the shared style entity and applies its style sheets as shared.
+pub fn shared_style_sheets(
+   entity: Entity,
+   ctx: StyleSheetCtx<BevyRenderer>,
+) -> (
+   impl Iterator<Item = AppliedStyleSheet<BevyRenderer>> + Send + 'static,
+   StyleSheetsInfo,
+) {
    {
       let mut entity_world_mut = ctx.world.entity_mut(entity);
       let shared_style_sheets = entity_world_mut.get_shared_style_state().unwrap();
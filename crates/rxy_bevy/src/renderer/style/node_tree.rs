@@ -2,14 +2,15 @@ use bevy_ecs::prelude::{EntityWorldMut, World};
 use bevy_ui::Interaction;
 
 use rxy_core::style::{
-   AppliedStyleSheet, ApplyStyleSheetsMemberState, AttrStyleOwner, NodeInterStyleAttrInfos,
-   NodeStyleAttrInfos, NodeStyleSheetId, StyleSheetCtx, StyleSheetDefinition, StyleSheetLocation,
-   StyleSheets, StyledNodeTree,
+   AppliedStyleSheet, ApplyStyleSheetsMemberState, AttrStyleOwner, BoxedStyleTokenResolver,
+   NodeInterStyleAttrInfos, NodeStyleAttrInfos, NodeStyleSheetId, StyleSheetCtx,
+   StyleSheetDefinition, StyleSheetLocation, StyleSheets, StyleTokenTarget, StyledNodeTree,
 };
-use rxy_core::{AttrIndex, RendererNodeId};
+use rxy_core::{AttrIndex, NodeTree, RendererNodeId};
 
 use crate::attrs::get_attr_by_index;
 use crate::renderer::style::node_style_state::NodeStyleSheetsState;
+use crate::renderer::style::theme::StyleTokenWatchers;
 use crate::renderer::style::{
    EntityStyleAttrInfoIterArgs, Previous, StyleEntityMutExt, StyleEntityWorldMutExt,
 };
@@ -49,13 +50,20 @@ impl StyledNodeTree<BevyRenderer> for World {
       node_id: RendererNodeId<BevyRenderer>,
       member_state: ApplyStyleSheetsMemberState,
    ) -> Result<(), StyleError> {
+      if let Some(watchers) = self.get_node_state_mut::<StyleTokenWatchers>(&node_id) {
+         watchers.remove_inline_style_sheets(
+            member_state.inline_sheet_index,
+            member_state.inline_sheet_count,
+         );
+      }
       let mut entity_world_mut: EntityWorldMut<'_> = self.entity_mut(node_id);
       let attr_is_set = entity_world_mut
          .get_mut::<ElementEntityExtraData>()
          .ok_or(StyleError::NoFoundElementEntityExtraData {
             node_id: node_id.clone(),
          })?
-         .attr_is_set;
+         .attr_is_set
+         .clone();
 
       let mut reset_keys = vec![];
 
@@ -100,7 +108,7 @@ impl StyledNodeTree<BevyRenderer> for World {
                   },
                   style_state,
                   |key: AttrIndex| {
-                     if !ElementEntityExtraData::static_is_set_attr(attr_is_set, key) {
+                     if !ElementEntityExtraData::static_is_set_attr(&attr_is_set, key) {
                         reset_keys.push(key);
                      }
                   },
@@ -138,7 +146,7 @@ impl StyledNodeTree<BevyRenderer> for World {
                            },
                            style_state,
                            |key: AttrIndex| {
-                              if !ElementEntityExtraData::static_is_set_attr(attr_is_set, key) {
+                              if !ElementEntityExtraData::static_is_set_attr(&attr_is_set, key) {
                                  reset_keys.push(key);
                               }
                            },
@@ -384,4 +392,25 @@ impl StyledNodeTree<BevyRenderer> for World {
       iter_args.iter_and_sync_set(entity_world_mut)?;
       Ok(())
    }
+
+   fn watch_style_token(
+      &mut self,
+      node_id: RendererNodeId<BevyRenderer>,
+      target: StyleTokenTarget,
+      resolver: BoxedStyleTokenResolver<BevyRenderer>,
+   ) {
+      self
+         .get_or_insert_default_node_state::<StyleTokenWatchers>(&node_id)
+         .insert(target, resolver);
+   }
+
+   fn unwatch_style_token(
+      &mut self,
+      node_id: RendererNodeId<BevyRenderer>,
+      target: StyleTokenTarget,
+   ) {
+      if let Some(watchers) = self.get_node_state_mut::<StyleTokenWatchers>(&node_id) {
+         watchers.remove(target);
+      }
+   }
 }
@@ -2,20 +2,34 @@ use core::any::TypeId;
 use core::fmt::Debug;
 
 use bevy_app::{App, Plugin, Update};
+use bevy_asset::{AssetApp, AssetEvent};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::component::Component;
 use bevy_ecs::entity::Entity;
-use bevy_ecs::prelude::{DetectChanges, IntoSystemConfigs, Res, Resource, World};
+use bevy_ecs::prelude::{
+   Changed, DetectChanges, IntoSystemConfigs, Query, Res, Resource, With, World,
+};
+use bevy_ecs::schedule::common_conditions::{on_event, resource_exists_and_changed};
 use bevy_ecs::world::FromWorld;
 use bevy_utils::HashMap;
 
 use super::focus_style::update_focus_style;
 use super::interaction_style::update_interaction_styles;
-use super::rxy_bevy_crate::FocusedEntity;
+use super::rxy_bevy_crate::{ElementStates, FocusVisible, FocusedEntity};
+use super::selector_style::{selector_styles_changed, update_selector_styles, SelectorStyles};
+use super::state_style::update_state_styles;
+use super::style_sheet_asset::{
+   update_style_sheet_assets, AssetStyleSheets, StyleSheetAsset, StyleSheetAssetLoader,
+};
+use super::theme::{update_style_tokens, Theme};
 
 #[derive(Resource, Default, Deref, DerefMut)]
 pub struct TypedEntities(HashMap<TypeId, Entity>);
 
+/// The label names of the spawned typed styles, used to match the rules of style sheet assets.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct TypedStyleNames(HashMap<&'static str, TypeId>);
+
 #[derive(Resource)]
 pub struct RxySharedStyleContainer(pub Entity);
 
@@ -37,15 +51,39 @@ impl Plugin for RxyStyleSheetPlugin {
       app.init_resource::<RxySharedStyleContainer>()
          .init_resource::<TypedEntities>()
          .init_resource::<Previous<FocusedEntity>>()
+         .init_resource::<Theme>()
+         .init_resource::<TypedStyleNames>()
+         .init_resource::<AssetStyleSheets>()
+         .init_resource::<SelectorStyles>()
+         .init_asset::<StyleSheetAsset>()
+         .init_asset_loader::<StyleSheetAssetLoader>()
          .add_systems(
             Update,
             (
                update_interaction_styles.after(update_focus_style),
-               update_focus_style.run_if(|res: Res<FocusedEntity>| res.is_changed()),
+               update_focus_style.run_if(
+                  |focus: Res<FocusedEntity>, focus_visible: Res<FocusVisible>| {
+                     focus.is_changed() || focus_visible.is_changed()
+                  },
+               ),
+               update_state_styles
+                  .before(update_interaction_styles)
+                  .run_if(any_with_component_changed::<ElementStates>),
+               update_style_sheet_assets.run_if(on_event::<AssetEvent<StyleSheetAsset>>()),
+               update_style_tokens
+                  .before(update_interaction_styles)
+                  .run_if(resource_exists_and_changed::<Theme>),
+               update_selector_styles
+                  .before(update_interaction_styles)
+                  .run_if(selector_styles_changed),
             ),
          );
    }
 }
 
+fn any_with_component_changed<T: Component>(query: Query<(), (Changed<T>, With<T>)>) -> bool {
+   !query.is_empty()
+}
+
 #[derive(Default, Component, Resource, Clone, Debug, Deref, DerefMut)]
 pub struct Previous<T>(pub T);
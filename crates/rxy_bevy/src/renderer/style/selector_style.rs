@@ -0,0 +1,601 @@
+use core::fmt::{Display, Formatter};
+
+use bevy_core::Name;
+use bevy_ecs::prelude::{
+   Added, Changed, DetectChanges, DetectChangesMut, Entity, Or, Query, RemovedComponents, Res,
+   Resource, With, World,
+};
+use bevy_ecs::system::SystemState;
+use bevy_hierarchy::{Children, Parent};
+use bevy_utils::tracing::error;
+use bevy_utils::HashSet;
+
+use rxy_core::style::{
+   AppliedStyleSheet, ApplyStyleSheetsMemberState, StyleSheetCtx, StyleSheets, StyleSheetsInfo,
+   StyledNodeTree,
+};
+use rxy_core::NodeTree;
+
+use super::rxy_bevy_crate::BevyRenderer;
+use super::shared_style_sheets;
+use super::shared_style_view::spawn_shared_style;
+use super::StyleEntityRefExt;
+use crate::{ElementEntityExtraData, Leaving};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SelectorParseError {
+   Empty,
+   UnexpectedCombinator,
+   UnknownPseudoClass(String),
+   InvalidNth(String),
+}
+
+impl Display for SelectorParseError {
+   fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+      match self {
+         SelectorParseError::Empty => write!(f, "empty selector"),
+         SelectorParseError::UnexpectedCombinator => write!(f, "unexpected combinator"),
+         SelectorParseError::UnknownPseudoClass(n) => write!(f, "unknown pseudo class `{}`", n),
+         SelectorParseError::InvalidNth(n) => write!(f, "invalid nth expression `{}`", n),
+      }
+   }
+}
+
+impl std::error::Error for SelectorParseError {}
+
+/// Matches the one-based sibling index `a * n + b` for some `n >= 0`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Nth {
+   pub a: i32,
+   pub b: i32,
+}
+
+impl Nth {
+   pub fn parse(s: &str) -> Result<Self, SelectorParseError> {
+      let s = s.split_whitespace().collect::<String>();
+      let err = || SelectorParseError::InvalidNth(s.clone());
+      match s.as_str() {
+         "odd" => return Ok(Nth { a: 2, b: 1 }),
+         "even" => return Ok(Nth { a: 2, b: 0 }),
+         _ => {}
+      }
+      let Some((a, b)) = s.split_once('n') else {
+         return Ok(Nth {
+            a: 0,
+            b: s.parse().map_err(|_| err())?,
+         });
+      };
+      let a = match a {
+         "" | "+" => 1,
+         "-" => -1,
+         a => a.parse().map_err(|_| err())?,
+      };
+      let b = match b {
+         "" => 0,
+         b => b
+            .strip_prefix('+')
+            .unwrap_or(b)
+            .parse()
+            .map_err(|_| err())?,
+      };
+      Ok(Nth { a, b })
+   }
+
+   pub fn matches(&self, index: usize) -> bool {
+      let offset = index as i32 - self.b;
+      match self.a {
+         0 => offset == 0,
+         a => offset % a == 0 && offset / a >= 0,
+      }
+   }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PseudoClass {
+   FirstChild,
+   LastChild,
+   NthChild(Nth),
+   NthLastChild(Nth),
+}
+
+impl PseudoClass {
+   fn parse(s: &str) -> Result<Self, SelectorParseError> {
+      let nth = |prefix: &str| {
+         s.strip_prefix(prefix)
+            .and_then(|n| n.strip_prefix('('))
+            .and_then(|n| n.strip_suffix(')'))
+      };
+      match s {
+         "first" | "first-child" => Ok(PseudoClass::FirstChild),
+         "last" | "last-child" => Ok(PseudoClass::LastChild),
+         _ => {
+            if let Some(n) = nth("nth-last-child") {
+               Ok(PseudoClass::NthLastChild(Nth::parse(n)?))
+            } else if let Some(n) = nth("nth-child") {
+               Ok(PseudoClass::NthChild(Nth::parse(n)?))
+            } else {
+               Err(SelectorParseError::UnknownPseudoClass(s.to_string()))
+            }
+         }
+      }
+   }
+
+   fn matches(&self, index: usize, count: usize) -> bool {
+      match self {
+         PseudoClass::FirstChild => index == 1,
+         PseudoClass::LastChild => index == count,
+         PseudoClass::NthChild(nth) => nth.matches(index),
+         PseudoClass::NthLastChild(nth) => nth.matches(count + 1 - index),
+      }
+   }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Combinator {
+   Descendant,
+   Child,
+}
+
+/// A name (`*` for any element) followed by pseudo classes, e.g. `row:nth-child(odd)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompoundSelector {
+   pub name: Option<String>,
+   pub pseudo_classes: Vec<PseudoClass>,
+}
+
+impl CompoundSelector {
+   fn parse(s: &str) -> Result<Self, SelectorParseError> {
+      let mut parts = s.split(':');
+      let name = match parts.next().unwrap_or_default() {
+         "" | "*" => None,
+         name => Some(name.to_string()),
+      };
+      let pseudo_classes = parts.map(PseudoClass::parse).collect::<Result<_, _>>()?;
+      Ok(Self {
+         name,
+         pseudo_classes,
+      })
+   }
+
+   fn matches(&self, world: &World, entity: Entity) -> bool {
+      if let Some(name) = &self.name {
+         if world.get::<Name>(entity).map(|n| n.as_str()) != Some(name.as_str()) {
+            return false;
+         }
+      }
+      if self.pseudo_classes.is_empty() {
+         return true;
+      }
+      let Some((index, count)) = sibling_index(world, entity) else {
+         return false;
+      };
+      self.pseudo_classes.iter().all(|n| n.matches(index, count))
+   }
+}
+
+/// A css-like selector evaluated against the element hierarchy.
+/// Names are the values of the `name` attr.
+///
+/// ```ignore
+/// list > row:nth-child(even)
+/// list row:last
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Selector {
+   /// The compound selectors from the outermost one, each with the combinator to the previous one.
+   pub parts: Vec<(Combinator, CompoundSelector)>,
+}
+
+impl Selector {
+   pub fn parse(s: &str) -> Result<Self, SelectorParseError> {
+      let mut parts = vec![];
+      let mut combinator = Combinator::Descendant;
+      for token in Self::tokens(s) {
+         if token == ">" {
+            if parts.is_empty() || combinator == Combinator::Child {
+               return Err(SelectorParseError::UnexpectedCombinator);
+            }
+            combinator = Combinator::Child;
+            continue;
+         }
+         parts.push((combinator, CompoundSelector::parse(token)?));
+         combinator = Combinator::Descendant;
+      }
+      if combinator == Combinator::Child {
+         return Err(SelectorParseError::UnexpectedCombinator);
+      }
+      if parts.is_empty() {
+         return Err(SelectorParseError::Empty);
+      }
+      Ok(Self { parts })
+   }
+
+   /// Splits the selector into compound selectors and `>` combinators.
+   /// The whitespace inside parentheses, like in `:nth-child(2n + 1)`, does not split.
+   fn tokens(s: &str) -> Vec<&str> {
+      let mut tokens = vec![];
+      let mut depth = 0usize;
+      let mut start = None;
+      for (i, c) in s.char_indices() {
+         match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+         }
+         let separator = depth == 0 && (c.is_whitespace() || c == '>');
+         match (separator, start) {
+            (true, Some(token_start)) => {
+               tokens.push(&s[token_start..i]);
+               start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+         }
+         if separator && c == '>' {
+            tokens.push(">");
+         }
+      }
+      if let Some(start) = start {
+         tokens.push(&s[start..]);
+      }
+      tokens
+   }
+
+   pub fn matches(&self, world: &World, entity: Entity) -> bool {
+      self.matches_part(world, entity, self.parts.len() - 1)
+   }
+
+   fn matches_part(&self, world: &World, entity: Entity, index: usize) -> bool {
+      let (combinator, compound) = &self.parts[index];
+      if !compound.matches(world, entity) {
+         return false;
+      }
+      if index == 0 {
+         return true;
+      }
+      match combinator {
+         Combinator::Child => parent_element(world, entity)
+            .is_some_and(|parent| self.matches_part(world, parent, index - 1)),
+         Combinator::Descendant => {
+            let mut current = entity;
+            while let Some(parent) = parent_element(world, current) {
+               if self.matches_part(world, parent, index - 1) {
+                  return true;
+               }
+               current = parent;
+            }
+            false
+         }
+      }
+   }
+}
+
+fn is_element(world: &World, entity: Entity) -> bool {
+   world.get::<ElementEntityExtraData>(entity).is_some()
+}
+
+fn parent_element(world: &World, entity: Entity) -> Option<Entity> {
+   let parent = world.get::<Parent>(entity)?.get();
+   is_element(world, parent).then_some(parent)
+}
+
+/// The one-based index of the element among its sibling elements, and the count of them.
+/// The elements playing their leave animation are no longer counted.
+fn sibling_index(world: &World, entity: Entity) -> Option<(usize, usize)> {
+   let parent = world.get::<Parent>(entity)?.get();
+   let siblings = world.get::<Children>(parent)?;
+   let mut index = None;
+   let mut count = 0;
+   for sibling in siblings
+      .iter()
+      .filter(|n| is_element(world, **n) && world.get::<Leaving>(**n).is_none())
+   {
+      count += 1;
+      if *sibling == entity {
+         index = Some(count);
+      }
+   }
+   index.map(|index| (index, count))
+}
+
+pub struct SelectorStyleRule {
+   pub selector: Selector,
+   pub entity: Entity,
+}
+
+/// The selector styles, applied to every element that matches their selectors.
+#[derive(Resource, Default)]
+pub struct SelectorStyles {
+   rules: Vec<SelectorStyleRule>,
+   removed: Vec<Entity>,
+}
+
+impl SelectorStyles {
+   pub fn rules(&self) -> &[SelectorStyleRule] {
+      &self.rules
+   }
+}
+
+pub trait SelectorStyleWorldExt {
+   fn insert_selector_style<SS>(
+      &mut self,
+      selector: &str,
+      style_sheets: SS,
+   ) -> Result<Entity, SelectorParseError>
+   where
+      SS: StyleSheets<BevyRenderer>;
+
+   fn remove_selector_style(&mut self, entity: Entity);
+}
+
+impl SelectorStyleWorldExt for World {
+   fn insert_selector_style<SS>(
+      &mut self,
+      selector: &str,
+      style_sheets: SS,
+   ) -> Result<Entity, SelectorParseError>
+   where
+      SS: StyleSheets<BevyRenderer>,
+   {
+      let selector = Selector::parse(selector)?;
+      let entity = spawn_shared_style(self, None, style_sheets);
+      self
+         .get_resource_or_insert_with(SelectorStyles::default)
+         .rules
+         .push(SelectorStyleRule { selector, entity });
+      Ok(entity)
+   }
+
+   fn remove_selector_style(&mut self, entity: Entity) {
+      let Some(mut selector_styles) = self.get_resource_mut::<SelectorStyles>() else {
+         return;
+      };
+      selector_styles.rules.retain(|n| n.entity != entity);
+      selector_styles.removed.push(entity);
+   }
+}
+
+struct SelectorStyleSheets(Entity);
+
+impl StyleSheets<BevyRenderer> for SelectorStyleSheets {
+   fn style_sheets(
+      self,
+      ctx: StyleSheetCtx<BevyRenderer>,
+   ) -> (
+      impl Iterator<Item = AppliedStyleSheet<BevyRenderer>> + Send + 'static,
+      StyleSheetsInfo,
+   ) {
+      shared_style_sheets(self.0, ctx)
+   }
+}
+
+/// The selector styles applied to a node, and the style sheet slots of the styles that no longer
+/// match, reused by the next matching styles.
+#[derive(Default)]
+struct SelectorStyleMatches {
+   matched: Vec<(Entity, ApplyStyleSheetsMemberState)>,
+   free: Vec<ApplyStyleSheetsMemberState>,
+}
+
+impl SelectorStyleMatches {
+   /// Takes a free slot of the size of the style sheets of the selector style.
+   fn take_free(&mut self, world: &World, entity: Entity) -> Option<ApplyStyleSheetsMemberState> {
+      let info = world
+         .get_entity(entity)?
+         .get_style_sheets_state()
+         .ok()?
+         .style_sheets_info();
+      let index = self.free.iter().position(|n| {
+         n.inline_sheet_count == info.inline_style_sheet_count
+            && n.shared_sheet_count == info.shared_style_sheet_count
+      })?;
+      Some(self.free.swap_remove(index))
+   }
+}
+
+pub(crate) fn selector_styles_changed(
+   selector_styles: Option<Res<SelectorStyles>>,
+   changed_query: Query<
+      (),
+      Or<(
+         Changed<Children>,
+         Changed<Name>,
+         Added<ElementEntityExtraData>,
+         Added<Leaving>,
+      )>,
+   >,
+   mut removed_children: RemovedComponents<Children>,
+   mut removed_leaving: RemovedComponents<Leaving>,
+) -> bool {
+   let Some(selector_styles) = selector_styles else {
+      return false;
+   };
+   let removed = removed_children.read().count() + removed_leaving.read().count() > 0;
+   if selector_styles.is_changed() {
+      return true;
+   }
+   !selector_styles.rules.is_empty() && (removed || !changed_query.is_empty())
+}
+
+/// The changes of the hierarchy that can change which selectors match.
+type SelectorHierarchyChanges = (
+   Query<'static, 'static, Entity, Or<(Changed<Children>, Changed<Name>)>>,
+   Query<
+      'static,
+      'static,
+      (Entity, Option<&'static Parent>),
+      Or<(Added<ElementEntityExtraData>, Added<Leaving>)>,
+   >,
+   RemovedComponents<'static, 'static, Leaving>,
+);
+
+/// The elements whose selector matches may have changed, all of them if the rules changed,
+/// otherwise the subtrees of the changed children and names, and of the parents whose sibling
+/// indexes changed.
+fn selector_dirty_nodes(
+   world: &mut World,
+   changes: &mut SystemState<SelectorHierarchyChanges>,
+) -> Vec<Entity> {
+   let (changed, added, mut removed_leaving) = changes.get_mut(world);
+   let mut roots = changed.iter().collect::<Vec<_>>();
+   roots.extend(
+      added
+         .iter()
+         .map(|(entity, parent)| parent.map_or(entity, |n| n.get())),
+   );
+   let removed_leaving = removed_leaving.read().collect::<Vec<_>>();
+
+   if world.is_resource_changed::<SelectorStyles>() {
+      return world
+         .query_filtered::<Entity, With<ElementEntityExtraData>>()
+         .iter(world)
+         .collect();
+   }
+   roots.extend(
+      removed_leaving
+         .into_iter()
+         .filter_map(|entity| Some(world.get::<Parent>(entity)?.get())),
+   );
+
+   let mut visited = HashSet::new();
+   let mut nodes = vec![];
+   while let Some(entity) = roots.pop() {
+      if !visited.insert(entity) {
+         continue;
+      }
+      if is_element(world, entity) {
+         nodes.push(entity);
+      }
+      if let Some(children) = world.get::<Children>(entity) {
+         roots.extend(children.iter().copied());
+      }
+   }
+   nodes
+}
+
+pub(crate) fn update_selector_styles(
+   world: &mut World,
+   changes: &mut SystemState<SelectorHierarchyChanges>,
+) {
+   let removed = core::mem::take(
+      &mut world
+         .resource_mut::<SelectorStyles>()
+         .bypass_change_detection()
+         .removed,
+   );
+
+   for node_id in selector_dirty_nodes(world, changes) {
+      let matched = {
+         let selector_styles = world.resource::<SelectorStyles>();
+         selector_styles
+            .rules
+            .iter()
+            .filter(|n| n.selector.matches(world, node_id))
+            .map(|n| n.entity)
+            .collect::<Vec<_>>()
+      };
+      let unchanged = match world.get_node_state_ref::<SelectorStyleMatches>(&node_id) {
+         None => matched.is_empty(),
+         Some(matches) => {
+            matches.matched.len() == matched.len()
+               && matches.matched.iter().all(|n| matched.contains(&n.0))
+         }
+      };
+      if unchanged {
+         continue;
+      }
+      let mut matches = world
+         .take_node_state::<SelectorStyleMatches>(&node_id)
+         .unwrap_or_default();
+
+      let mut unmatched = vec![];
+      matches.matched.retain(|(entity, member_state)| {
+         let retain = matched.contains(entity);
+         if !retain {
+            unmatched.push(*member_state);
+         }
+         retain
+      });
+      for member_state in unmatched {
+         match world.unbuild_style_sheet(node_id, member_state) {
+            Ok(()) => matches.free.push(member_state),
+            Err(err) => error!("unbuild selector style error: {:?}", err),
+         }
+      }
+
+      for entity in matched {
+         if matches.matched.iter().any(|n| n.0 == entity) {
+            continue;
+         }
+         let free = matches.take_free(world, entity);
+         match world.build_style_sheets(node_id, SelectorStyleSheets(entity), free) {
+            // the state returned for a reused slot has its indexes moved past it
+            Ok(member_state) => matches.matched.push((entity, free.unwrap_or(member_state))),
+            Err(err) => {
+               error!("build selector style error: {:?}", err);
+               matches.free.extend(free);
+            }
+         }
+      }
+      if !matches.matched.is_empty() || !matches.free.is_empty() {
+         world.set_node_state(&node_id, matches);
+      }
+   }
+
+   for entity in removed {
+      world.despawn(entity);
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn parse_selector() {
+      let selector = Selector::parse("list>row:nth-child(2n+1):last").unwrap();
+      assert_eq!(selector.parts.len(), 2);
+      assert_eq!(selector.parts[1].0, Combinator::Child);
+      assert_eq!(selector.parts[1].1.name.as_deref(), Some("row"));
+      assert_eq!(
+         selector.parts[1].1.pseudo_classes,
+         vec![
+            PseudoClass::NthChild(Nth { a: 2, b: 1 }),
+            PseudoClass::LastChild
+         ]
+      );
+
+      let selector =
+         Selector::parse("list > row:nth-child( 2n + 1 ) > cell:nth-last-child(-n + 3)").unwrap();
+      assert_eq!(selector.parts.len(), 3);
+      assert!(selector.parts[1..].iter().all(|n| n.0 == Combinator::Child));
+      assert_eq!(
+         selector.parts[1].1.pseudo_classes,
+         vec![PseudoClass::NthChild(Nth { a: 2, b: 1 })]
+      );
+      assert_eq!(
+         selector.parts[2].1.pseudo_classes,
+         vec![PseudoClass::NthLastChild(Nth { a: -1, b: 3 })]
+      );
+
+      let selector = Selector::parse("list * :first").unwrap();
+      assert_eq!(selector.parts.len(), 3);
+      assert!(selector.parts.iter().all(|n| n.0 == Combinator::Descendant));
+
+      assert_eq!(Selector::parse(" "), Err(SelectorParseError::Empty));
+      assert_eq!(
+         Selector::parse("> row"),
+         Err(SelectorParseError::UnexpectedCombinator)
+      );
+   }
+
+   #[test]
+   fn nth_matches() {
+      let even = Nth::parse("even").unwrap();
+      assert!(even.matches(2) && even.matches(4) && !even.matches(1));
+      let first_three = Nth::parse("-n+3").unwrap();
+      assert!(first_three.matches(1) && first_three.matches(3) && !first_three.matches(4));
+      assert!(Nth::parse("3").unwrap().matches(3));
+      assert!(Nth::parse("x").is_err());
+   }
+}
@@ -10,10 +10,46 @@ use rxy_core::style::{StyleSheetCtx, StyleSheets};
 use rxy_core::{IntoView, RendererNodeId, View, ViewCtx};
 
 use super::node_style_state::NodeStyleSheetsState;
-use super::plugin::RxySharedStyleContainer;
+use super::plugin::{RxySharedStyleContainer, TypedStyleNames};
 use super::rxy_bevy_crate::SchemaCtx;
 use super::rxy_bevy_crate::{BevyRenderer, RendererState};
-use super::{SharedStyleState, StyleWorldExt};
+use super::style_sheet_asset::{override_style_sheets, AssetStyleSheets, DefaultStyleSheets};
+use super::{SharedStyleState, StyleEntityMutExt, StyleWorldExt};
+
+/// Spawns a shared style under the [`RxySharedStyleContainer`], its style sheets are applied to its subscribers.
+pub(crate) fn spawn_shared_style<SS>(
+   world: &mut World,
+   reserve_key: Option<RendererNodeId<BevyRenderer>>,
+   style_sheets: SS,
+) -> Entity
+where
+   SS: StyleSheets<BevyRenderer>,
+{
+   let shared_style_container = world.resource::<RxySharedStyleContainer>().0;
+   let name = bevy_core::Name::new("[shared_style]");
+   let node_id = match reserve_key {
+      None => world.spawn(name).set_parent(shared_style_container).id(),
+      Some(reserve_key) => world
+         .get_or_spawn(reserve_key)
+         .unwrap()
+         .insert(name)
+         .set_parent(shared_style_container)
+         .id(),
+   };
+
+   let (style_sheets, _info) = style_sheets.style_sheets(StyleSheetCtx {
+      inline_style_sheet_index: 0,
+      shared_style_sheet_index: 0,
+      world,
+      node_id,
+   });
+   let style_sheets_state: NodeStyleSheetsState = style_sheets.collect();
+   world.entity_mut(node_id).insert((
+      RendererState(style_sheets_state),
+      RendererState(SharedStyleState::default()),
+   ));
+   node_id
+}
 
 pub trait SchemaCtxExt {
    fn default_typed_style<SS>(
@@ -48,12 +84,13 @@ pub trait TypedStyleWorldExt {
    ) -> Entity
    where
       SS: StyleSheets<BevyRenderer>;
-   fn default_typed_style<SS>(
+   fn default_typed_style<L, SS>(
       &mut self,
-      typed_style: impl TypedStyleLabel,
+      typed_style: L,
       style_f: impl FnOnce() -> SS,
    ) -> (bool, Entity)
    where
+      L: TypedStyleLabel,
       SS: StyleSheets<BevyRenderer>;
 }
 
@@ -67,44 +104,45 @@ impl TypedStyleWorldExt for World {
    where
       SS: StyleSheets<BevyRenderer>,
    {
-      let shared_style_container = self.resource::<RxySharedStyleContainer>().0;
-      let name = bevy_core::Name::new("[shared_style]");
-      let node_id = match reserve_key {
-         None => self.spawn(name).set_parent(shared_style_container).id(),
-         Some(reserve_key) => self
-            .get_or_spawn(reserve_key)
+      let node_id = spawn_shared_style(self, reserve_key, style_sheets);
+      let mut entity_world_mut = self.entity_mut(node_id);
+      let default_style_sheets = DefaultStyleSheets(
+         entity_world_mut
+            .get_style_sheets_state()
             .unwrap()
-            .insert(name)
-            .set_parent(shared_style_container)
-            .id(),
-      };
-
-      let (style_sheets, _info) = style_sheets.style_sheets(StyleSheetCtx {
-         inline_style_sheet_index: 0,
-         shared_style_sheet_index: 0,
-         world: self,
-         node_id,
-      });
-      let style_sheets_state: NodeStyleSheetsState = style_sheets.collect();
-      self.entity_mut(node_id).insert((
-         RendererState(style_sheets_state),
-         RendererState(SharedStyleState::default()),
-      ));
+            .inline_style_sheet
+            .clone(),
+      );
+      entity_world_mut.insert(RendererState(default_style_sheets));
+      let asset_style_sheets = self
+         .resource::<TypedStyleNames>()
+         .iter()
+         .find(|n| *n.1 == type_id)
+         .and_then(|n| self.get_resource::<AssetStyleSheets>()?.get(*n.0).cloned());
+      if let Some(asset_style_sheets) = asset_style_sheets {
+         let mut entity_world_mut = self.entity_mut(node_id);
+         let style_sheets_state = entity_world_mut.get_style_sheets_state().unwrap();
+         override_style_sheets(style_sheets_state, asset_style_sheets);
+      }
       self.insert_typed_entity(type_id, node_id);
       node_id
    }
 
-   fn default_typed_style<SS>(
+   fn default_typed_style<L, SS>(
       &mut self,
-      typed_style: impl TypedStyleLabel,
+      typed_style: L,
       style_f: impl FnOnce() -> SS,
    ) -> (bool, Entity)
    where
+      L: TypedStyleLabel,
       SS: StyleSheets<BevyRenderer>,
    {
       if let Some(entity) = self.get_typed_entity(typed_style.type_id()) {
          return (false, entity);
       }
+      self
+         .resource_mut::<TypedStyleNames>()
+         .insert(L::label_name(), typed_style.type_id());
       (
          true,
          self.spawn_typed_style(None, typed_style.type_id(), style_f()),
@@ -118,6 +156,7 @@ where
    SS: StyleSheets<BevyRenderer>,
 {
    type_id: TypeId,
+   label_name: &'static str,
    style_sheets: SS,
 }
 
@@ -133,6 +172,9 @@ where
       _reserve_key: Option<Self::Key>,
       _will_rebuild: bool,
    ) -> Self::Key {
+      ctx.world
+         .resource_mut::<TypedStyleNames>()
+         .insert(self.label_name, self.type_id);
       ctx.world
          .spawn_typed_style(None, self.type_id, self.style_sheets);
    }
@@ -147,12 +189,19 @@ pub trait DefaultStyleDef {
 }
 
 pub trait TypedStyleLabel: Copy + Clone + Send + 'static {
+   /// The name of the label in a [`StyleSheetAsset`](super::StyleSheetAsset), the type name without its path.
+   fn label_name() -> &'static str {
+      let name = core::any::type_name::<Self>();
+      name.rsplit("::").next().unwrap_or(name)
+   }
+
    fn def<SS>(style: SS) -> TypedSharedStyleView<SS>
    where
       SS: StyleSheets<BevyRenderer>,
    {
       TypedSharedStyleView {
          type_id: TypeId::of::<Self>(),
+         label_name: Self::label_name(),
          style_sheets: style,
       }
    }
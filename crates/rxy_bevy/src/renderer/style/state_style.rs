@@ -0,0 +1,58 @@
+use bevy_ecs::prelude::{Changed, Entity, With, World};
+use bevy_utils::tracing::error;
+
+use rxy_core::style::{NodeInterStyleAttrInfos, StyleInteraction};
+use rxy_core::AttrIndex;
+
+use crate::attrs::get_attr_by_index;
+
+use super::rxy_bevy_crate::{ElementEntityExtraData, ElementStates, RendererState};
+use super::EntityStyleAttrInfoIterArgs;
+
+/// Resyncs the attrs of the disabled, checked, selected and drag-over style sheets when the [`ElementStates`] of a node change.
+pub(crate) fn update_state_styles(world: &mut World) {
+   let states_interaction = StyleInteraction::Disabled
+      | StyleInteraction::Checked
+      | StyleInteraction::Selected
+      | StyleInteraction::DragOver;
+   let entities = world
+      .query_filtered::<Entity, (
+         Changed<ElementStates>,
+         With<RendererState<NodeInterStyleAttrInfos>>,
+      )>()
+      .iter(world)
+      .collect::<Vec<_>>();
+
+   for entity in entities {
+      let entity_ref = world.entity(entity);
+      let (Some(entity_extra_data), Some(RendererState(inter_attr_infos))) = (
+         entity_ref.get::<ElementEntityExtraData>(),
+         entity_ref.get::<RendererState<NodeInterStyleAttrInfos>>(),
+      ) else {
+         continue;
+      };
+      let mut attr_ids: Vec<AttrIndex> = inter_attr_infos
+         .iter()
+         .filter(|n| n.0.intersects(states_interaction))
+         .flat_map(|n| n.1.keys().cloned())
+         .filter(|n| !entity_extra_data.is_set_attr(*n))
+         .collect();
+      attr_ids.sort();
+      attr_ids.dedup();
+      if attr_ids.is_empty() {
+         continue;
+      }
+
+      // reset first, the attrs that no style sheet matches anymore keep their default values
+      for attr_id in attr_ids.iter().cloned() {
+         get_attr_by_index(attr_id).set_value(world, entity, None);
+      }
+      let iter_args = EntityStyleAttrInfoIterArgs {
+         limit_attr_ids: Some(attr_ids.as_slice()),
+         ..EntityStyleAttrInfoIterArgs::all_kind()
+      };
+      if let Err(err) = iter_args.iter_and_sync_set(world.entity_mut(entity)) {
+         error!("sync state styles error: {:?}", err);
+      }
+   }
+}
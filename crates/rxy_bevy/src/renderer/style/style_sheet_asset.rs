@@ -0,0 +1,759 @@
+use core::fmt::{Display, Formatter};
+
+use bevy_asset::io::Reader;
+use bevy_asset::{Asset, AssetEvent, AssetId, AssetLoader, Assets, AsyncReadExt, LoadContext};
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::event::{Events, ManualEventReader};
+use bevy_ecs::prelude::{Entity, Local, Resource, World};
+use bevy_reflect::TypePath;
+use bevy_utils::tracing::warn;
+use bevy_utils::{BoxedFuture, HashMap};
+
+use rxy_core::style::{
+   ApplyStyleSheetsMemberState, AttrStyleOwner, NodeStyleSheetId, StyleInteraction, StyleItemValue,
+   StyleSheetDefinition, StyleSheetId, StyleSheetIndex, StyleSheetLocation,
+};
+use rxy_core::{MemberHashMapState, NodeTree};
+
+use crate::attrs::{get_attr_by_index, get_attr_by_name};
+use crate::renderer::style::node_style_state::NodeStyleSheetsState;
+use crate::renderer::style::theme::StyleTokenWatchers;
+use crate::renderer::style::{
+   EntityStyleAttrInfoIterArgs, Result, StyleEntityMutExt, StyleEntityWorldMutExt, StyleWorldExt,
+   TypedStyleNames,
+};
+use crate::{parse_attr_value, parse_css_val, ElementEntityExtraData};
+
+/// Shorthands that set the four sides in css order: top, right, bottom, left.
+const SIDE_SHORTHANDS: &[(&str, [&str; 4])] = &[
+   (
+      "padding",
+      [
+         "padding_top",
+         "padding_right",
+         "padding_bottom",
+         "padding_left",
+      ],
+   ),
+   (
+      "margin",
+      ["margin_top", "margin_right", "margin_bottom", "margin_left"],
+   ),
+   (
+      "border_width",
+      ["border_top", "border_right", "border_bottom", "border_left"],
+   ),
+];
+
+/// The style sheets of a [`TypedStyleLabel`](super::TypedStyleLabel), by its label name.
+#[derive(Clone, Debug)]
+pub struct AssetStyle {
+   pub label: String,
+   pub style_sheets: Vec<StyleSheetDefinition>,
+}
+
+/// A style sheet file in a css subset.
+///
+/// ```css
+/// /* selectors are the names of `TypedStyle` labels */
+/// CheckboxStyle {
+///    width: 20px;
+///    border-width: 1px;
+///    border-color: #4b5563;
+/// }
+///
+/// CheckboxStyle:hover, ButtonStyle:hover {
+///    bg-color: slate-600;
+/// }
+/// ```
+///
+/// A rule replaces the style sheet of the same interaction defined in code.
+/// When the file changes, the shared styles are updated for all nodes using them.
+#[derive(Asset, TypePath, Clone, Debug, Default)]
+pub struct StyleSheetAsset {
+   pub styles: Vec<AssetStyle>,
+}
+
+impl StyleSheetAsset {
+   pub fn parse(source: &str) -> core::result::Result<Self, StyleSheetAssetError> {
+      let source = strip_comments(source);
+      let mut asset = StyleSheetAsset::default();
+      let mut rest = source.as_str();
+      while let Some(open) = rest.find('{') {
+         let selectors = rest[..open].trim();
+         let close = rest[open..].find('}').ok_or_else(|| {
+            StyleSheetAssetError::Parse(format!("missing `}}` after `{selectors}`"))
+         })? + open;
+         let items = parse_declarations(&rest[open + 1..close])?;
+         for selector in selectors.split(',') {
+            let (label, interaction) = parse_selector(selector.trim())?;
+            asset.push(label, interaction, items.clone());
+         }
+         rest = &rest[close + 1..];
+      }
+      if !rest.trim().is_empty() {
+         return Err(StyleSheetAssetError::Parse(format!(
+            "unexpected `{}`",
+            rest.trim()
+         )));
+      }
+      Ok(asset)
+   }
+
+   fn push(
+      &mut self,
+      label: &str,
+      interaction: Option<StyleInteraction>,
+      items: Vec<StyleItemValue>,
+   ) {
+      let style = match self.styles.iter_mut().position(|n| n.label == label) {
+         Some(index) => &mut self.styles[index],
+         None => {
+            self.styles.push(AssetStyle {
+               label: label.to_string(),
+               style_sheets: vec![],
+            });
+            self.styles.last_mut().unwrap()
+         }
+      };
+      match style
+         .style_sheets
+         .iter_mut()
+         .find(|n| n.interaction == interaction)
+      {
+         None => style
+            .style_sheets
+            .push(StyleSheetDefinition { interaction, items }),
+         Some(style_sheet) => {
+            for item in items {
+               push_item(&mut style_sheet.items, item);
+            }
+         }
+      }
+   }
+}
+
+#[derive(Debug)]
+pub enum StyleSheetAssetError {
+   Io(std::io::Error),
+   Utf8(core::str::Utf8Error),
+   Parse(String),
+}
+
+impl Display for StyleSheetAssetError {
+   fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+      match self {
+         StyleSheetAssetError::Io(err) => write!(f, "could not read style sheet: {err}"),
+         StyleSheetAssetError::Utf8(err) => write!(f, "style sheet is not utf-8: {err}"),
+         StyleSheetAssetError::Parse(err) => write!(f, "invalid style sheet: {err}"),
+      }
+   }
+}
+
+impl std::error::Error for StyleSheetAssetError {}
+
+impl From<std::io::Error> for StyleSheetAssetError {
+   fn from(value: std::io::Error) -> Self {
+      StyleSheetAssetError::Io(value)
+   }
+}
+
+impl From<core::str::Utf8Error> for StyleSheetAssetError {
+   fn from(value: core::str::Utf8Error) -> Self {
+      StyleSheetAssetError::Utf8(value)
+   }
+}
+
+#[derive(Default)]
+pub struct StyleSheetAssetLoader;
+
+impl AssetLoader for StyleSheetAssetLoader {
+   type Asset = StyleSheetAsset;
+   type Settings = ();
+   type Error = StyleSheetAssetError;
+
+   fn load<'a>(
+      &'a self,
+      reader: &'a mut Reader,
+      _settings: &'a Self::Settings,
+      _load_context: &'a mut LoadContext,
+   ) -> BoxedFuture<'a, core::result::Result<Self::Asset, Self::Error>> {
+      Box::pin(async move {
+         let mut bytes = Vec::new();
+         reader.read_to_end(&mut bytes).await?;
+         StyleSheetAsset::parse(core::str::from_utf8(&bytes)?)
+      })
+   }
+
+   fn extensions(&self) -> &[&str] {
+      &["css"]
+   }
+}
+
+fn strip_comments(source: &str) -> String {
+   let mut result = String::with_capacity(source.len());
+   let mut rest = source;
+   while let Some(start) = rest.find("/*") {
+      result.push_str(&rest[..start]);
+      rest = match rest[start..].find("*/") {
+         Some(end) => &rest[start + end + 2..],
+         None => "",
+      };
+   }
+   result.push_str(rest);
+   result
+}
+
+fn parse_selector(
+   selector: &str,
+) -> core::result::Result<(&str, Option<StyleInteraction>), StyleSheetAssetError> {
+   let mut parts = selector.split(':');
+   let label = parts.next().unwrap_or_default().trim_start_matches('.');
+   if label.is_empty() || !label.chars().all(|c| c.is_alphanumeric() || c == '_') {
+      return Err(StyleSheetAssetError::Parse(format!(
+         "invalid selector `{selector}`"
+      )));
+   }
+   let mut interaction: Option<StyleInteraction> = None;
+   for pseudo_class in parts {
+      let pseudo_class = match pseudo_class {
+         "hover" => StyleInteraction::Hover,
+         "active" => StyleInteraction::Active,
+         "focus" => StyleInteraction::Focus,
+         "focus-visible" => StyleInteraction::FocusVisible,
+         "disabled" => StyleInteraction::Disabled,
+         "checked" => StyleInteraction::Checked,
+         "selected" => StyleInteraction::Selected,
+         "drag-over" => StyleInteraction::DragOver,
+         _ => {
+            return Err(StyleSheetAssetError::Parse(format!(
+               "unsupported pseudo class `{pseudo_class}` in `{selector}`"
+            )))
+         }
+      };
+      interaction = Some(interaction.unwrap_or(StyleInteraction::empty()) | pseudo_class);
+   }
+   Ok((label, interaction))
+}
+
+fn parse_declarations(
+   body: &str,
+) -> core::result::Result<Vec<StyleItemValue>, StyleSheetAssetError> {
+   let mut items = vec![];
+   for declaration in body.split(';').map(str::trim).filter(|n| !n.is_empty()) {
+      let (name, value) = declaration.split_once(':').ok_or_else(|| {
+         StyleSheetAssetError::Parse(format!("invalid declaration `{declaration}`"))
+      })?;
+      let name = name.trim().replace('-', "_");
+      let value = value.trim();
+      let invalid_value =
+         || StyleSheetAssetError::Parse(format!("invalid value `{value}` for `{name}`"));
+
+      if let Some((_, sides)) = SIDE_SHORTHANDS.iter().find(|n| n.0 == name) {
+         let values = value
+            .split_whitespace()
+            .map(parse_css_val)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(invalid_value)?;
+         let values = match values[..] {
+            [a] => [a, a, a, a],
+            [a, b] => [a, b, a, b],
+            [a, b, c] => [a, b, c, b],
+            [a, b, c, d] => [a, b, c, d],
+            _ => return Err(invalid_value()),
+         };
+         for (side, value) in sides.iter().zip(values) {
+            push_item(
+               &mut items,
+               StyleItemValue {
+                  attr_id: get_attr_by_name(side).unwrap().index(),
+                  value: rxy_core::smallbox!(value),
+               },
+            );
+         }
+         continue;
+      }
+
+      let attr = get_attr_by_name(&name)
+         .ok_or_else(|| StyleSheetAssetError::Parse(format!("unknown attribute `{name}`")))?;
+      push_item(
+         &mut items,
+         StyleItemValue {
+            attr_id: attr.index(),
+            value: parse_attr_value(attr, value).ok_or_else(invalid_value)?,
+         },
+      );
+   }
+   Ok(items)
+}
+
+fn push_item(items: &mut Vec<StyleItemValue>, item: StyleItemValue) {
+   items.retain(|n| n.attr_id != item.attr_id);
+   items.push(item);
+}
+
+/// The style sheets of all loaded [`StyleSheetAsset`]s, by label name.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct AssetStyleSheets(HashMap<String, Vec<StyleSheetDefinition>>);
+
+/// The style sheets a typed style is spawned with, the asset style sheets are applied over them.
+#[derive(Clone, Default)]
+pub(crate) struct DefaultStyleSheets(pub Vec<Option<StyleSheetDefinition>>);
+
+/// Replaces the style sheets of the same interaction and appends the others,
+/// returns the indexes of the replaced style sheets.
+pub(crate) fn override_style_sheets(
+   style_sheets_state: &mut NodeStyleSheetsState,
+   style_sheets: Vec<StyleSheetDefinition>,
+) -> Vec<StyleSheetIndex> {
+   let mut replaced = vec![];
+   for style_sheet in style_sheets {
+      let index = style_sheets_state
+         .inline_style_sheet
+         .iter()
+         .enumerate()
+         .position(|(index, n)| {
+            n.as_ref()
+               .is_some_and(|n| n.interaction == style_sheet.interaction)
+               && !replaced.contains(&(index as StyleSheetIndex))
+         });
+      match index {
+         Some(index) => {
+            style_sheets_state.inline_style_sheet[index] = Some(style_sheet);
+            replaced.push(index as StyleSheetIndex);
+         }
+         None => {
+            style_sheets_state
+               .inline_style_sheet
+               .push(Some(style_sheet));
+         }
+      }
+   }
+   replaced
+}
+
+/// Rebuilds the style sheets of a shared style from its [`DefaultStyleSheets`] and the asset,
+/// and re-applies them to its subscribers.
+fn update_shared_style_sheets(
+   world: &mut World,
+   entity: Entity,
+   style_sheets: Vec<StyleSheetDefinition>,
+) -> Result {
+   let DefaultStyleSheets(default_style_sheets) = world
+      .get_node_state_ref::<DefaultStyleSheets>(&entity)
+      .cloned()
+      .unwrap_or_default();
+   let mut entity_world_mut = world.entity_mut(entity);
+   let subscribers = entity_world_mut
+      .get_shared_style_state()?
+      .subscribers
+      .iter()
+      .cloned()
+      .collect::<Vec<_>>();
+   let style_sheets_state = entity_world_mut.get_style_sheets_state()?;
+   let mut new_state = NodeStyleSheetsState {
+      inline_style_sheet: default_style_sheets,
+      shared_style_sheet_ids: style_sheets_state.shared_style_sheet_ids.clone(),
+   };
+   let replaced = override_style_sheets(&mut new_state, style_sheets);
+   let new = new_state.inline_style_sheet.clone();
+   let old = core::mem::replace(style_sheets_state, new_state).inline_style_sheet;
+
+   // the values of the asset replace the tokens of the replaced style sheets
+   if let Some(watchers) = world.get_node_state_mut::<StyleTokenWatchers>(&entity) {
+      for index in replaced {
+         watchers.remove_inline_style_sheets(index, 1);
+      }
+   }
+
+   for subscriber in subscribers {
+      if world.get_entity(subscriber).is_none() {
+         continue;
+      }
+      update_subscriber_style_sheets(world, entity, subscriber, &old, &new)?;
+   }
+   Ok(())
+}
+
+/// Replaces the old style sheets of the shared style applied to the subscriber with the new ones.
+///
+/// The slots of the subscriber refer to the style sheets by index, so they keep their place.
+/// When the shared style has more style sheets than before, the style members applying only the
+/// shared style are moved to new slots for all of them.
+fn update_subscriber_style_sheets(
+   world: &mut World,
+   entity: Entity,
+   subscriber: Entity,
+   old: &[Option<StyleSheetDefinition>],
+   new: &[Option<StyleSheetDefinition>],
+) -> Result {
+   let mut entity_world_mut = world.entity_mut(subscriber);
+   let attr_is_set = entity_world_mut
+      .get::<ElementEntityExtraData>()
+      .map(|n| n.attr_is_set.clone())
+      .unwrap_or_default();
+   let mut shared_style_sheet_ids = entity_world_mut
+      .get_style_sheets_state()?
+      .shared_style_sheet_ids
+      .clone();
+
+   let mut reset_keys = vec![];
+   let mut attr_ids = vec![];
+   let mut iter_inter_style_sheet = false;
+   // the slots of the shared style, with the index of their style sheet in it
+   let mut slots = vec![];
+   for (slot, style_sheet_id) in shared_style_sheet_ids.iter_mut().enumerate() {
+      let Some(index) = style_sheet_id
+         .as_ref()
+         .filter(|n| n.node_id == entity)
+         .map(|n| n.node_style_sheet_id.index)
+      else {
+         continue;
+      };
+      *style_sheet_id = None;
+      let slot = slot as StyleSheetIndex;
+      slots.push((slot, index));
+      let Some(Some(old_style_sheet)) = old.get(index as usize) else {
+         continue;
+      };
+      let style_sheet_id = NodeStyleSheetId {
+         index: slot,
+         location: StyleSheetLocation::Shared,
+      };
+      let require_reset_f = |attr_id| {
+         if !ElementEntityExtraData::static_is_set_attr(&attr_is_set, attr_id) {
+            reset_keys.push(attr_id);
+         }
+      };
+      if old_style_sheet.interaction.is_some() {
+         iter_inter_style_sheet = true;
+         entity_world_mut.scoped_inter_style_state_or_default(|_, attr_style_owner| {
+            attr_style_owner.remove_attr_style_of_definition(
+               old_style_sheet,
+               style_sheet_id,
+               require_reset_f,
+            )
+         })??;
+      } else {
+         entity_world_mut.scoped_style_state(|_, attr_style_owner| {
+            attr_style_owner.remove_attr_style_of_definition(
+               old_style_sheet,
+               style_sheet_id,
+               require_reset_f,
+            )
+         })??;
+      }
+      attr_ids.extend(old_style_sheet.items.iter().map(|n| n.attr_id));
+   }
+
+   if new.len() > old.len() {
+      if let Some(member_states) =
+         world.get_node_state_mut::<MemberHashMapState<ApplyStyleSheetsMemberState>>(&subscriber)
+      {
+         for member_state in member_states.0.get().values_mut() {
+            let range = member_state.shared_sheet_index
+               ..member_state.shared_sheet_index + member_state.shared_sheet_count;
+            if member_state.inline_sheet_count != 0
+               || member_state.shared_sheet_count as usize != old.len()
+               || !range.clone().all(|slot| slots.iter().any(|n| n.0 == slot))
+            {
+               continue;
+            }
+            slots.retain(|n| !range.contains(&n.0));
+            if range.end as usize == shared_style_sheet_ids.len() {
+               shared_style_sheet_ids.truncate(range.start as usize);
+            }
+            let start = shared_style_sheet_ids.len() as StyleSheetIndex;
+            member_state.shared_sheet_index = start;
+            member_state.shared_sheet_count = new.len() as StyleSheetIndex;
+            slots.extend((0..new.len() as StyleSheetIndex).map(|index| (start + index, index)));
+            shared_style_sheet_ids.resize(start as usize + new.len(), None);
+         }
+      }
+   }
+
+   let mut entity_world_mut = world.entity_mut(subscriber);
+   for (slot, index) in slots {
+      let Some(Some(new_style_sheet)) = new.get(index as usize) else {
+         continue;
+      };
+      if new_style_sheet.interaction.is_some() {
+         iter_inter_style_sheet = true;
+         entity_world_mut.scoped_inter_style_state_or_default(
+            |entity_world_mut, attr_style_owner| {
+               new_style_sheet.add_to(
+                  attr_style_owner,
+                  StyleSheetLocation::Shared,
+                  slot,
+                  entity_world_mut.world(),
+                  subscriber,
+               )
+            },
+         )??;
+      } else {
+         entity_world_mut.scoped_style_state(|entity_world_mut, attr_style_owner| {
+            new_style_sheet.add_to(
+               attr_style_owner,
+               StyleSheetLocation::Shared,
+               slot,
+               entity_world_mut.world(),
+               subscriber,
+            )
+         })??;
+      }
+      attr_ids.extend(new_style_sheet.items.iter().map(|n| n.attr_id));
+      shared_style_sheet_ids[slot as usize] = Some(StyleSheetId {
+         node_style_sheet_id: NodeStyleSheetId {
+            index,
+            location: StyleSheetLocation::Inline,
+         },
+         node_id: entity,
+      });
+   }
+   entity_world_mut
+      .get_style_sheets_state()?
+      .shared_style_sheet_ids = shared_style_sheet_ids;
+
+   entity_world_mut.world_scope(|world: &mut World| {
+      for attr_index in reset_keys.iter().cloned() {
+         get_attr_by_index(attr_index).set_value(world, subscriber, None);
+      }
+   });
+   EntityStyleAttrInfoIterArgs {
+      iter_normal_style_sheet: true,
+      iter_inter_style_sheet,
+      limit_attr_ids: Some(attr_ids.as_slice()),
+   }
+   .iter_and_sync_set(entity_world_mut)?;
+   Ok(())
+}
+
+/// Applies the loaded and modified [`StyleSheetAsset`]s to their shared styles.
+///
+/// The labels no longer in a modified or removed asset get the style sheets they were spawned with back.
+pub(crate) fn update_style_sheet_assets(
+   world: &mut World,
+   mut reader: Local<ManualEventReader<AssetEvent<StyleSheetAsset>>>,
+   mut asset_labels: Local<HashMap<AssetId<StyleSheetAsset>, Vec<String>>>,
+) {
+   let mut ids = vec![];
+   for event in reader.read(world.resource::<Events<AssetEvent<StyleSheetAsset>>>()) {
+      if let AssetEvent::LoadedWithDependencies { id }
+      | AssetEvent::Modified { id }
+      | AssetEvent::Removed { id } = event
+      {
+         if !ids.contains(id) {
+            ids.push(*id);
+         }
+      }
+   }
+
+   for id in ids {
+      let styles = world
+         .resource::<Assets<StyleSheetAsset>>()
+         .get(id)
+         .map(|n| n.styles.clone())
+         .unwrap_or_default();
+      let labels = styles.iter().map(|n| n.label.clone()).collect::<Vec<_>>();
+      let removed_labels = asset_labels
+         .insert(id, labels)
+         .unwrap_or_default()
+         .into_iter()
+         .filter(|label| !styles.iter().any(|n| n.label == *label))
+         .map(|label| AssetStyle {
+            label,
+            style_sheets: vec![],
+         });
+      for style in styles.clone().into_iter().chain(removed_labels) {
+         if style.style_sheets.is_empty() {
+            world
+               .resource_mut::<AssetStyleSheets>()
+               .remove(&style.label);
+         } else {
+            world
+               .resource_mut::<AssetStyleSheets>()
+               .insert(style.label.clone(), style.style_sheets.clone());
+         }
+         let Some(type_id) = world
+            .resource::<TypedStyleNames>()
+            .get(style.label.as_str())
+            .copied()
+         else {
+            continue;
+         };
+         let Some(entity) = world.get_typed_entity(type_id) else {
+            continue;
+         };
+         if let Err(err) = update_shared_style_sheets(world, entity, style.style_sheets) {
+            warn!(
+               "failed to update the shared style `{}`: {:?}",
+               style.label, err
+            );
+         }
+      }
+      if world
+         .resource::<Assets<StyleSheetAsset>>()
+         .get(id)
+         .is_none()
+      {
+         asset_labels.remove(&id);
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use core::any::TypeId;
+
+   use bevy_ecs::system::{IntoSystem, System};
+   use bevy_render::color::Color;
+   use bevy_ui::BackgroundColor;
+
+   use rxy_core::style::{
+      x, AppliedStyleSheet, StyleSheetCtx, StyleSheets, StyleSheetsInfo, StyledNodeTree,
+   };
+   use rxy_core::HasIndex;
+
+   use crate::all_attrs::{bg_color, padding_left, padding_top, width};
+   use crate::elements::element_div;
+   use crate::prelude::*;
+   use crate::renderer::style::plugin::{RxySharedStyleContainer, TypedEntities};
+   use crate::renderer::style::TypedStyleWorldExt;
+   use crate::BevyRenderer;
+
+   use super::*;
+
+   #[derive(Copy, Clone)]
+   struct CardStyle;
+
+   impl TypedStyleLabel for CardStyle {}
+
+   impl StyleSheets<BevyRenderer> for CardStyle {
+      fn style_sheets(
+         self,
+         ctx: StyleSheetCtx<BevyRenderer>,
+      ) -> (
+         impl Iterator<Item = AppliedStyleSheet<BevyRenderer>> + Send + 'static,
+         StyleSheetsInfo,
+      ) {
+         typed_shared_style_sheets(TypeId::of::<Self>(), ctx)
+      }
+   }
+
+   #[test]
+   fn parse_style_sheet() {
+      let asset = StyleSheetAsset::parse(
+         "
+         /* comment */
+         CheckboxStyle {
+            width: 20px;
+            padding: 2px 4px;
+         }
+         CheckboxStyle:hover, .ButtonStyle:hover {
+            bg-color: #ff0000;
+         }
+         ",
+      )
+      .unwrap();
+      assert_eq!(asset.styles.len(), 2);
+
+      let style_sheets = &asset.styles[0].style_sheets;
+      assert_eq!(asset.styles[0].label, "CheckboxStyle");
+      assert_eq!(style_sheets.len(), 2);
+      assert_eq!(style_sheets[0].interaction, None);
+      let attr_ids = style_sheets[0]
+         .items
+         .iter()
+         .map(|n| n.attr_id)
+         .collect::<Vec<_>>();
+      assert_eq!(attr_ids.len(), 5);
+      assert_eq!(attr_ids[0], width::INDEX);
+      assert!(attr_ids.contains(&padding_top::INDEX) && attr_ids.contains(&padding_left::INDEX));
+      assert_eq!(style_sheets[1].interaction, Some(StyleInteraction::Hover));
+      assert_eq!(style_sheets[1].items[0].attr_id, bg_color::INDEX);
+
+      assert_eq!(asset.styles[1].label, "ButtonStyle");
+
+      assert!(StyleSheetAsset::parse("A { unknown: 1px; }").is_err());
+      assert!(StyleSheetAsset::parse("A { width: red; }").is_err());
+      assert!(StyleSheetAsset::parse("A:unknown { width: 1px; }").is_err());
+      assert!(StyleSheetAsset::parse("A { width: 1px;").is_err());
+   }
+
+   #[test]
+   fn override_default_style_sheets() {
+      let style_sheet = |interaction| StyleSheetDefinition {
+         interaction,
+         items: vec![],
+      };
+      let mut state = NodeStyleSheetsState {
+         inline_style_sheet: vec![
+            Some(style_sheet(None)),
+            Some(style_sheet(Some(StyleInteraction::Hover))),
+         ],
+         ..Default::default()
+      };
+      let replaced = override_style_sheets(
+         &mut state,
+         vec![
+            style_sheet(Some(StyleInteraction::Active)),
+            style_sheet(Some(StyleInteraction::Hover)),
+         ],
+      );
+      assert_eq!(replaced, vec![1]);
+      let interactions = state
+         .inline_style_sheet
+         .iter()
+         .map(|n| n.as_ref().unwrap().interaction)
+         .collect::<Vec<_>>();
+      assert_eq!(
+         interactions,
+         vec![
+            None,
+            Some(StyleInteraction::Hover),
+            Some(StyleInteraction::Active)
+         ]
+      );
+   }
+
+   #[test]
+   fn reload_without_the_rule_restores_the_default_style() {
+      let mut world = World::new();
+      world.init_resource::<FocusedEntity>();
+      world.init_resource::<RxySharedStyleContainer>();
+      world.init_resource::<TypedEntities>();
+      world.init_resource::<TypedStyleNames>();
+      world.init_resource::<AssetStyleSheets>();
+      world.init_resource::<Assets<StyleSheetAsset>>();
+      world.init_resource::<Events<AssetEvent<StyleSheetAsset>>>();
+      let mut update_assets = IntoSystem::into_system(update_style_sheet_assets);
+      update_assets.initialize(&mut world);
+
+      world.default_typed_style(CardStyle, || x().bg_color(Color::RED));
+      let node = world.spawn_node::<element_div>(None, None);
+      world.build_style_sheets(node, CardStyle, None).unwrap();
+      let bg = |world: &World| world.get::<BackgroundColor>(node).unwrap().0;
+      assert_eq!(bg(&world), Color::RED);
+
+      let asset = StyleSheetAsset::parse("CardStyle { bg-color: #0000ff; }").unwrap();
+      let id = world
+         .resource_mut::<Assets<StyleSheetAsset>>()
+         .add(asset)
+         .id();
+      world.send_event(AssetEvent::LoadedWithDependencies { id });
+      update_assets.run((), &mut world);
+      assert_eq!(bg(&world), Color::BLUE);
+
+      let asset = StyleSheetAsset::parse("OtherStyle { width: 1px; }").unwrap();
+      *world
+         .resource_mut::<Assets<StyleSheetAsset>>()
+         .get_mut(id)
+         .unwrap() = asset;
+      world.send_event(AssetEvent::Modified { id });
+      update_assets.run((), &mut world);
+      assert_eq!(bg(&world), Color::RED);
+      assert!(!world
+         .resource::<AssetStyleSheets>()
+         .contains_key("CardStyle"));
+   }
+}
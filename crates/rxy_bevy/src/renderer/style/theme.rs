@@ -0,0 +1,393 @@
+use bevy_ecs::prelude::{Entity, Resource, With, World};
+use bevy_render::color::Color;
+use bevy_ui::Val;
+use bevy_utils::tracing::error;
+
+use rxy_core::style::{BoxedStyleTokenResolver, StyleSheetIndex, StyleTokenTarget};
+use rxy_core::NodeTree;
+
+use crate::attrs::get_attr_by_index;
+use crate::renderer::style::{EntityStyleAttrInfoIterArgs, StyleEntityMutExt};
+use crate::{tailwind_color, BevyRenderer, RendererState};
+
+#[derive(Clone, Debug)]
+pub struct ThemeColors {
+   pub primary: Color,
+   pub secondary: Color,
+   pub background: Color,
+   pub surface: Color,
+   pub text: Color,
+   pub text_muted: Color,
+   pub border: Color,
+   pub success: Color,
+   pub warning: Color,
+   pub error: Color,
+}
+
+#[derive(Clone, Debug)]
+pub struct ThemeSpacing {
+   pub xs: Val,
+   pub sm: Val,
+   pub md: Val,
+   pub lg: Val,
+   pub xl: Val,
+}
+
+#[derive(Clone, Debug)]
+pub struct ThemeRadii {
+   pub sm: Val,
+   pub md: Val,
+   pub lg: Val,
+   pub full: Val,
+}
+
+#[derive(Clone, Debug)]
+pub struct ThemeFontSizes {
+   pub xs: f32,
+   pub sm: f32,
+   pub md: f32,
+   pub lg: f32,
+   pub xl: f32,
+}
+
+/// A complete set of design tokens of one theme variant.
+#[derive(Clone, Debug)]
+pub struct ThemeTokens {
+   pub colors: ThemeColors,
+   pub spacing: ThemeSpacing,
+   pub radii: ThemeRadii,
+   pub font_sizes: ThemeFontSizes,
+}
+
+fn palette(name: &str, shade: u16) -> Color {
+   tailwind_color(name, shade).unwrap()
+}
+
+impl ThemeTokens {
+   pub fn light() -> Self {
+      Self {
+         colors: ThemeColors {
+            primary: palette("sky", 500),
+            secondary: palette("violet", 500),
+            background: Color::WHITE,
+            surface: palette("slate", 100),
+            text: palette("slate", 900),
+            text_muted: palette("slate", 500),
+            border: palette("slate", 300),
+            success: palette("green", 500),
+            warning: palette("amber", 500),
+            error: palette("red", 500),
+         },
+         ..Self::dark()
+      }
+   }
+
+   pub fn dark() -> Self {
+      Self {
+         colors: ThemeColors {
+            primary: palette("sky", 400),
+            secondary: palette("violet", 400),
+            background: palette("slate", 950),
+            surface: palette("slate", 800),
+            text: palette("slate", 50),
+            text_muted: palette("slate", 400),
+            border: palette("slate", 600),
+            success: palette("green", 400),
+            warning: palette("amber", 400),
+            error: palette("red", 400),
+         },
+         spacing: ThemeSpacing {
+            xs: Val::Px(4.),
+            sm: Val::Px(8.),
+            md: Val::Px(12.),
+            lg: Val::Px(16.),
+            xl: Val::Px(24.),
+         },
+         radii: ThemeRadii {
+            sm: Val::Px(2.),
+            md: Val::Px(4.),
+            lg: Val::Px(8.),
+            full: Val::Px(9999.),
+         },
+         font_sizes: ThemeFontSizes {
+            xs: 12.,
+            sm: 14.,
+            md: 16.,
+            lg: 18.,
+            xl: 20.,
+         },
+      }
+   }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ThemeMode {
+   #[default]
+   Light,
+   Dark,
+}
+
+/// The active theme. Changing it re-resolves every [`token`] used by attrs and style sheets.
+#[derive(Resource, Clone, Debug)]
+pub struct Theme {
+   pub mode: ThemeMode,
+   pub light: ThemeTokens,
+   pub dark: ThemeTokens,
+}
+
+impl Default for Theme {
+   fn default() -> Self {
+      Self {
+         mode: ThemeMode::Light,
+         light: ThemeTokens::light(),
+         dark: ThemeTokens::dark(),
+      }
+   }
+}
+
+impl Theme {
+   pub fn tokens(&self) -> &ThemeTokens {
+      match self.mode {
+         ThemeMode::Light => &self.light,
+         ThemeMode::Dark => &self.dark,
+      }
+   }
+
+   pub fn set_mode(&mut self, mode: ThemeMode) {
+      self.mode = mode;
+   }
+
+   pub fn toggle(&mut self) {
+      self.mode = match self.mode {
+         ThemeMode::Light => ThemeMode::Dark,
+         ThemeMode::Dark => ThemeMode::Light,
+      };
+   }
+}
+
+fn with_theme_tokens<T>(world: &World, f: impl FnOnce(&ThemeTokens) -> T) -> T {
+   match world.get_resource::<Theme>() {
+      None => f(&ThemeTokens::light()),
+      Some(theme) => f(theme.tokens()),
+   }
+}
+
+/// Style tokens of the active [`Theme`].
+///
+/// ```ignore
+/// div().bg_color(token::primary).padding(token::spacing_md)
+/// ```
+pub mod token {
+   use bevy_render::color::Color;
+   use bevy_ui::Val;
+
+   use rxy_core::style::StyleToken;
+
+   use super::with_theme_tokens;
+   use crate::BevyRenderer;
+
+   macro_rules! define_theme_tokens {
+      ($ty:ty; $($name:ident => $group:ident.$field:ident),* $(,)?) => {
+         $(
+            #[allow(non_upper_case_globals)]
+            pub const $name: StyleToken<BevyRenderer, $ty> =
+               StyleToken::new(|world| with_theme_tokens(world, |n| n.$group.$field.clone()));
+         )*
+      };
+   }
+
+   define_theme_tokens!(Color;
+      primary => colors.primary,
+      secondary => colors.secondary,
+      background => colors.background,
+      surface => colors.surface,
+      text => colors.text,
+      text_muted => colors.text_muted,
+      border => colors.border,
+      success => colors.success,
+      warning => colors.warning,
+      error => colors.error,
+   );
+
+   define_theme_tokens!(Val;
+      spacing_xs => spacing.xs,
+      spacing_sm => spacing.sm,
+      spacing_md => spacing.md,
+      spacing_lg => spacing.lg,
+      spacing_xl => spacing.xl,
+      radius_sm => radii.sm,
+      radius_md => radii.md,
+      radius_lg => radii.lg,
+      radius_full => radii.full,
+   );
+
+   define_theme_tokens!(f32;
+      font_size_xs => font_sizes.xs,
+      font_size_sm => font_sizes.sm,
+      font_size_md => font_sizes.md,
+      font_size_lg => font_sizes.lg,
+      font_size_xl => font_sizes.xl,
+   );
+}
+
+/// The style tokens used by a node, resolved again when the theme changes.
+#[derive(Default)]
+pub(crate) struct StyleTokenWatchers(
+   Vec<(StyleTokenTarget, BoxedStyleTokenResolver<BevyRenderer>)>,
+);
+
+impl StyleTokenWatchers {
+   pub fn insert(
+      &mut self,
+      target: StyleTokenTarget,
+      resolver: BoxedStyleTokenResolver<BevyRenderer>,
+   ) {
+      match self.0.iter_mut().find(|n| n.0 == target) {
+         None => self.0.push((target, resolver)),
+         Some(watcher) => watcher.1 = resolver,
+      }
+   }
+
+   pub fn remove(&mut self, target: StyleTokenTarget) {
+      self.0.retain(|n| n.0 != target);
+   }
+
+   pub fn remove_inline_style_sheets(&mut self, start: StyleSheetIndex, count: StyleSheetIndex) {
+      self.0.retain(|n| match n.0 {
+         StyleTokenTarget::InlineStyleSheet { index, .. } => {
+            index < start || index >= start + count
+         }
+         StyleTokenTarget::Attr(_) => true,
+      });
+   }
+}
+
+pub(crate) fn update_style_tokens(world: &mut World) {
+   let entities = world
+      .query_filtered::<Entity, With<RendererState<StyleTokenWatchers>>>()
+      .iter(world)
+      .collect::<Vec<_>>();
+
+   for entity in entities {
+      let Some(watchers) = world.get_node_state_ref::<StyleTokenWatchers>(&entity) else {
+         continue;
+      };
+      let values = watchers
+         .0
+         .iter()
+         .map(|(target, resolver)| (*target, resolver.resolve(world)))
+         .collect::<Vec<_>>();
+
+      let mut attr_ids = vec![];
+      let mut iter_inter_style_sheet = false;
+      for (target, value) in values {
+         match target {
+            StyleTokenTarget::Attr(attr_id) => {
+               get_attr_by_index(attr_id).set_value(world, entity, Some(value));
+            }
+            StyleTokenTarget::InlineStyleSheet { index, attr_id } => {
+               let mut entity_world_mut = world.entity_mut(entity);
+               let Ok(style_sheet) = entity_world_mut
+                  .get_style_sheets_state()
+                  .and_then(|n| n.get_inline_style_sheet_mut(index))
+               else {
+                  continue;
+               };
+               let Some(item) = style_sheet.items.iter_mut().find(|n| n.attr_id == attr_id) else {
+                  continue;
+               };
+               item.value = value;
+               iter_inter_style_sheet |= style_sheet.interaction.is_some();
+               attr_ids.push(attr_id);
+            }
+         }
+      }
+      if attr_ids.is_empty() {
+         continue;
+      }
+
+      // the style sheets of a shared style are applied to its subscribers
+      let nodes = match world.entity_mut(entity).get_shared_style_state() {
+         Ok(shared_style_state) => shared_style_state.subscribers.iter().cloned().collect(),
+         Err(_) => vec![entity],
+      };
+      for node_id in nodes {
+         if world.get_entity(node_id).is_none() {
+            continue;
+         }
+         let iter_args = EntityStyleAttrInfoIterArgs {
+            iter_normal_style_sheet: true,
+            iter_inter_style_sheet,
+            limit_attr_ids: Some(attr_ids.as_slice()),
+         };
+         if let Err(err) = iter_args.iter_and_sync_set(world.entity_mut(node_id)) {
+            error!("sync style tokens error: {:?}", err);
+         }
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use bevy_ui::BackgroundColor;
+
+   use rxy_core::style::{AttrStyleTokenResolver, StyledNodeTree};
+   use rxy_core::{ElementAttrType, HasIndex};
+
+   use crate::all_attrs::bg_color;
+   use crate::elements::element_div;
+
+   use super::*;
+
+   fn watch_primary(world: &mut World) -> Entity {
+      let entity = world.spawn_node::<element_div>(None, None);
+      let resolver = AttrStyleTokenResolver::<BevyRenderer, bg_color, Color>::new(token::primary);
+      bg_color::set_value(world, entity, Some(resolver.value(world)));
+      world.watch_style_token(
+         entity,
+         StyleTokenTarget::Attr(bg_color::INDEX),
+         resolver.boxed(),
+      );
+      entity
+   }
+
+   fn bg(world: &World, entity: Entity) -> Color {
+      world.get::<BackgroundColor>(entity).unwrap().0
+   }
+
+   #[test]
+   fn tokens_resolve_from_the_active_mode() {
+      let mut world = World::new();
+      assert_eq!(
+         token::primary.get(&world),
+         ThemeTokens::light().colors.primary
+      );
+
+      world.insert_resource(Theme::default());
+      assert_eq!(token::spacing_md.get(&world), Val::Px(12.));
+      world.resource_mut::<Theme>().toggle();
+      assert_eq!(world.resource::<Theme>().mode, ThemeMode::Dark);
+      assert_eq!(
+         token::primary.get(&world),
+         ThemeTokens::dark().colors.primary
+      );
+      assert_eq!(token::text.get(&world), palette("slate", 50));
+   }
+
+   #[test]
+   fn switching_the_theme_updates_the_watched_attrs() {
+      let mut world = World::new();
+      world.insert_resource(Theme::default());
+      let entity = watch_primary(&mut world);
+      assert_eq!(bg(&world, entity), ThemeTokens::light().colors.primary);
+
+      world.resource_mut::<Theme>().set_mode(ThemeMode::Dark);
+      update_style_tokens(&mut world);
+      assert_eq!(bg(&world, entity), ThemeTokens::dark().colors.primary);
+
+      world.unwatch_style_token(entity, StyleTokenTarget::Attr(bg_color::INDEX));
+      world.resource_mut::<Theme>().set_mode(ThemeMode::Light);
+      update_style_tokens(&mut world);
+      assert_eq!(bg(&world, entity), ThemeTokens::dark().colors.primary);
+   }
+}
@@ -5,12 +5,13 @@ use bevy_ui::{AlignItems, Display, FlexDirection, FlexWrap, JustifyContent, Posi
 use rxy_core::{ElementAttr, ElementAttrMember, ElementView, MapToAttrMarker, MemberOwner, XNest};
 
 use crate::all_attrs::{
-   align_items, column_gap, display, flex_direction, flex_grow, flex_shrink, flex_wrap, height,
-   justify_content, margin_bottom, margin_left, margin_right, margin_top, max_height, max_width,
-   min_height, min_width, overflow_x, overflow_y, padding_bottom, padding_left, padding_right,
-   padding_top, position_type, row_gap, text_align, text_linebreak, visibility, width, z_index,
+   align_items, border_radius, box_shadow, column_gap, display, flex_direction, flex_grow,
+   flex_shrink, flex_wrap, height, justify_content, margin_bottom, margin_left, margin_right,
+   margin_top, max_height, max_width, min_height, min_width, overflow_x, overflow_y,
+   padding_bottom, padding_left, padding_right, padding_top, position_type, row_gap, text_align,
+   text_linebreak, visibility, width, z_index,
 };
-use crate::BevyRenderer;
+use crate::{BevyRenderer, BoxShadow};
 
 macro_rules! impl_tailwind_attrs {
    ($name:ident;$ty:ident) => {
@@ -294,6 +295,71 @@ macro_rules! impl_tailwind_attrs {
             self.member(ElementAttr::new(JustifyText::Right.into()))
          }
 
+         #[inline]
+         fn rounded_none(self) -> Self::AddMember<ElementAttr<BevyRenderer, border_radius>> {
+            self.member(ElementAttr::new(Val::Px(0.).into()))
+         }
+         #[inline]
+         fn rounded_sm(self) -> Self::AddMember<ElementAttr<BevyRenderer, border_radius>> {
+            self.member(ElementAttr::new(Val::Px(2.).into()))
+         }
+         #[inline]
+         fn rounded(self) -> Self::AddMember<ElementAttr<BevyRenderer, border_radius>> {
+            self.member(ElementAttr::new(Val::Px(4.).into()))
+         }
+         #[inline]
+         fn rounded_md(self) -> Self::AddMember<ElementAttr<BevyRenderer, border_radius>> {
+            self.member(ElementAttr::new(Val::Px(6.).into()))
+         }
+         #[inline]
+         fn rounded_lg(self) -> Self::AddMember<ElementAttr<BevyRenderer, border_radius>> {
+            self.member(ElementAttr::new(Val::Px(8.).into()))
+         }
+         #[inline]
+         fn rounded_xl(self) -> Self::AddMember<ElementAttr<BevyRenderer, border_radius>> {
+            self.member(ElementAttr::new(Val::Px(12.).into()))
+         }
+         #[inline]
+         fn rounded_2xl(self) -> Self::AddMember<ElementAttr<BevyRenderer, border_radius>> {
+            self.member(ElementAttr::new(Val::Px(16.).into()))
+         }
+         #[inline]
+         fn rounded_3xl(self) -> Self::AddMember<ElementAttr<BevyRenderer, border_radius>> {
+            self.member(ElementAttr::new(Val::Px(24.).into()))
+         }
+         #[inline]
+         fn rounded_full(self) -> Self::AddMember<ElementAttr<BevyRenderer, border_radius>> {
+            self.member(ElementAttr::new(Val::Px(9999.).into()))
+         }
+         #[inline]
+         fn shadow_none(self) -> Self::AddMember<ElementAttr<BevyRenderer, box_shadow>> {
+            self.member(ElementAttr::new(BoxShadow::NONE.into()))
+         }
+         #[inline]
+         fn shadow_sm(self) -> Self::AddMember<ElementAttr<BevyRenderer, box_shadow>> {
+            self.member(ElementAttr::new(BoxShadow::SM.into()))
+         }
+         #[inline]
+         fn shadow(self) -> Self::AddMember<ElementAttr<BevyRenderer, box_shadow>> {
+            self.member(ElementAttr::new(BoxShadow::BASE.into()))
+         }
+         #[inline]
+         fn shadow_md(self) -> Self::AddMember<ElementAttr<BevyRenderer, box_shadow>> {
+            self.member(ElementAttr::new(BoxShadow::MD.into()))
+         }
+         #[inline]
+         fn shadow_lg(self) -> Self::AddMember<ElementAttr<BevyRenderer, box_shadow>> {
+            self.member(ElementAttr::new(BoxShadow::LG.into()))
+         }
+         #[inline]
+         fn shadow_xl(self) -> Self::AddMember<ElementAttr<BevyRenderer, box_shadow>> {
+            self.member(ElementAttr::new(BoxShadow::XL.into()))
+         }
+         #[inline]
+         fn shadow_2xl(self) -> Self::AddMember<ElementAttr<BevyRenderer, box_shadow>> {
+            self.member(ElementAttr::new(BoxShadow::XL2.into()))
+         }
+
          #[inline]
          fn size<T>(
             self,
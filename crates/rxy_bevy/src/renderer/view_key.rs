@@ -3,10 +3,13 @@ use bevy_hierarchy::DespawnRecursiveExt;
 
 use rxy_core::{NodeTree, RendererNodeId, RendererWorld, ViewKey};
 
-use crate::BevyRenderer;
+use crate::{BevyRenderer, LeaveAction, TransitionWorldExt};
 
 impl ViewKey<BevyRenderer> for Entity {
    fn remove(self, world: &mut RendererWorld<BevyRenderer>) {
+      if world.start_leave_transition(self, LeaveAction::Despawn) {
+         return;
+      }
       world.entity_mut(self).despawn_recursive();
    }
 
@@ -0,0 +1,368 @@
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{load_internal_asset, Asset, Assets, Handle};
+use bevy_core::Name;
+use bevy_ecs::prelude::{
+   Changed, Commands, Component, DetectChanges, DetectChangesMut, Entity, IntoSystemConfigs, Or,
+   Query, Ref, ResMut, With, Without,
+};
+use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy_mod_picking::prelude::Pickable;
+use bevy_reflect::{Reflect, TypePath};
+use bevy_render::color::Color;
+use bevy_render::render_resource::{AsBindGroup, Shader, ShaderRef};
+use bevy_ui::node_bundles::MaterialNodeBundle;
+use bevy_ui::{
+   BackgroundColor, BorderColor, FocusPolicy, Node, PositionType, Style, UiMaterial,
+   UiMaterialPlugin, UiRect, UiSystem, Val, ZIndex,
+};
+use bevy_window::{PrimaryWindow, Window};
+use glam::{Vec2, Vec4};
+
+use rxy_core::AttrValueLerp;
+
+use crate::renderer::impl_copy_attr_values;
+
+const SHAPE_SHADER_HANDLE: Handle<Shader> =
+   Handle::weak_from_u128(0x5a3c_9e1f_47d2_4b8a_a1e6_2f90_c3d7_18b4);
+
+/// Draws the rounded corners, box shadows and gradients of the nodes with a [`ShapeStyle`].
+pub struct RxyShapePlugin;
+
+impl Plugin for RxyShapePlugin {
+   fn build(&self, app: &mut App) {
+      load_internal_asset!(app, SHAPE_SHADER_HANDLE, "shape.wgsl", Shader::from_wgsl);
+      app.add_plugins(UiMaterialPlugin::<ShapeMaterial>::default())
+         .register_type::<ShapeStyle>()
+         .add_systems(PostUpdate, sync_shape_decorations.after(UiSystem::Layout));
+   }
+}
+
+/// A css-like `box-shadow`, drawn around the rounded box of the node.
+/// A shadow with a transparent color is not drawn.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+pub struct BoxShadow {
+   pub color: Color,
+   pub offset: Vec2,
+   pub blur: f32,
+   pub spread: f32,
+}
+
+impl Default for BoxShadow {
+   fn default() -> Self {
+      Self::NONE
+   }
+}
+
+impl BoxShadow {
+   pub const NONE: Self = Self::new(Color::NONE, Vec2::ZERO, 0., 0.);
+   pub const SM: Self = Self::new(Color::rgba(0., 0., 0., 0.05), Vec2::new(0., 1.), 2., 0.);
+   pub const BASE: Self = Self::new(Color::rgba(0., 0., 0., 0.1), Vec2::new(0., 1.), 3., 0.);
+   pub const MD: Self = Self::new(Color::rgba(0., 0., 0., 0.1), Vec2::new(0., 4.), 6., -1.);
+   pub const LG: Self = Self::new(Color::rgba(0., 0., 0., 0.1), Vec2::new(0., 10.), 15., -3.);
+   pub const XL: Self = Self::new(Color::rgba(0., 0., 0., 0.1), Vec2::new(0., 20.), 25., -5.);
+   pub const XL2: Self = Self::new(Color::rgba(0., 0., 0., 0.25), Vec2::new(0., 25.), 50., -12.);
+
+   pub const fn new(color: Color, offset: Vec2, blur: f32, spread: f32) -> Self {
+      Self {
+         color,
+         offset,
+         blur,
+         spread,
+      }
+   }
+
+   pub fn is_none(&self) -> bool {
+      self.color.a() <= 0.
+   }
+
+   /// The space the shadow takes outside of the node.
+   fn margin(&self) -> f32 {
+      if self.is_none() {
+         return 0.;
+      }
+      self.blur.max(0.) + self.spread.max(0.) + self.offset.abs().max_element()
+   }
+}
+
+/// A css-like two stops `linear-gradient`, replacing the background color of the node.
+/// The `angle` is in degrees, `0` goes to the top and `90` goes to the right.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+pub struct LinearGradient {
+   pub angle: f32,
+   pub start: Color,
+   pub end: Color,
+}
+
+impl Default for LinearGradient {
+   fn default() -> Self {
+      Self::new(180., Color::NONE, Color::NONE)
+   }
+}
+
+impl LinearGradient {
+   pub const fn new(angle: f32, start: Color, end: Color) -> Self {
+      Self { angle, start, end }
+   }
+
+   pub fn is_none(&self) -> bool {
+      self.start.a() <= 0. && self.end.a() <= 0.
+   }
+}
+
+impl_copy_attr_values!(BoxShadow, LinearGradient);
+
+impl AttrValueLerp for BoxShadow {
+   fn lerp(&self, to: &Self, t: f32) -> Self {
+      // fade from the color of the other shadow, instead of from black
+      let from_color = if self.is_none() {
+         to.color.with_a(0.)
+      } else {
+         self.color
+      };
+      let to_color = if to.is_none() {
+         self.color.with_a(0.)
+      } else {
+         to.color
+      };
+      Self {
+         color: AttrValueLerp::lerp(&from_color, &to_color, t),
+         offset: self.offset.lerp(to.offset, t),
+         blur: AttrValueLerp::lerp(&self.blur, &to.blur, t),
+         spread: AttrValueLerp::lerp(&self.spread, &to.spread, t),
+      }
+   }
+}
+
+impl AttrValueLerp for LinearGradient {
+   fn lerp(&self, to: &Self, t: f32) -> Self {
+      Self {
+         angle: AttrValueLerp::lerp(&self.angle, &to.angle, t),
+         start: AttrValueLerp::lerp(&self.start, &to.start, t),
+         end: AttrValueLerp::lerp(&self.end, &to.end, t),
+      }
+   }
+}
+
+/// The rounded corners, box shadow and gradient of a node, set by the `border_radius`, `box_shadow` and `bg_gradient` attrs.
+///
+/// They are drawn by a decoration child node with a [`ShapeMaterial`], which also takes over the [`BackgroundColor`]
+/// and [`BorderColor`] of the node. The decoration is drawn over the image and text of the node itself,
+/// so use them on container nodes.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Reflect)]
+pub struct ShapeStyle {
+   pub border_radius: Val,
+   pub box_shadow: BoxShadow,
+   pub gradient: LinearGradient,
+}
+
+impl ShapeStyle {
+   pub fn is_empty(&self) -> bool {
+      let has_radius = match self.border_radius {
+         Val::Auto => false,
+         Val::Px(n) | Val::Percent(n) | Val::Vw(n) | Val::Vh(n) | Val::VMin(n) | Val::VMax(n) => {
+            n > 0.
+         }
+      };
+      !has_radius && self.box_shadow.is_none() && self.gradient.is_none()
+   }
+}
+
+pub use shape_uniform::ShapeUniform;
+
+// the `ShaderType` derive emits layout checks for each field that are never called
+#[allow(dead_code)]
+mod shape_uniform {
+   use bevy_render::render_resource::ShaderType;
+   use glam::{Vec2, Vec4};
+
+   #[derive(ShaderType, Copy, Clone, Debug, Default, PartialEq)]
+   pub struct ShapeUniform {
+      pub size: Vec2,
+      pub margin: f32,
+      pub radius: f32,
+      /// left, top, right, bottom
+      pub border: Vec4,
+      pub background: Vec4,
+      pub border_color: Vec4,
+      pub gradient_start: Vec4,
+      pub gradient_end: Vec4,
+      pub gradient_angle: f32,
+      pub has_gradient: u32,
+      pub shadow_blur: f32,
+      pub shadow_spread: f32,
+      pub shadow_offset: Vec2,
+      pub shadow_color: Vec4,
+   }
+}
+
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug)]
+pub struct ShapeMaterial {
+   #[uniform(0)]
+   pub shape: ShapeUniform,
+}
+
+impl UiMaterial for ShapeMaterial {
+   fn fragment_shader() -> ShaderRef {
+      SHAPE_SHADER_HANDLE.into()
+   }
+}
+
+/// The decoration child of a node with a [`ShapeStyle`], and the colors taken from the node.
+#[derive(Component, Clone, Debug)]
+struct ShapeDecoration {
+   entity: Entity,
+   background: Color,
+   border_color: Color,
+}
+
+fn linear_color(color: Color) -> Vec4 {
+   Vec4::from_array(color.as_linear_rgba_f32())
+}
+
+fn sync_shape_decorations(
+   mut commands: Commands,
+   mut materials: ResMut<Assets<ShapeMaterial>>,
+   window_query: Query<&Window, With<PrimaryWindow>>,
+   mut shape_query: Query<
+      (
+         Entity,
+         Ref<ShapeStyle>,
+         Ref<Node>,
+         Ref<Style>,
+         Option<&mut BackgroundColor>,
+         Option<&mut BorderColor>,
+         Option<&mut ShapeDecoration>,
+      ),
+      Or<(
+         Changed<ShapeStyle>,
+         Changed<Node>,
+         Changed<Style>,
+         Changed<BackgroundColor>,
+         Changed<BorderColor>,
+      )>,
+   >,
+   mut decoration_query: Query<(&mut Style, &Handle<ShapeMaterial>), Without<ShapeStyle>>,
+) {
+   let viewport_size = window_query
+      .get_single()
+      .map(|window| Vec2::new(window.resolution.width(), window.resolution.height()))
+      .unwrap_or(Vec2::ZERO);
+
+   for (entity, shape_style, node, style, mut bg_color, mut border_color, decoration) in
+      shape_query.iter_mut()
+   {
+      if shape_style.is_empty() {
+         let Some(decoration) = decoration else {
+            continue;
+         };
+         // give back the colors, unless the attrs just set new ones
+         if let Some(bg_color) = bg_color.as_mut().filter(|n| !n.is_changed()) {
+            bg_color.0 = decoration.background;
+         }
+         if let Some(border_color) = border_color.as_mut().filter(|n| !n.is_changed()) {
+            border_color.0 = decoration.border_color;
+         }
+         commands.entity(decoration.entity).despawn_recursive();
+         commands.entity(entity).remove::<ShapeDecoration>();
+         continue;
+      }
+
+      // the decoration takes the colors when it is spawned, or when the attrs set new colors
+      let background = match bg_color.as_mut() {
+         Some(bg_color) if bg_color.is_changed() || decoration.is_none() => {
+            core::mem::replace(&mut bg_color.bypass_change_detection().0, Color::NONE)
+         }
+         _ => decoration
+            .as_ref()
+            .map_or(Color::NONE, |decoration| decoration.background),
+      };
+      let border = match border_color.as_mut() {
+         Some(border_color) if border_color.is_changed() || decoration.is_none() => {
+            core::mem::replace(&mut border_color.bypass_change_detection().0, Color::NONE)
+         }
+         _ => decoration
+            .as_ref()
+            .map_or(Color::NONE, |decoration| decoration.border_color),
+      };
+
+      let size = node.size();
+      let resolve = |val: Val, parent_size: f32| {
+         val.resolve(parent_size, viewport_size)
+            .unwrap_or(0.)
+            .max(0.)
+      };
+      let border_widths = Vec4::new(
+         resolve(style.border.left, size.x),
+         resolve(style.border.top, size.x),
+         resolve(style.border.right, size.x),
+         resolve(style.border.bottom, size.x),
+      );
+      let box_shadow = shape_style.box_shadow;
+      let gradient = shape_style.gradient;
+      let margin = box_shadow.margin();
+      let shape = ShapeUniform {
+         size,
+         margin,
+         radius: resolve(shape_style.border_radius, size.min_element()),
+         border: border_widths,
+         background: linear_color(background),
+         border_color: linear_color(border),
+         gradient_start: linear_color(gradient.start),
+         gradient_end: linear_color(gradient.end),
+         gradient_angle: gradient.angle,
+         has_gradient: !gradient.is_none() as u32,
+         shadow_blur: box_shadow.blur.max(0.),
+         shadow_spread: box_shadow.spread,
+         shadow_offset: box_shadow.offset,
+         shadow_color: linear_color(box_shadow.color),
+      };
+      // absolute nodes are placed inside of the border of the parent
+      let decoration_style = |style: &mut Style| {
+         style.position_type = PositionType::Absolute;
+         style.left = Val::Px(-margin - border_widths.x);
+         style.top = Val::Px(-margin - border_widths.y);
+         style.width = Val::Px(size.x + margin * 2.);
+         style.height = Val::Px(size.y + margin * 2.);
+         style.margin = UiRect::all(Val::Px(0.));
+      };
+
+      match decoration {
+         Some(mut decoration) => {
+            decoration.background = background;
+            decoration.border_color = border;
+            let Ok((mut style, material)) = decoration_query.get_mut(decoration.entity) else {
+               continue;
+            };
+            decoration_style(&mut style);
+            if let Some(material) = materials.get_mut(material) {
+               if material.shape != shape {
+                  material.shape = shape;
+               }
+            }
+         }
+         None => {
+            let mut style = Style::default();
+            decoration_style(&mut style);
+            let decoration_entity = commands
+               .spawn((
+                  MaterialNodeBundle {
+                     style,
+                     material: materials.add(ShapeMaterial { shape }),
+                     focus_policy: FocusPolicy::Pass,
+                     z_index: ZIndex::Local(i32::MIN),
+                     ..Default::default()
+                  },
+                  Pickable::IGNORE,
+                  Name::new("[Shape Decoration]"),
+               ))
+               .set_parent(entity)
+               .id();
+            commands.entity(entity).insert(ShapeDecoration {
+               entity: decoration_entity,
+               background,
+               border_color: border,
+            });
+         }
+      }
+   }
+}
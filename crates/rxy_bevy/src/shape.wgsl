@@ -0,0 +1,81 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+struct ShapeUniform {
+    size: vec2<f32>,
+    margin: f32,
+    radius: f32,
+    // left, top, right, bottom
+    border: vec4<f32>,
+    background: vec4<f32>,
+    border_color: vec4<f32>,
+    gradient_start: vec4<f32>,
+    gradient_end: vec4<f32>,
+    gradient_angle: f32,
+    has_gradient: u32,
+    shadow_blur: f32,
+    shadow_spread: f32,
+    shadow_offset: vec2<f32>,
+    shadow_color: vec4<f32>,
+}
+
+@group(1) @binding(0) var<uniform> shape: ShapeUniform;
+
+// signed distance to a box centered at the origin with rounded corners
+fn sd_rounded_box(p: vec2<f32>, half_size: vec2<f32>, radius: f32) -> f32 {
+    let q = abs(p) - half_size + vec2(radius);
+    return length(max(q, vec2(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
+// draws `src` over `dst`, both are not premultiplied
+fn blend_over(src: vec4<f32>, dst: vec4<f32>) -> vec4<f32> {
+    let alpha = src.a + dst.a * (1.0 - src.a);
+    if alpha <= 0.0 {
+        return vec4(0.0);
+    }
+    let rgb = (src.rgb * src.a + dst.rgb * dst.a * (1.0 - src.a)) / alpha;
+    return vec4(rgb, alpha);
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    // the quad covers the node and the margin of the shadow around it
+    let p = (in.uv - vec2(0.5)) * (shape.size + vec2(shape.margin * 2.0));
+    let half_size = shape.size * 0.5;
+    let radius = min(shape.radius, min(half_size.x, half_size.y));
+
+    var shadow = vec4(0.0);
+    if shape.shadow_color.a > 0.0 {
+        let spread_radius = max(radius + shape.shadow_spread, 0.0);
+        let d = sd_rounded_box(
+            p - shape.shadow_offset,
+            max(half_size + vec2(shape.shadow_spread), vec2(0.0)),
+            spread_radius,
+        );
+        let blur = max(shape.shadow_blur, 1.0);
+        let coverage = 1.0 - smoothstep(-blur * 0.5, blur * 0.5, d);
+        shadow = vec4(shape.shadow_color.rgb, shape.shadow_color.a * coverage);
+    }
+
+    var fill = shape.background;
+    if shape.has_gradient != 0u {
+        // like css, 0deg goes to the top and the gradient line touches the corners
+        let angle = radians(shape.gradient_angle);
+        let direction = vec2(sin(angle), -cos(angle));
+        let line_length = abs(shape.size.x * direction.x) + abs(shape.size.y * direction.y);
+        let t = clamp(dot(p, direction) / max(line_length, 0.0001) + 0.5, 0.0, 1.0);
+        fill = mix(shape.gradient_start, shape.gradient_end, t);
+    }
+
+    let border = shape.border;
+    if any(border > vec4(0.0)) {
+        let inner_center = vec2(border.x - border.z, border.y - border.w) * 0.5;
+        let inner_half_size = max(half_size - vec2(border.x + border.z, border.y + border.w) * 0.5, vec2(0.0));
+        let inner_radius = max(radius - max(max(border.x, border.y), max(border.z, border.w)), 0.0);
+        let inner_d = sd_rounded_box(p - inner_center, inner_half_size, inner_radius);
+        fill = mix(fill, shape.border_color, clamp(0.5 + inner_d, 0.0, 1.0));
+    }
+
+    let d = sd_rounded_box(p, half_size, radius);
+    let body = vec4(fill.rgb, fill.a * clamp(0.5 - d, 0.0, 1.0));
+    return blend_over(body, shadow);
+}
@@ -0,0 +1,413 @@
+use bevy_ecs::prelude::{Component, Entity, With, World};
+use bevy_hierarchy::{DespawnRecursiveExt, Parent};
+use bevy_mod_picking::prelude::Pickable;
+use bevy_time::Time;
+use bevy_transform::prelude::GlobalTransform;
+use bevy_ui::{Node, PositionType, Style, Val};
+use glam::Vec2;
+
+use rxy_core::{
+   MemberOwner, NodeTree, ViewMember, ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin,
+};
+
+use crate::{AttrAnimationPlayer, AttrKeyframes, BevyRenderer, Focusable, RendererState};
+
+/// Enter/leave animations of an element.
+///
+/// The enter keyframes play when the element is built or taken back from recycling.
+/// When the element is removed or recycled, it stays in place until the leave keyframes complete,
+/// out of the layout of its siblings, see [`Leaving`].
+#[derive(Clone, Default)]
+pub struct XTransition {
+   pub enter: Option<AttrKeyframes>,
+   pub leave: Option<AttrKeyframes>,
+}
+
+pub fn x_transition() -> XTransition {
+   XTransition::default()
+}
+
+impl XTransition {
+   pub fn enter(mut self, keyframes: AttrKeyframes) -> Self {
+      self.enter = Some(keyframes);
+      self
+   }
+
+   pub fn leave(mut self, keyframes: AttrKeyframes) -> Self {
+      self.leave = Some(keyframes);
+      self
+   }
+}
+
+struct ElementTransition {
+   transition: XTransition,
+   index: ViewMemberIndex,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum LeaveAction {
+   Despawn,
+   Recycle { container: Entity },
+}
+
+pub(crate) struct LeavingNode {
+   player: AttrAnimationPlayer,
+   action: LeaveAction,
+}
+
+/// Marks an element playing its leave animation.
+///
+/// The element is no longer part of the view, it is positioned absolutely where it was laid out so
+/// the views that take its place are laid out as if it was gone.
+/// The queries over the sibling elements, like the `:nth-child` selectors, skip it.
+#[derive(Component, Clone, Debug)]
+pub struct Leaving {
+   position_type: PositionType,
+   left: Val,
+   top: Val,
+   width: Val,
+   height: Val,
+}
+
+impl Leaving {
+   /// Takes the element out of the layout of its siblings, at the place it was laid out.
+   fn detach(world: &mut World, entity: Entity) {
+      let (Some(node), Some(transform)) = (
+         world.get::<Node>(entity),
+         world.get::<GlobalTransform>(entity),
+      ) else {
+         return;
+      };
+      let rect = node.logical_rect(transform);
+      // absolute positions are relative to the padding box of the parent
+      let origin = world
+         .get::<Parent>(entity)
+         .and_then(|parent| {
+            let parent = world.get_entity(parent.get())?;
+            let rect = parent
+               .get::<Node>()?
+               .logical_rect(parent.get::<GlobalTransform>()?);
+            let border = parent.get::<Style>().map(|n| n.border).unwrap_or_default();
+            let px = |val: Val| match val {
+               Val::Px(px) => px,
+               _ => 0.,
+            };
+            Some(rect.min + Vec2::new(px(border.left), px(border.top)))
+         })
+         .unwrap_or_default();
+
+      let mut entity_mut = world.entity_mut(entity);
+      let Some(mut style) = entity_mut.get_mut::<Style>() else {
+         return;
+      };
+      let leaving = Leaving {
+         position_type: style.position_type,
+         left: style.left,
+         top: style.top,
+         width: style.width,
+         height: style.height,
+      };
+      style.position_type = PositionType::Absolute;
+      style.left = Val::Px(rect.min.x - origin.x);
+      style.top = Val::Px(rect.min.y - origin.y);
+      style.width = Val::Px(rect.width());
+      style.height = Val::Px(rect.height());
+      entity_mut.insert(leaving);
+   }
+
+   /// Puts the element back into the layout, when it is recycled.
+   fn attach(world: &mut World, entity: Entity) {
+      let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+         return;
+      };
+      let Some(leaving) = entity_mut.take::<Leaving>() else {
+         return;
+      };
+      if let Some(mut style) = entity_mut.get_mut::<Style>() {
+         style.position_type = leaving.position_type;
+         style.left = leaving.left;
+         style.top = leaving.top;
+         style.width = leaving.width;
+         style.height = leaving.height;
+      }
+   }
+}
+
+impl LeaveAction {
+   fn apply(self, world: &mut World, entity: Entity) {
+      if world.get_entity(entity).is_none() {
+         return;
+      }
+      match self {
+         LeaveAction::Despawn => {
+            world.entity_mut(entity).despawn_recursive();
+         }
+         LeaveAction::Recycle { container } => {
+            Leaving::attach(world, entity);
+            world.set_visibility(true, &entity);
+            world.insert_before(Some(&container), None, &[entity]);
+         }
+      }
+   }
+}
+
+pub(crate) trait TransitionWorldExt {
+   /// Starts the leave animation of the entity, if it has one.
+   /// Returns `false` if the entity can be removed or recycled immediately.
+   fn start_leave_transition(&mut self, entity: Entity, action: LeaveAction) -> bool;
+
+   /// Stops a running leave animation and replays the enter animation.
+   /// Without an enter animation, the element is reset to the first leave keyframe.
+   fn restart_enter_transition(&mut self, entity: Entity);
+}
+
+impl TransitionWorldExt for World {
+   fn start_leave_transition(&mut self, entity: Entity, action: LeaveAction) -> bool {
+      if let Some(leaving) = self.get_node_state_mut::<LeavingNode>(&entity) {
+         leaving.action = action;
+         return true;
+      }
+      let Some(leave) = self
+         .get_node_state_ref::<ElementTransition>(&entity)
+         .and_then(|n| n.transition.leave.clone())
+      else {
+         return false;
+      };
+      if let LeaveAction::Despawn = action {
+         // the node is no longer part of the view, keep it out of focus and picking
+         self
+            .entity_mut(entity)
+            .remove::<Focusable>()
+            .insert(Pickable::IGNORE);
+      }
+      Leaving::detach(self, entity);
+      leave.apply(self, entity, 0.);
+      self.set_node_state(
+         &entity,
+         LeavingNode {
+            player: AttrAnimationPlayer::new(leave),
+            action,
+         },
+      );
+      true
+   }
+
+   fn restart_enter_transition(&mut self, entity: Entity) {
+      drop(self.take_node_state::<LeavingNode>(&entity));
+      Leaving::attach(self, entity);
+      let Some((transition, index)) = self
+         .get_node_state_ref::<ElementTransition>(&entity)
+         .map(|n| (n.transition.clone(), n.index))
+      else {
+         return;
+      };
+      let Some(enter) = transition.enter else {
+         // without an enter animation, go back to the style the leave animation started from
+         if let Some(leave) = transition.leave {
+            leave.apply(self, entity, 0.);
+         }
+         return;
+      };
+      enter.apply(self, entity, 0.);
+      ViewMemberCtx::<BevyRenderer> {
+         index,
+         world: self,
+         node_id: entity,
+      }
+      .set_indexed_view_member_state(AttrAnimationPlayer::new(enter));
+   }
+}
+
+pub(crate) fn update_leaving_nodes(world: &mut World) {
+   let delta = world.resource::<Time>().delta_seconds();
+   let entities = world
+      .query_filtered::<Entity, With<RendererState<LeavingNode>>>()
+      .iter(world)
+      .collect::<Vec<_>>();
+
+   let mut events = vec![];
+   for entity in entities {
+      let completed = world
+         .node_state_scoped(&entity, |world, leaving: &mut LeavingNode| {
+            !leaving.player.tick(delta, world, entity, &mut events)
+         })
+         .unwrap_or(false);
+      if completed {
+         if let Some(leaving) = world.take_node_state::<LeavingNode>(&entity) {
+            leaving.action.apply(world, entity);
+         }
+      }
+   }
+   world.send_event_batch(events);
+}
+
+impl ViewMemberOrigin<BevyRenderer> for XTransition {
+   type Origin = Self;
+}
+
+impl ViewMember<BevyRenderer> for XTransition {
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(mut ctx: ViewMemberCtx<BevyRenderer>, view_removed: bool) {
+      // the leave animation still needs the transition after the view is removed
+      if view_removed {
+         return;
+      }
+      drop(ctx.world.take_node_state::<ElementTransition>(&ctx.node_id));
+      drop(ctx.take_indexed_view_member_state::<AttrAnimationPlayer>());
+   }
+
+   fn build(self, mut ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      if let Some(enter) = self.enter.clone() {
+         enter.apply(&mut *ctx.world, ctx.node_id, 0.);
+         ctx.set_indexed_view_member_state(AttrAnimationPlayer::new(enter));
+      }
+      self.rebuild(ctx);
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      ctx.world.set_node_state(
+         &ctx.node_id,
+         ElementTransition {
+            transition: self,
+            index: ctx.index,
+         },
+      );
+   }
+}
+
+pub trait MemberOwnerTransitionExt: MemberOwner<BevyRenderer> + Sized {
+   fn transition(self, transition: XTransition) -> Self::AddMember<XTransition>
+   where
+      (Self::VM, XTransition): ViewMember<BevyRenderer>,
+   {
+      self.member(transition)
+   }
+}
+
+impl<T> MemberOwnerTransitionExt for T where T: MemberOwner<BevyRenderer> {}
+
+#[cfg(test)]
+mod tests {
+   use core::time::Duration;
+
+   use bevy_ecs::event::Events;
+   use bevy_hierarchy::Parent;
+   use bevy_render::color::Color;
+   use bevy_ui::BackgroundColor;
+
+   use crate::all_attrs::bg_color;
+   use crate::elements::element_div;
+   use crate::AnimationEvent;
+
+   use super::*;
+
+   fn transition_world() -> World {
+      let mut world = World::new();
+      world.init_resource::<Time>();
+      world.init_resource::<Events<AnimationEvent>>();
+      world
+   }
+
+   fn fade(from: Color, to: Color) -> AttrKeyframes {
+      AttrKeyframes::new(Duration::from_millis(100)).track(bg_color, [(0., from), (1., to)])
+   }
+
+   fn spawn_element(world: &mut World, transition: XTransition) -> Entity {
+      let entity = world.spawn_node::<element_div>(None, None);
+      transition.build(
+         ViewMemberCtx {
+            index: 0,
+            world: &mut *world,
+            node_id: entity,
+         },
+         false,
+      );
+      entity
+   }
+
+   fn finish_leaving(world: &mut World) {
+      world
+         .resource_mut::<Time>()
+         .advance_by(Duration::from_millis(200));
+      update_leaving_nodes(world);
+   }
+
+   fn color(world: &World, entity: Entity) -> Color {
+      world.get::<BackgroundColor>(entity).unwrap().0
+   }
+
+   #[test]
+   fn enter_starts_from_the_first_keyframe() {
+      let mut world = transition_world();
+      let entity = spawn_element(
+         &mut world,
+         x_transition().enter(fade(Color::NONE, Color::WHITE)),
+      );
+      assert_eq!(color(&world, entity), Color::NONE);
+   }
+
+   #[test]
+   fn leave_despawns_once_completed() {
+      let mut world = transition_world();
+      let entity = spawn_element(
+         &mut world,
+         x_transition().leave(fade(Color::WHITE, Color::NONE)),
+      );
+      assert!(world.start_leave_transition(entity, LeaveAction::Despawn));
+      assert_eq!(color(&world, entity), Color::WHITE);
+      assert!(world.get_entity(entity).is_some());
+
+      finish_leaving(&mut world);
+      assert!(world.get_entity(entity).is_none());
+   }
+
+   #[test]
+   fn without_leave_nothing_is_delayed() {
+      let mut world = transition_world();
+      let entity = spawn_element(
+         &mut world,
+         x_transition().enter(fade(Color::NONE, Color::WHITE)),
+      );
+      assert!(!world.start_leave_transition(entity, LeaveAction::Despawn));
+   }
+
+   #[test]
+   fn recycled_leave_only_element_resets_when_reused() {
+      let mut world = transition_world();
+      let container = world.spawn_empty().id();
+      let entity = spawn_element(
+         &mut world,
+         x_transition().leave(fade(Color::WHITE, Color::NONE)),
+      );
+      assert!(world.start_leave_transition(entity, LeaveAction::Recycle { container }));
+
+      finish_leaving(&mut world);
+      assert_eq!(world.get::<Parent>(entity).unwrap().get(), container);
+      assert_eq!(color(&world, entity), Color::NONE);
+
+      world.restart_enter_transition(entity);
+      assert_eq!(color(&world, entity), Color::WHITE);
+   }
+
+   #[test]
+   fn recycled_element_replays_enter() {
+      let mut world = transition_world();
+      let container = world.spawn_empty().id();
+      let entity = spawn_element(
+         &mut world,
+         x_transition()
+            .enter(fade(Color::RED, Color::WHITE))
+            .leave(fade(Color::WHITE, Color::NONE)),
+      );
+      assert!(world.start_leave_transition(entity, LeaveAction::Recycle { container }));
+
+      // reused before the leave animation completed
+      world.restart_enter_transition(entity);
+      assert_eq!(color(&world, entity), Color::RED);
+      finish_leaving(&mut world);
+      assert!(world.get::<Parent>(entity).is_none());
+   }
+}
@@ -0,0 +1,192 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, RwLock};
+
+use bevy_ecs::prelude::Entity;
+#[cfg(feature = "xy_reactive")]
+use bevy_ecs::prelude::{Component, Query, Res, World};
+#[cfg(feature = "xy_reactive")]
+use bevy_hierarchy::{HierarchyQueryExt, Parent};
+#[cfg(feature = "xy_reactive")]
+use xy_reactive::prelude::{use_signal, ReadSignal, SignalGetUntracked, SignalSet, WriteSignal};
+
+use rxy_core::{ViewMember, ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin};
+
+use crate::{BevyRenderer, FocusedEntity};
+
+/// Returns a view member and the [`FocusHandle`] of the view it is added to.
+///
+/// ```ignore
+/// let (focus_member, focus_handle) = x_focus_handle();
+/// input().member(focus_member);
+/// button().on_pressed(move |mut focus: ResMut<FocusedEntity>| focus_handle.focus(&mut focus));
+/// ```
+pub fn x_focus_handle() -> (XFocus, FocusHandle) {
+   let handle = FocusHandle::new();
+   (XFocus(handle.clone()), handle)
+}
+
+/// Focuses and blurs a view, and observes its focus.
+#[derive(Clone)]
+pub struct FocusHandle {
+   entity: Arc<RwLock<Option<Entity>>>,
+   #[cfg(feature = "xy_reactive")]
+   signals: FocusSignals,
+}
+
+impl Debug for FocusHandle {
+   fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      f.debug_struct("FocusHandle")
+         .field("entity", &self.entity)
+         .finish_non_exhaustive()
+   }
+}
+
+impl FocusHandle {
+   fn new() -> Self {
+      Self {
+         entity: Default::default(),
+         #[cfg(feature = "xy_reactive")]
+         signals: FocusSignals::new(),
+      }
+   }
+
+   /// The entity of the view, `None` until it is built and after it is removed.
+   pub fn entity(&self) -> Option<Entity> {
+      *self.entity.read().unwrap()
+   }
+
+   pub fn focus(&self, focus: &mut FocusedEntity) {
+      if let Some(entity) = self.entity() {
+         focus.0 = Some(entity);
+      }
+   }
+
+   /// Removes the focus if the view has it.
+   pub fn blur(&self, focus: &mut FocusedEntity) {
+      if self.entity().is_some() && focus.0 == self.entity() {
+         focus.0 = None;
+      }
+   }
+
+   pub fn is_focused(&self, focus: &FocusedEntity) -> bool {
+      self.entity().is_some() && focus.0 == self.entity()
+   }
+
+   /// Whether the view is focused, similar to the `:focus` css pseudo-class.
+   #[cfg(feature = "xy_reactive")]
+   pub fn focused(&self) -> ReadSignal<bool> {
+      self.signals.focused.0
+   }
+
+   /// Whether the view or one of its descendants is focused, similar to the `:focus-within` css pseudo-class.
+   #[cfg(feature = "xy_reactive")]
+   pub fn focus_within(&self) -> ReadSignal<bool> {
+      self.signals.focus_within.0
+   }
+}
+
+pub struct XFocus(FocusHandle);
+
+/// The entity of the [`FocusHandle`], reset when the view member is unbuilt.
+struct FocusHandleEntity(Arc<RwLock<Option<Entity>>>);
+
+impl ViewMemberOrigin<BevyRenderer> for XFocus {
+   type Origin = Self;
+}
+
+impl ViewMember<BevyRenderer> for XFocus {
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(mut ctx: ViewMemberCtx<BevyRenderer>, view_removed: bool) {
+      if let Some(FocusHandleEntity(entity)) = ctx.take_indexed_view_member_state() {
+         let mut entity = entity.write().unwrap();
+         // the handle may have been built into another view since
+         if *entity == Some(ctx.node_id) {
+            *entity = None;
+         }
+      }
+      #[cfg(feature = "xy_reactive")]
+      if !view_removed {
+         ctx.world.entity_mut(ctx.node_id).remove::<FocusSignals>();
+      }
+      #[cfg(not(feature = "xy_reactive"))]
+      let _ = (ctx, view_removed);
+   }
+
+   fn build(self, mut ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      let entity = ctx.node_id;
+      *self.0.entity.write().unwrap() = Some(entity);
+      ctx.set_indexed_view_member_state(FocusHandleEntity(self.0.entity.clone()));
+      #[cfg(feature = "xy_reactive")]
+      {
+         self.0.signals.update(entity, ctx.world);
+         ctx.world.entity_mut(entity).insert(self.0.signals);
+      }
+      #[cfg(not(feature = "xy_reactive"))]
+      let _ = ctx;
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      self.build(ctx, true);
+   }
+}
+
+/// The signals of a [`FocusHandle`], updated when the [`FocusedEntity`] changes.
+#[cfg(feature = "xy_reactive")]
+#[derive(Component, Copy, Clone)]
+pub struct FocusSignals {
+   focused: (ReadSignal<bool>, WriteSignal<bool>),
+   focus_within: (ReadSignal<bool>, WriteSignal<bool>),
+}
+
+#[cfg(feature = "xy_reactive")]
+impl FocusSignals {
+   fn new() -> Self {
+      Self {
+         focused: use_signal(false),
+         focus_within: use_signal(false),
+      }
+   }
+
+   fn set(&self, focused: bool, focus_within: bool) {
+      if self.focused.0.try_get_untracked() != Some(focused) {
+         self.focused.1.try_set(focused);
+      }
+      if self.focus_within.0.try_get_untracked() != Some(focus_within) {
+         self.focus_within.1.try_set(focus_within);
+      }
+   }
+
+   fn update(&self, entity: Entity, world: &mut World) {
+      let focus = world.resource::<FocusedEntity>().0;
+      let mut focus_within = focus == Some(entity);
+      if let Some(mut current) = focus.filter(|_| !focus_within) {
+         while let Some(parent) = world.get::<Parent>(current) {
+            current = parent.get();
+            if current == entity {
+               focus_within = true;
+               break;
+            }
+         }
+      }
+      self.set(focus == Some(entity), focus_within);
+   }
+}
+
+#[cfg(feature = "xy_reactive")]
+pub(crate) fn update_focus_signals(
+   focus: Res<FocusedEntity>,
+   query: Query<(Entity, &FocusSignals)>,
+   parents: Query<&Parent>,
+) {
+   for (entity, signals) in query.iter() {
+      let focused = focus.0 == Some(entity);
+      let focus_within = focused
+         || focus
+            .0
+            .is_some_and(|focus| parents.iter_ancestors(focus).any(|n| n == entity));
+      signals.set(focused, focus_within);
+   }
+}
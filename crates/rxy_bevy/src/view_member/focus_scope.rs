@@ -0,0 +1,140 @@
+use rxy_core::{ViewMember, ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin};
+
+use crate::{BevyRenderer, FocusScope, InitialFocus, XBundle};
+
+/// Makes the view a [`FocusScope`]: Tab navigation cycles within it, its first focusable descendant
+/// gets the focus and the previous focus is restored when the view is removed.
+///
+/// ```ignore
+/// div().member(x_focus_scope()).children((button().member(x_initial_focus()), button()))
+/// ```
+pub fn x_focus_scope() -> XFocusScope {
+   XFocusScope(FocusScope::default())
+}
+
+/// Marks the view as the [`InitialFocus`] of its [`FocusScope`].
+pub fn x_initial_focus() -> XBundle<InitialFocus> {
+   XBundle(InitialFocus)
+}
+
+pub struct XFocusScope(pub FocusScope);
+
+impl XFocusScope {
+   pub fn trap(mut self, trap: bool) -> Self {
+      self.0.trap = trap;
+      self
+   }
+
+   pub fn autofocus(mut self, autofocus: bool) -> Self {
+      self.0.autofocus = autofocus;
+      self
+   }
+
+   pub fn restore_focus(mut self, restore_focus: bool) -> Self {
+      self.0.restore_focus = restore_focus;
+      self
+   }
+}
+
+impl ViewMemberOrigin<BevyRenderer> for XFocusScope {
+   type Origin = Self;
+}
+
+impl ViewMember<BevyRenderer> for XFocusScope {
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(ctx: ViewMemberCtx<BevyRenderer>, view_removed: bool) {
+      XBundle::<FocusScope>::unbuild(ctx, view_removed);
+   }
+
+   fn build(self, ctx: ViewMemberCtx<BevyRenderer>, will_rebuild: bool) {
+      XBundle(self.0).build(ctx, will_rebuild);
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      let entity = ctx.node_id;
+      // the settings are read when the scope is added
+      if ctx.world.get::<FocusScope>(entity) != Some(&self.0) {
+         ctx.world
+            .entity_mut(entity)
+            .remove::<FocusScope>()
+            .insert(self.0);
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use bevy_app::App;
+   use bevy_ecs::prelude::{Entity, With};
+
+   use rxy_core::{ElementView, IntoView, ViewKey};
+
+   use crate::elements::ElementTypeRegisterAppExt;
+   use crate::prelude::*;
+   use crate::{FocusScopes, Focusable, FocusablePlugin, FocusedEntity, RxyViewSpawner};
+
+   use super::*;
+
+   fn focus_app() -> (App, Entity) {
+      let mut app = App::new();
+      app.register_element_types().add_plugins(FocusablePlugin);
+      let outside = app.world.spawn(Focusable::default()).id();
+      app.world.resource_mut::<FocusedEntity>().0 = Some(outside);
+      (app, outside)
+   }
+
+   fn scope_view(scope: XFocusScope) -> impl IntoView<BevyRenderer> {
+      div()
+         .member(scope)
+         .children((button(), button().member(x_initial_focus())))
+   }
+
+   fn initial_focus(app: &mut App) -> Entity {
+      app.world
+         .query_filtered::<Entity, With<InitialFocus>>()
+         .single(&app.world)
+   }
+
+   #[test]
+   fn scope_view_focuses_its_initial_focus_and_restores_on_removal() {
+      let (mut app, outside) = focus_app();
+      let parent = app.world.spawn_empty().id();
+      let key = app
+         .world
+         .spawn_view(scope_view(x_focus_scope()), move |_| parent);
+      app.update();
+
+      let initial_focus = initial_focus(&mut app);
+      assert_eq!(app.world.resource::<FocusedEntity>().0, Some(initial_focus));
+      assert!(app.world.resource::<FocusScopes>().trap().is_some());
+
+      key.remove(&mut app.world);
+      app.update();
+      assert_eq!(app.world.resource::<FocusedEntity>().0, Some(outside));
+      assert_eq!(app.world.resource::<FocusScopes>().trap(), None);
+   }
+
+   #[test]
+   fn scope_view_settings() {
+      let (mut app, outside) = focus_app();
+      let parent = app.world.spawn_empty().id();
+      let scope = x_focus_scope()
+         .trap(false)
+         .autofocus(false)
+         .restore_focus(false);
+      let key = app.world.spawn_view(scope_view(scope), move |_| parent);
+      app.update();
+      assert_eq!(app.world.resource::<FocusedEntity>().0, Some(outside));
+      assert_eq!(app.world.resource::<FocusScopes>().trap(), None);
+
+      let initial_focus = initial_focus(&mut app);
+      app.world.resource_mut::<FocusedEntity>().0 = Some(initial_focus);
+      key.remove(&mut app.world);
+      app.update();
+      // the removed entity is not focused again, the focus is not restored either
+      assert_eq!(app.world.resource::<FocusedEntity>().0, None);
+   }
+}
@@ -1,5 +1,9 @@
 pub use bundle::*;
 pub use event::*;
+pub use focus::*;
+pub use focus_scope::*;
 
 mod bundle;
 mod event;
+mod focus;
+mod focus_scope;
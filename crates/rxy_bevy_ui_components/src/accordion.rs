@@ -0,0 +1,166 @@
+use std::fmt::Debug;
+
+use bevy_input::keyboard::KeyCode;
+use bevy_ui::Display;
+
+use rxy_ui::bevy::x_bundle;
+use rxy_ui::prelude::*;
+
+use crate::option_group::{x_option, x_option_keys, OptionGroupContext, OptionGroupNode};
+use crate::tabs::{lazy_content, x_panel_visibility};
+use crate::x_ui_setting_once;
+
+#[derive(TypedStyle)]
+pub struct AccordionStyle;
+
+#[derive(TypedStyle)]
+pub struct AccordionHeaderStyle;
+
+#[derive(TypedStyle)]
+pub struct AccordionPanelStyle;
+
+/// A list of [`accordion_item`]s, the selected one is expanded and confirming its header collapses it.
+///
+/// A panel is built the first time it is expanded and is kept hidden when it is collapsed.
+/// The up and down arrow keys move the focus between the headers.
+///
+/// ```ignore
+/// let selected = use_rw_signal(Some("Graphics"));
+/// accordion::<&str>().selected(selected).slot_content((
+///    accordion_item("Graphics", "Graphics", graphics_settings()),
+///    accordion_item("Controls", "Controls", controls_settings()),
+/// ))
+/// ```
+#[derive(ElementSchema)]
+pub struct Accordion<T: Debug + Send + Sync + PartialEq + Clone + 'static> {
+   ctx: SchemaCtx,
+   content: Slot,
+   selected: ReadSignal<Option<T>>,
+   onchange: Sender<Option<T>>,
+}
+
+impl<T> SchemaElementView<BevyRenderer> for Accordion<T>
+where
+   T: Debug + Send + Sync + PartialEq + Clone + 'static,
+{
+   fn view(self) -> impl IntoElementView<BevyRenderer> {
+      let Accordion {
+         mut ctx,
+         content,
+         selected,
+         onchange,
+      } = self;
+      let value_signal = ctx.use_controlled_state(selected, onchange);
+
+      ctx.default_typed_style(AccordionStyle, || {
+         x().flex_col().border(1).border_color(token::border)
+      });
+      ctx.default_typed_style(AccordionHeaderStyle, || {
+         (
+            x().flex()
+               .items_center()
+               .px(12)
+               .py(8)
+               .text_color(token::text),
+            x_hover().bg_color(token::surface),
+            x_selected().text_color(token::primary),
+            x_focus_visible()
+               .outline_width(2)
+               .outline_offset(2)
+               .outline_color(token::primary),
+         )
+      });
+      ctx.default_typed_style(AccordionPanelStyle, || x().flex_col().px(12).py(8));
+
+      provide_context(
+         OptionGroupContext {
+            value_signal,
+            readonly: use_signal(false).0,
+         },
+         div()
+            .name("accordion")
+            .style(AccordionStyle)
+            .member(x_bundle(OptionGroupNode))
+            .children(content),
+      )
+   }
+}
+
+/// A header expanding the content in an [`accordion`].
+pub fn accordion_item<T, H, C>(
+   value: T,
+   header: H,
+   content: C,
+) -> FnSchemaView<impl SchemaIntoViewFn<BevyRenderer>>
+where
+   T: Send + Sync + PartialEq + Clone + 'static,
+   H: IntoView<BevyRenderer> + Send + 'static,
+   C: IntoView<BevyRenderer> + Send + 'static,
+{
+   fn_schema_view(move || {
+      view_builder(move |ctx, _| {
+         let OptionGroupContext { value_signal, .. } =
+            ctx.context::<OptionGroupContext<Option<T>>>();
+         let is_expanded = use_memo({
+            let value = value.clone();
+            move |_| value_signal.with(|n| n.as_ref() == Some(&value))
+         });
+
+         div()
+            .name("accordion-item")
+            .style(x().flex_col())
+            .children((
+               button()
+                  .name("accordion-header")
+                  .style(AccordionHeaderStyle)
+                  .selected(is_expanded)
+                  .member(x_option())
+                  .member(x_option_keys(KeyCode::ArrowUp, KeyCode::ArrowDown, false))
+                  .member(x_ui_setting_once(move |n| {
+                     ().on(n.confirm_event_ids.clone(), move || {
+                        value_signal.update(|selected| toggle(selected, &value));
+                     })
+                  }))
+                  .children(header),
+               // the collapsed panel takes no space
+               div()
+                  .name("accordion-panel")
+                  .style(AccordionPanelStyle)
+                  .display(rx(move || {
+                     if is_expanded.get() {
+                        Display::Flex
+                     } else {
+                        Display::None
+                     }
+                  }))
+                  .member(x_panel_visibility(is_expanded))
+                  .children(lazy_content(is_expanded, content)),
+            ))
+      })
+   })
+}
+
+/// Collapses the item if it is expanded, expands it otherwise.
+fn toggle<T: PartialEq + Clone>(selected: &mut Option<T>, value: &T) {
+   *selected = if selected.as_ref() == Some(value) {
+      None
+   } else {
+      Some(value.clone())
+   };
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn confirming_a_header_toggles_its_item() {
+      let mut selected = None;
+      toggle(&mut selected, &"Graphics");
+      assert_eq!(selected, Some("Graphics"));
+      toggle(&mut selected, &"Controls");
+      assert_eq!(selected, Some("Controls"));
+      toggle(&mut selected, &"Controls");
+      assert_eq!(selected, None);
+   }
+}
@@ -3,7 +3,7 @@ use bevy_render::prelude::Color;
 use rxy_ui::prelude::*;
 
 // use bevy::prelude::*;
-use crate::{x_theme_once, x_ui_setting_once};
+use crate::x_ui_setting_once;
 
 #[derive(TypedStyle)]
 pub struct CheckboxStyle;
@@ -24,6 +24,7 @@ pub fn schema_checkbox(
                 .border(1)
                 .border_color(Color::DARK_GRAY),
             x_hover().bg_color(Color::DARK_GRAY),
+            x_checked().bg_color(token::primary),
             // FocusStyle,
         )
     });
@@ -31,11 +32,7 @@ pub fn schema_checkbox(
     button()
         .name("checkbox")
         .style(CheckboxStyle)
-        .bg_color(rx(move || {
-            is_checked
-                .get()
-                .then_some(x_theme_once(|n| n.primary_color))
-        }))
+        .checked(rx(move || is_checked.get()))
         .rx_member(move || {
             readonly.not_then_some(x_ui_setting_once(move |n| {
                 ().on(n.confirm_event_ids.clone(), move || {
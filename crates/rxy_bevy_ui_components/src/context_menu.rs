@@ -0,0 +1,514 @@
+use std::sync::Arc;
+
+use bevy_app::{App, Plugin, PostUpdate, Update};
+use bevy_ecs::prelude::{
+   Commands, Component, Entity, IntoSystemConfigs, Query, Res, Resource, With, World,
+};
+use bevy_hierarchy::DespawnRecursiveExt;
+use bevy_input::keyboard::KeyCode;
+use bevy_input::mouse::MouseButton;
+use bevy_input::ButtonInput;
+use bevy_mod_picking::prelude::PointerButton;
+use bevy_render::prelude::Visibility;
+use bevy_transform::prelude::GlobalTransform;
+use bevy_ui::prelude::NodeBundle;
+use bevy_ui::{FocusPolicy, Node, PositionType, Style, UiSystem, Val, ZIndex};
+use bevy_window::{PrimaryWindow, Window};
+use glam::Vec2;
+
+use rxy_ui::bevy::navigation::NavigationTargets;
+use rxy_ui::bevy::x_bundle;
+use rxy_ui::prelude::*;
+use rxy_ui::style::TypedStyleWorldExt;
+
+use crate::option_group::{x_option, x_option_keys, OptionGroupNode};
+use crate::x_ui_setting_once;
+
+/// Opens the menus of the views with a [`context_menu`] member and closes them on outside presses.
+pub struct ContextMenuPlugin;
+
+impl Plugin for ContextMenuPlugin {
+   fn build(&self, app: &mut App) {
+      app.init_resource::<ContextMenuState>()
+         .add_systems(Update, close_context_menu_on_outside_press)
+         .add_systems(PostUpdate, position_context_menu.after(UiSystem::Layout));
+   }
+}
+
+#[derive(TypedStyle)]
+pub struct ContextMenuStyle;
+#[derive(TypedStyle)]
+pub struct ContextMenuItemStyle;
+#[derive(TypedStyle)]
+pub struct ContextMenuShortcutStyle;
+#[derive(TypedStyle)]
+pub struct ContextMenuSeparatorStyle;
+
+type MenuAction = Arc<dyn Fn(&mut World) + Send + Sync>;
+
+#[derive(Clone)]
+enum MenuItemKind {
+   Action(MenuAction),
+   Submenu(Arc<Vec<MenuItem>>),
+   Separator,
+}
+
+/// An entry of a [`context_menu`], see [`menu_item`], [`submenu`] and [`menu_separator`].
+#[derive(Clone)]
+pub struct MenuItem {
+   kind: MenuItemKind,
+   label: String,
+   shortcut: Option<String>,
+   disabled: bool,
+}
+
+/// Runs the action and closes the menus when confirmed.
+pub fn menu_item(
+   label: impl Into<String>,
+   action: impl Fn(&mut World) + Send + Sync + 'static,
+) -> MenuItem {
+   MenuItem {
+      kind: MenuItemKind::Action(Arc::new(action)),
+      label: label.into(),
+      shortcut: None,
+      disabled: false,
+   }
+}
+
+/// Opens the items in a nested menu when hovered, confirmed or on the right arrow key.
+pub fn submenu(label: impl Into<String>, items: impl IntoIterator<Item = MenuItem>) -> MenuItem {
+   MenuItem {
+      kind: MenuItemKind::Submenu(Arc::new(items.into_iter().collect())),
+      label: label.into(),
+      shortcut: None,
+      disabled: false,
+   }
+}
+
+pub fn menu_separator() -> MenuItem {
+   MenuItem {
+      kind: MenuItemKind::Separator,
+      label: String::new(),
+      shortcut: None,
+      disabled: false,
+   }
+}
+
+impl MenuItem {
+   /// The text shown at the end of the item, e.g. `Ctrl+C`. It does not bind the keys.
+   pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+      self.shortcut = Some(shortcut.into());
+      self
+   }
+
+   /// A disabled item is shown muted and can not be focused or confirmed.
+   pub fn disabled(mut self, disabled: bool) -> Self {
+      self.disabled = disabled;
+      self
+   }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum MenuOrigin {
+   Pointer(Vec2),
+   /// A submenu is placed next to the item that opened it.
+   Item(Entity),
+}
+
+/// The overlay node of an open menu, the root menu has a depth of 0 and each submenu one more.
+#[derive(Component, Clone, Debug)]
+pub struct ContextMenu {
+   pub depth: usize,
+   origin: MenuOrigin,
+}
+
+/// The open menus, the index being their depth.
+#[derive(Resource, Default, Debug)]
+struct ContextMenuState {
+   menus: Vec<Entity>,
+}
+
+/// Opens a menu of the items at the pointer when the view is clicked with the secondary button.
+///
+/// The items are navigated with the arrow, home and end keys, escape closes the innermost menu,
+/// and pressing outside of the menus closes them all.
+///
+/// ```ignore
+/// div().member(context_menu([
+///    menu_item("Copy", copy).shortcut("Ctrl+C"),
+///    menu_item("Paste", paste).shortcut("Ctrl+V").disabled(true),
+///    menu_separator(),
+///    submenu("Sort by", [menu_item("Name", sort_by_name), menu_item("Date", sort_by_date)]),
+/// ]))
+/// ```
+pub fn context_menu(items: impl IntoIterator<Item = MenuItem>) -> impl ViewMember<BevyRenderer> {
+   let items = Arc::new(items.into_iter().collect::<Vec<_>>());
+   // the innermost context menu wins
+   ().on(
+      BubblePointerEvent::Click(Some(PointerButton::Secondary)).stop_propagation(),
+      move |world: &mut World| {
+         let position = world
+            .resource::<ListenerInputPointerClick>()
+            .pointer_location
+            .position;
+         open_context_menu(world, 0, MenuOrigin::Pointer(position), items.clone(), true);
+      },
+   )
+}
+
+fn open_context_menu(
+   world: &mut World,
+   depth: usize,
+   origin: MenuOrigin,
+   items: Arc<Vec<MenuItem>>,
+   autofocus: bool,
+) {
+   close_context_menus(world, depth);
+   register_context_menu_styles(world);
+
+   let menu = world
+      .spawn((
+         NodeBundle {
+            style: Style {
+               position_type: PositionType::Absolute,
+               ..Default::default()
+            },
+            focus_policy: FocusPolicy::Block,
+            // below the tooltips
+            z_index: ZIndex::Global(i32::MAX - 2),
+            // shown once positioned
+            visibility: Visibility::Hidden,
+            ..Default::default()
+         },
+         ContextMenu { depth, origin },
+         FocusScope {
+            // the submenus opened by hovering leave the focus in the parent menu
+            trap: depth == 0 || autofocus,
+            autofocus,
+            restore_focus: true,
+         },
+         OptionGroupNode,
+      ))
+      .id();
+   let _ = world.spawn_view(
+      div()
+         .name("context-menu")
+         .style(ContextMenuStyle)
+         .children(x_iter(
+            items
+               .iter()
+               .cloned()
+               .map(move |item| menu_item_view(item, depth)),
+         )),
+      move |_| menu,
+   );
+   world.resource_mut::<ContextMenuState>().menus.push(menu);
+}
+
+/// Closes the menus from the depth on, a depth of 0 closes them all.
+fn close_context_menus(world: &mut World, depth: usize) {
+   let menus = &mut world.resource_mut::<ContextMenuState>().menus;
+   let closed = menus.split_off(depth.min(menus.len()));
+   for menu in closed.into_iter().rev() {
+      if let Some(menu) = world.get_entity_mut(menu) {
+         menu.despawn_recursive();
+      }
+   }
+}
+
+fn register_context_menu_styles(world: &mut World) {
+   world.default_typed_style(ContextMenuStyle, || {
+      x().flex_col()
+         .min_w(180)
+         .py(4)
+         .bg_color(token::surface)
+         .border(1)
+         .border_color(token::border)
+   });
+   world.default_typed_style(ContextMenuItemStyle, || {
+      (
+         x().flex()
+            .justify_between()
+            .items_center()
+            .gap(24)
+            .px(12)
+            .py(4)
+            .text_color(token::text),
+         x_hover().bg_color(token::background),
+         x_focus_visible().bg_color(token::background),
+         x_disabled().text_color(token::text_muted),
+      )
+   });
+   world.default_typed_style(ContextMenuShortcutStyle, || {
+      x().text_color(token::text_muted)
+   });
+   world.default_typed_style(ContextMenuSeparatorStyle, || {
+      x().h(1).my(4).bg_color(token::border)
+   });
+}
+
+fn menu_item_view(item: MenuItem, depth: usize) -> impl IntoView<BevyRenderer> {
+   let MenuItem {
+      kind,
+      label,
+      shortcut,
+      disabled,
+   } = item;
+   if let MenuItemKind::Separator = kind {
+      return div()
+         .name("context-menu-separator")
+         .style(ContextMenuSeparatorStyle)
+         .either_left();
+   }
+   let end = match kind {
+      MenuItemKind::Submenu(_) => Some(">".to_string()),
+      _ => shortcut,
+   };
+   let content = (
+      label,
+      end.map(|n| n.into_view().style(ContextMenuShortcutStyle)),
+   );
+   if disabled {
+      // not focusable, so the keys skip it
+      return div()
+         .name("context-menu-item")
+         .style(ContextMenuItemStyle)
+         .disabled(true)
+         .children(content)
+         .either_left()
+         .either_right();
+   }
+
+   button()
+      .name("context-menu-item")
+      .style(ContextMenuItemStyle)
+      .member(x_option())
+      .member(x_option_keys(KeyCode::ArrowUp, KeyCode::ArrowDown, true))
+      .member(member_builder(
+         move |ctx: ViewMemberCtx<BevyRenderer>, _| {
+            let entry = ctx.node_id;
+            let submenu = match &kind {
+               MenuItemKind::Submenu(items) => Some(items.clone()),
+               _ => None,
+            };
+            (
+               // the arrow keys open and close the submenus instead of navigating away from the item
+               x_bundle(NavigationTargets::default().left(entry).right(entry)),
+               ().on_esc(move |world: &mut World| close_context_menus(world, depth))
+                  .on_just_pressed(KeyCode::ArrowLeft, move |world: &mut World| {
+                     if depth > 0 {
+                        close_context_menus(world, depth);
+                     }
+                  })
+                  .on_just_pressed(KeyCode::ArrowRight, {
+                     let submenu = submenu.clone();
+                     move |world: &mut World| {
+                        if let Some(items) = submenu.clone() {
+                           open_context_menu(
+                              world,
+                              depth + 1,
+                              MenuOrigin::Item(entry),
+                              items,
+                              true,
+                           );
+                        }
+                     }
+                  })
+                  .on_pointer_over(move |world: &mut World| {
+                     hover_menu_item(world, depth, entry, submenu.clone());
+                  }),
+               x_ui_setting_once(move |n| {
+                  ().on(
+                     n.confirm_event_ids.clone(),
+                     move |world: &mut World| match &kind {
+                        MenuItemKind::Action(action) => {
+                           close_context_menus(world, 0);
+                           action(world);
+                        }
+                        MenuItemKind::Submenu(items) => {
+                           open_context_menu(
+                              world,
+                              depth + 1,
+                              MenuOrigin::Item(entry),
+                              items.clone(),
+                              true,
+                           );
+                        }
+                        MenuItemKind::Separator => {}
+                     },
+                  )
+               }),
+            )
+         },
+      ))
+      .children(content)
+      .either_right()
+      .either_right()
+}
+
+/// Closes the submenus of the other items and opens the one of the hovered item.
+fn hover_menu_item(
+   world: &mut World,
+   depth: usize,
+   entry: Entity,
+   submenu: Option<Arc<Vec<MenuItem>>>,
+) {
+   let is_open = world
+      .resource::<ContextMenuState>()
+      .menus
+      .get(depth + 1)
+      .and_then(|n| world.get::<ContextMenu>(*n))
+      .is_some_and(|n| n.origin == MenuOrigin::Item(entry));
+   if is_open {
+      return;
+   }
+   close_context_menus(world, depth + 1);
+   if let Some(items) = submenu {
+      open_context_menu(world, depth + 1, MenuOrigin::Item(entry), items, false);
+   }
+}
+
+fn close_context_menu_on_outside_press(
+   mut commands: Commands,
+   buttons: Res<ButtonInput<MouseButton>>,
+   windows: Query<&Window, With<PrimaryWindow>>,
+   menus: Query<(&Node, &GlobalTransform), With<ContextMenu>>,
+) {
+   if menus.is_empty() || buttons.get_just_pressed().next().is_none() {
+      return;
+   }
+   let cursor = windows.get_single().ok().and_then(|n| n.cursor_position());
+   let inside = cursor.is_some_and(|cursor| {
+      menus
+         .iter()
+         .any(|(node, transform)| node.logical_rect(transform).contains(cursor))
+   });
+   if !inside {
+      commands.add(|world: &mut World| close_context_menus(world, 0));
+   }
+}
+
+/// The top left corner of a menu of the size opening from the start and end of the space,
+/// towards the end when it fits and towards the start otherwise.
+fn menu_position((start, end): (Vec2, Vec2), size: Vec2, window_size: Vec2) -> Vec2 {
+   let x = if end.x + size.x <= window_size.x {
+      end.x
+   } else {
+      start.x - size.x
+   };
+   let y = if end.y + size.y <= window_size.y {
+      end.y
+   } else {
+      start.y - size.y
+   };
+   Vec2::new(x, y).clamp(Vec2::ZERO, (window_size - size).max(Vec2::ZERO))
+}
+
+fn position_context_menu(
+   mut menus: Query<(&ContextMenu, &Node, &mut Style, &mut Visibility)>,
+   items: Query<(&Node, &GlobalTransform)>,
+   windows: Query<&Window, With<PrimaryWindow>>,
+) {
+   let Ok(window) = windows.get_single() else {
+      return;
+   };
+   let window_size = Vec2::new(window.width(), window.height());
+   for (menu, node, mut style, mut visibility) in menus.iter_mut() {
+      let size = node.size();
+      if size.x <= 0. || size.y <= 0. {
+         continue;
+      }
+      let (start, end) = match menu.origin {
+         MenuOrigin::Pointer(position) => (position, position),
+         MenuOrigin::Item(item) => {
+            let Ok((item_node, item_transform)) = items.get(item) else {
+               continue;
+            };
+            let rect = item_node.logical_rect(item_transform);
+            (
+               Vec2::new(rect.min.x, rect.max.y),
+               Vec2::new(rect.max.x, rect.min.y),
+            )
+         }
+      };
+      let position = menu_position((start, end), size, window_size);
+
+      let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+      if style.left != left || style.top != top {
+         style.left = left;
+         style.top = top;
+      } else if *visibility == Visibility::Hidden {
+         // the layout has applied the position
+         *visibility = Visibility::Inherited;
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use bevy_hierarchy::{BuildWorldChildren, Children};
+
+   use super::*;
+
+   fn open_menus(world: &mut World, count: usize) -> Vec<Entity> {
+      world.init_resource::<ContextMenuState>();
+      let mut origin = MenuOrigin::Pointer(Vec2::ZERO);
+      let menus = (0..count)
+         .map(|depth| {
+            let menu = world.spawn(ContextMenu { depth, origin }).id();
+            let item = world.spawn_empty().set_parent(menu).id();
+            origin = MenuOrigin::Item(item);
+            menu
+         })
+         .collect::<Vec<_>>();
+      world.resource_mut::<ContextMenuState>().menus = menus.clone();
+      menus
+   }
+
+   fn item_of(world: &World, menu: Entity) -> Entity {
+      world.get::<Children>(menu).unwrap()[0]
+   }
+
+   #[test]
+   fn closing_a_depth_closes_the_submenus() {
+      let mut world = World::new();
+      let menus = open_menus(&mut world, 3);
+      close_context_menus(&mut world, 1);
+      assert_eq!(world.resource::<ContextMenuState>().menus, menus[..1]);
+      assert!(world.get_entity(menus[1]).is_none() && world.get_entity(menus[2]).is_none());
+
+      close_context_menus(&mut world, 0);
+      assert!(world.resource::<ContextMenuState>().menus.is_empty());
+      assert!(world.get_entity(menus[0]).is_none());
+   }
+
+   #[test]
+   fn hovering_an_item_closes_the_other_submenus() {
+      let mut world = World::new();
+      let menus = open_menus(&mut world, 3);
+      // the submenu of the hovered item stays open
+      let item = item_of(&world, menus[0]);
+      hover_menu_item(&mut world, 0, item, None);
+      assert_eq!(world.resource::<ContextMenuState>().menus, menus);
+
+      let other_item = world.spawn_empty().set_parent(menus[0]).id();
+      hover_menu_item(&mut world, 0, other_item, None);
+      assert_eq!(world.resource::<ContextMenuState>().menus, menus[..1]);
+   }
+
+   #[test]
+   fn opens_towards_the_start_when_the_end_does_not_fit() {
+      let window_size = Vec2::new(800., 600.);
+      let size = Vec2::new(180., 100.);
+      let pointer = |position: Vec2| menu_position((position, position), size, window_size);
+      assert_eq!(pointer(Vec2::new(100., 100.)), Vec2::new(100., 100.));
+      assert_eq!(pointer(Vec2::new(700., 550.)), Vec2::new(520., 450.));
+
+      // a submenu opens on the right of the item, or on its left
+      let item = (Vec2::new(600., 220.), Vec2::new(780., 200.));
+      assert_eq!(
+         menu_position(item, size, window_size),
+         Vec2::new(420., 200.)
+      );
+   }
+}
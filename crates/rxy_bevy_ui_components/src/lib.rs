@@ -1,25 +1,89 @@
 use std::sync::Arc;
+use bevy_app::{App, Plugin};
 use bevy_ecs::prelude::Resource;
+use bevy_input::gamepad::{Gamepad, GamepadButton, GamepadButtonType};
+use bevy_input::keyboard::KeyCode;
 use bevy_reflect::Reflect;
 use bevy_render::prelude::Color;
 use rxy_ui::bevy::{BevyRenderer, x_res_once};
-use rxy_ui::prelude::ElementEventId;
-use rxy_ui::{x_world, MaybeReflect, MaybeSend, RendererWorld, XWorld};
+use rxy_ui::prelude::{x_just_pressed, x_pointer_click, ElementEventId, ElementEventIds};
+use rxy_ui::{MaybeSend, RendererWorld, XWorld};
 
+pub use accordion::*;
+pub use checkbox::*;
+pub use context_menu::*;
+pub use progress::*;
+pub use radio::*;
+pub use segmented_control::*;
+pub use select::*;
+pub use slider::*;
+pub use switch::*;
+pub use table::*;
+pub use tabs::*;
+pub use tooltip::*;
+pub use tree_view::*;
+
+mod accordion;
 mod checkbox;
-// mod select;
-// mod slider;
+mod context_menu;
+mod option_group;
+mod progress;
+mod radio;
+mod segmented_control;
+mod select;
+mod slider;
+mod switch;
+mod table;
+mod tabs;
+mod tooltip;
+mod tree_view;
+
+/// Adds the resources and systems of the components.
+pub struct UiComponentsPlugin;
+
+impl Plugin for UiComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiGlobalSetting>()
+            .add_plugins((TooltipPlugin, SelectPlugin, ContextMenuPlugin));
+    }
+}
 
 #[derive(Resource, Reflect)]
 pub struct UiGlobalSetting {
+    /// The events that confirm a component, e.g. toggle a checkbox or open a select.
     confirm_event_ids: Arc<Vec<ElementEventId>>,
 }
 
-#[derive(Resource, Reflect)]
-pub struct UiThemeSetting {
-    primary_color: Color,
+impl Default for UiGlobalSetting {
+    fn default() -> Self {
+        Self {
+            confirm_event_ids: Arc::new(
+                (
+                    x_just_pressed(KeyCode::Enter),
+                    x_just_pressed(GamepadButton::new(Gamepad::new(0), GamepadButtonType::South)),
+                    x_pointer_click(),
+                )
+                    .iter_event_ids()
+                    .collect(),
+            ),
+        }
+    }
 }
 
+#[allow(deprecated)]
+mod theme_setting {
+    use super::*;
+
+    #[deprecated(note = "use the `token::primary` style token and the `Theme` resource instead")]
+    #[derive(Resource, Reflect)]
+    pub struct UiThemeSetting {
+        pub primary_color: Color,
+    }
+}
+
+#[allow(deprecated)]
+pub use theme_setting::UiThemeSetting;
+
 macro_rules! define_x_res_fn {
     ($ident:ident,$ty:ty) => {
         #[inline]
@@ -37,6 +101,16 @@ macro_rules! define_x_res_fn {
     };
 }
 
+#[deprecated(note = "use the `token::primary` style token and the `Theme` resource instead")]
+#[allow(deprecated)]
+#[inline]
+pub fn x_theme_once<T, F>(
+    f: F,
+) -> XWorld<BevyRenderer, impl FnOnce(&mut RendererWorld<BevyRenderer>) -> T + MaybeSend + 'static>
+where
+    F: FnOnce(&UiThemeSetting) -> T + MaybeSend + 'static,
+{
+    x_res_once(|n: &UiThemeSetting| f(n))
+}
 
-define_x_res_fn!(x_theme_once,UiThemeSetting);
 define_x_res_fn!(x_ui_setting_once,UiGlobalSetting);
\ No newline at end of file
@@ -0,0 +1,243 @@
+use std::sync::Arc;
+
+use bevy_ecs::prelude::{Component, Entity, World};
+use bevy_hierarchy::{Children, Parent};
+use bevy_input::keyboard::KeyCode;
+use bevy_ui::{Display, Style};
+
+use rxy_ui::bevy::navigation::GamepadNavigationInput;
+use rxy_ui::bevy::{x_bundle, FocusVisible, Leaving, XBundle};
+use rxy_ui::prelude::*;
+
+/// The value of the options of a group, provided as a context.
+#[derive(Clone)]
+pub(crate) struct OptionGroupContext<T: Send + Sync + 'static> {
+   pub value_signal: RwSignal<T>,
+   pub readonly: ReadSignal<bool>,
+}
+
+/// The element containing the options navigated by [`x_option_keys`].
+#[derive(Component)]
+pub(crate) struct OptionGroupNode;
+
+/// An option of an [`OptionGroupNode`], the hidden and the leaving options are skipped.
+#[derive(Component)]
+pub(crate) struct OptionNode;
+
+/// Selects the option when it is focused with the keys.
+#[derive(Component, Clone)]
+pub(crate) struct SelectOnFocus(pub Arc<dyn Fn() + Send + Sync>);
+
+pub(crate) fn x_option() -> XBundle<OptionNode> {
+   x_bundle(OptionNode)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum OptionFocusMove {
+   Previous,
+   Next,
+   First,
+   Last,
+}
+
+/// Moves the focus to the previous or next option and to the first or last one with the home and end keys.
+pub(crate) fn x_option_keys(
+   previous: impl FocusInputEventIterator,
+   next: impl FocusInputEventIterator,
+   wrap: bool,
+) -> impl ViewMember<BevyRenderer> {
+   ().on_just_pressed(previous, move |world: &mut World| {
+      move_option_focus(world, OptionFocusMove::Previous, wrap);
+   })
+   .on_just_pressed(next, move |world: &mut World| {
+      move_option_focus(world, OptionFocusMove::Next, wrap);
+   })
+   .on_just_pressed(KeyCode::Home, move |world: &mut World| {
+      move_option_focus(world, OptionFocusMove::First, wrap);
+   })
+   .on_just_pressed(KeyCode::End, move |world: &mut World| {
+      move_option_focus(world, OptionFocusMove::Last, wrap);
+   })
+}
+
+fn move_option_focus(world: &mut World, to: OptionFocusMove, wrap: bool) {
+   // the directional navigation moves the focus with the arrow keys already
+   let arrow_keys_navigate = world
+      .get_resource::<GamepadNavigationInput>()
+      .is_some_and(|n| n.enabled && n.arrow_keys);
+   if arrow_keys_navigate && matches!(to, OptionFocusMove::Previous | OptionFocusMove::Next) {
+      return;
+   }
+   let Some(focus) = world.resource::<FocusedEntity>().0 else {
+      return;
+   };
+   let mut group = Some(focus);
+   while let Some(entity) = group {
+      if world.get::<OptionGroupNode>(entity).is_some() {
+         break;
+      }
+      group = world.get::<Parent>(entity).map(|n| n.get());
+   }
+   let Some(group) = group else {
+      return;
+   };
+
+   let mut options = vec![];
+   collect_shown_options(world, group, &mut options);
+   let len = options.len();
+   let current = options.iter().position(|n| *n == focus);
+   let index = match to {
+      OptionFocusMove::Previous => current.and_then(|n| match n.checked_sub(1) {
+         None if wrap => len.checked_sub(1),
+         n => n,
+      }),
+      OptionFocusMove::Next => current.and_then(|n| match n + 1 {
+         n if n < len => Some(n),
+         _ if wrap => Some(0),
+         _ => None,
+      }),
+      OptionFocusMove::First => (len > 0).then_some(0),
+      OptionFocusMove::Last => len.checked_sub(1),
+   };
+   if let Some(option) = index.map(|n| options[n]) {
+      focus_option(world, option);
+   }
+}
+
+/// Focuses the option as the keys do, it is selected too if it has a [`SelectOnFocus`].
+pub(crate) fn focus_option(world: &mut World, option: Entity) {
+   world.resource_mut::<FocusedEntity>().0 = Some(option);
+   world.resource_mut::<FocusVisible>().0 = true;
+   if let Some(select) = world.get::<SelectOnFocus>(option).cloned() {
+      (select.0)();
+   }
+}
+
+fn collect_shown_options(world: &World, entity: Entity, options: &mut Vec<Entity>) {
+   let Some(children) = world.get::<Children>(entity) else {
+      return;
+   };
+   for &child in children.iter() {
+      if world
+         .get::<Style>(child)
+         .is_some_and(|n| n.display == Display::None)
+         || world.get::<Leaving>(child).is_some()
+      {
+         continue;
+      }
+      if world.get::<OptionNode>(child).is_some() {
+         options.push(child);
+      } else if world.get::<OptionGroupNode>(child).is_none() {
+         collect_shown_options(world, child, options);
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use core::sync::atomic::{AtomicUsize, Ordering};
+
+   use bevy_hierarchy::BuildWorldChildren;
+
+   use super::*;
+
+   fn option_group(world: &mut World, hidden: &[usize]) -> Vec<Entity> {
+      world.init_resource::<FocusedEntity>();
+      world.init_resource::<FocusVisible>();
+      let group = world.spawn(OptionGroupNode).id();
+      (0..4)
+         .map(|index| {
+            let mut option = world.spawn(OptionNode);
+            option.set_parent(group);
+            if hidden.contains(&index) {
+               option.insert(Style {
+                  display: Display::None,
+                  ..Default::default()
+               });
+            }
+            option.id()
+         })
+         .collect()
+   }
+
+   fn move_from(world: &mut World, from: Entity, to: OptionFocusMove, wrap: bool) -> Entity {
+      world.resource_mut::<FocusedEntity>().0 = Some(from);
+      move_option_focus(world, to, wrap);
+      world.resource::<FocusedEntity>().0.unwrap()
+   }
+
+   #[test]
+   fn skips_the_hidden_options() {
+      let mut world = World::new();
+      let options = option_group(&mut world, &[1, 2]);
+      assert_eq!(
+         move_from(&mut world, options[0], OptionFocusMove::Next, false),
+         options[3]
+      );
+      assert_eq!(
+         move_from(&mut world, options[3], OptionFocusMove::Previous, false),
+         options[0]
+      );
+      assert!(world.resource::<FocusVisible>().0);
+   }
+
+   #[test]
+   fn wraps_and_jumps_to_the_ends() {
+      let mut world = World::new();
+      let options = option_group(&mut world, &[]);
+      assert_eq!(
+         move_from(&mut world, options[3], OptionFocusMove::Next, false),
+         options[3]
+      );
+      assert_eq!(
+         move_from(&mut world, options[3], OptionFocusMove::Next, true),
+         options[0]
+      );
+      assert_eq!(
+         move_from(&mut world, options[0], OptionFocusMove::Previous, true),
+         options[3]
+      );
+      assert_eq!(
+         move_from(&mut world, options[1], OptionFocusMove::Last, false),
+         options[3]
+      );
+      assert_eq!(
+         move_from(&mut world, options[2], OptionFocusMove::First, false),
+         options[0]
+      );
+   }
+
+   #[test]
+   fn selects_on_focus() {
+      let mut world = World::new();
+      let options = option_group(&mut world, &[]);
+      let selected = Arc::new(AtomicUsize::new(0));
+      let counter = selected.clone();
+      world
+         .entity_mut(options[1])
+         .insert(SelectOnFocus(Arc::new(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+         })));
+      move_from(&mut world, options[0], OptionFocusMove::Next, false);
+      assert_eq!(selected.load(Ordering::Relaxed), 1);
+   }
+
+   #[test]
+   fn arrow_keys_are_left_to_the_directional_navigation() {
+      let mut world = World::new();
+      let options = option_group(&mut world, &[]);
+      world.insert_resource(GamepadNavigationInput {
+         enabled: true,
+         arrow_keys: true,
+         ..Default::default()
+      });
+      assert_eq!(
+         move_from(&mut world, options[0], OptionFocusMove::Next, false),
+         options[0]
+      );
+      assert_eq!(
+         move_from(&mut world, options[0], OptionFocusMove::Last, false),
+         options[3]
+      );
+   }
+}
@@ -0,0 +1,160 @@
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+use std::time::Duration;
+
+use bevy_ui::Val;
+
+use rxy_ui::bevy::all_attrs::rotation;
+use rxy_ui::bevy::{x_animate, AttrKeyframes};
+use rxy_ui::prelude::*;
+
+#[derive(TypedStyle)]
+pub struct ProgressBarStyle;
+#[derive(TypedStyle)]
+pub struct ProgressBarFillStyle;
+
+#[derive(TypedStyle)]
+pub struct SpinnerStyle;
+#[derive(TypedStyle)]
+pub struct SpinnerIndicatorStyle;
+
+#[derive(TypedStyle)]
+pub struct RadialProgressStyle;
+#[derive(TypedStyle)]
+pub struct RadialProgressSegmentStyle;
+
+const SPINNER_SIZE: f32 = 24.;
+const SPINNER_INDICATOR_SIZE: f32 = 6.;
+const RADIAL_PROGRESS_SIZE: f32 = 48.;
+const RADIAL_PROGRESS_SEGMENTS: usize = 24;
+
+/// A horizontal bar filled from the left, the value goes from `0.0` to `1.0`.
+#[schema]
+pub fn schema_progress_bar(
+   mut ctx: SchemaCtx,
+   value: ReadSignal<f32>,
+) -> impl IntoElementView<BevyRenderer> {
+   ctx.default_typed_style(ProgressBarStyle, || {
+      x().h(8)
+         .min_w(150.)
+         .flex()
+         .bg_color(token::border)
+         .rounded_full()
+   });
+   ctx.default_typed_style(ProgressBarFillStyle, || {
+      x().h_full().bg_color(token::primary).rounded_full()
+   });
+
+   let width = use_memo(move |_| fill_width(value.get()));
+   div().name("progress-bar").style(ProgressBarStyle).children(
+      div()
+         .name("progress-bar-fill")
+         .style(ProgressBarFillStyle)
+         .w(width),
+   )
+}
+
+/// The width of the fill of a progress bar, the value is clamped.
+fn fill_width(value: f32) -> Val {
+   Val::Percent(value.clamp(0., 1.) * 100.)
+}
+
+/// An indeterminate indicator, a dot turning around a ring.
+#[schema]
+pub fn schema_spinner(mut ctx: SchemaCtx) -> impl IntoElementView<BevyRenderer> {
+   ctx.default_typed_style(SpinnerStyle, || {
+      x().size(SPINNER_SIZE)
+         .relative()
+         .border(3)
+         .border_color(token::border)
+         .rounded_full()
+   });
+   ctx.default_typed_style(SpinnerIndicatorStyle, || {
+      x().absolute()
+         .size(SPINNER_INDICATOR_SIZE)
+         .top(-3)
+         .left(Val::Percent(50.))
+         .ml(-SPINNER_INDICATOR_SIZE / 2.)
+         .bg_color(token::primary)
+         .rounded_full()
+   });
+
+   // a full turn can not be interpolated between two rotations, so it is split in quarters
+   let turn = AttrKeyframes::new(Duration::from_millis(900))
+      .track(
+         rotation,
+         [
+            (0., 0.),
+            (0.25, -FRAC_PI_2),
+            (0.5, -PI),
+            (0.75, -PI - FRAC_PI_2),
+            (1., -TAU),
+         ],
+      )
+      .looped();
+   div()
+      .name("spinner")
+      .style(SpinnerStyle)
+      .member(x_animate(turn))
+      .children(div().name("spinner-indicator").style(SpinnerIndicatorStyle))
+}
+
+/// The number of lit segments of a radial progress, rounded to the nearest segment.
+fn lit_segment_count(value: f32) -> usize {
+   (value.clamp(0., 1.) * RADIAL_PROGRESS_SEGMENTS as f32).round() as usize
+}
+
+/// A ring of segments lit clockwise from the top, the value goes from `0.0` to `1.0`.
+///
+/// Suits cooldowns, the content laid over the ring is up to the app.
+#[schema]
+pub fn schema_radial_progress(
+   mut ctx: SchemaCtx,
+   value: ReadSignal<f32>,
+) -> impl IntoElementView<BevyRenderer> {
+   ctx.default_typed_style(RadialProgressStyle, || {
+      x().size(RADIAL_PROGRESS_SIZE).relative()
+   });
+   ctx.default_typed_style(RadialProgressSegmentStyle, || {
+      (
+         x().w(3).h(8).bg_color(token::border),
+         x_checked().bg_color(token::primary),
+      )
+   });
+
+   let lit_segments = use_memo(move |_| lit_segment_count(value.get()));
+   let segments = (0..RADIAL_PROGRESS_SEGMENTS).map(move |i| {
+      let angle = i as f32 / RADIAL_PROGRESS_SEGMENTS as f32 * TAU;
+      // the arm covers the whole ring and is rotated around its center, the segment sits at its top
+      div()
+         .name("radial-progress-arm")
+         .style(x().absolute().w_full().h_full().flex().justify_center())
+         .rotation(-angle)
+         .children(
+            div()
+               .name("radial-progress-segment")
+               .style(RadialProgressSegmentStyle)
+               .checked(use_memo(move |_| i < lit_segments.get())),
+         )
+   });
+   div()
+      .name("radial-progress")
+      .style(RadialProgressStyle)
+      .children(x_iter(segments))
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn progress_values_are_clamped() {
+      assert_eq!(fill_width(0.25), Val::Percent(25.));
+      assert_eq!(fill_width(-1.), Val::Percent(0.));
+      assert_eq!(fill_width(2.), Val::Percent(100.));
+
+      assert_eq!(lit_segment_count(0.), 0);
+      assert_eq!(lit_segment_count(0.5), RADIAL_PROGRESS_SEGMENTS / 2);
+      assert_eq!(lit_segment_count(1. / 48.), 1);
+      assert_eq!(lit_segment_count(1.5), RADIAL_PROGRESS_SEGMENTS);
+   }
+}
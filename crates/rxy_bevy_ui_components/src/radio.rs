@@ -0,0 +1,137 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use bevy_input::keyboard::KeyCode;
+
+use rxy_ui::bevy::x_bundle;
+use rxy_ui::prelude::*;
+
+use crate::option_group::{
+   x_option, x_option_keys, OptionGroupContext, OptionGroupNode, SelectOnFocus,
+};
+use crate::x_ui_setting_once;
+
+#[derive(TypedStyle)]
+pub struct RadioGroupStyle;
+
+#[derive(TypedStyle)]
+pub struct RadioStyle;
+
+#[derive(TypedStyle)]
+pub struct RadioIndicatorStyle;
+
+/// Mutually exclusive [`radio`] options, the arrow keys move the focus to the previous or next option
+/// and select it.
+///
+/// ```ignore
+/// radio_group::<Difficulty>()
+///    .value(Difficulty::Normal)
+///    .slot_content((
+///       radio(Difficulty::Easy, "Easy"),
+///       radio(Difficulty::Normal, "Normal"),
+///       radio(Difficulty::Hard, "Hard"),
+///    ))
+/// ```
+#[derive(ElementSchema)]
+pub struct RadioGroup<T: Default + Debug + Send + Sync + PartialEq + Clone + 'static> {
+   ctx: SchemaCtx,
+   content: Slot,
+   value: ReadSignal<T>,
+   readonly: ReadSignal<bool>,
+   onchange: Sender<T>,
+}
+
+impl<T> SchemaElementView<BevyRenderer> for RadioGroup<T>
+where
+   T: Default + Debug + Send + Sync + PartialEq + Clone + 'static,
+{
+   fn view(self) -> impl IntoElementView<BevyRenderer> {
+      let RadioGroup {
+         mut ctx,
+         content,
+         value,
+         readonly,
+         onchange,
+      } = self;
+      let value_signal = ctx.use_controlled_state(value, onchange);
+
+      ctx.default_typed_style(RadioGroupStyle, || x().flex_col().gap(8));
+      ctx.default_typed_style(RadioStyle, || {
+         (
+            x().flex().items_center().gap(8).text_color(token::text),
+            x_focus_visible()
+               .outline_width(2)
+               .outline_offset(2)
+               .outline_color(token::primary),
+         )
+      });
+      ctx.default_typed_style(RadioIndicatorStyle, || {
+         (
+            x().size(20).border(2).border_color(token::border),
+            x_hover().border_color(token::primary),
+            x_checked()
+               .border_color(token::primary)
+               .bg_color(token::primary),
+         )
+      });
+
+      provide_context(
+         OptionGroupContext {
+            value_signal,
+            readonly,
+         },
+         div()
+            .name("radio-group")
+            .style(RadioGroupStyle)
+            .member(x_bundle(OptionGroupNode))
+            .children(content),
+      )
+   }
+}
+
+/// An option of a [`radio_group`].
+pub fn radio<T, IV>(value: T, label: IV) -> FnSchemaView<impl SchemaIntoViewFn<BevyRenderer>>
+where
+   T: Send + Sync + PartialEq + Clone + 'static,
+   IV: IntoView<BevyRenderer> + Send + 'static,
+{
+   fn_schema_view(move || {
+      view_builder(move |ctx, _| {
+         let OptionGroupContext {
+            value_signal,
+            readonly,
+         } = ctx.context::<OptionGroupContext<T>>();
+         let is_checked = use_memo({
+            let value = value.clone();
+            move |_| value_signal.get() == value
+         });
+         let select = move || {
+            if !readonly.get_untracked() {
+               value_signal.set(value.clone());
+            }
+         };
+
+         button()
+            .name("radio")
+            .style(RadioStyle)
+            .checked(is_checked)
+            .member(x_option())
+            .member(x_bundle(SelectOnFocus(Arc::new(select.clone()))))
+            .member(x_option_keys(
+               (KeyCode::ArrowUp, KeyCode::ArrowLeft),
+               (KeyCode::ArrowDown, KeyCode::ArrowRight),
+               true,
+            ))
+            .member(x_ui_setting_once(move |n| {
+               ().on(n.confirm_event_ids.clone(), select)
+            }))
+            .children((
+               div()
+                  .name("radio-indicator")
+                  .style(RadioIndicatorStyle)
+                  .checked(is_checked),
+               label,
+            ))
+      })
+   })
+}
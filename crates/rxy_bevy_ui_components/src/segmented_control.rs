@@ -0,0 +1,116 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use bevy_input::keyboard::KeyCode;
+
+use rxy_ui::bevy::x_bundle;
+use rxy_ui::prelude::*;
+
+use crate::option_group::{
+   x_option, x_option_keys, OptionGroupContext, OptionGroupNode, SelectOnFocus,
+};
+use crate::x_ui_setting_once;
+
+#[derive(TypedStyle)]
+pub struct SegmentedControlStyle;
+
+#[derive(TypedStyle)]
+pub struct SegmentStyle;
+
+/// A row of mutually exclusive [`segment`]s, the left and right arrow keys select the previous or next one.
+///
+/// ```ignore
+/// segmented_control::<&str>()
+///    .value("Low")
+///    .slot_content((segment("Low", "Low"), segment("High", "High")))
+/// ```
+#[derive(ElementSchema)]
+pub struct SegmentedControl<T: Default + Debug + Send + Sync + PartialEq + Clone + 'static> {
+   ctx: SchemaCtx,
+   content: Slot,
+   value: ReadSignal<T>,
+   readonly: ReadSignal<bool>,
+   onchange: Sender<T>,
+}
+
+impl<T> SchemaElementView<BevyRenderer> for SegmentedControl<T>
+where
+   T: Default + Debug + Send + Sync + PartialEq + Clone + 'static,
+{
+   fn view(self) -> impl IntoElementView<BevyRenderer> {
+      let SegmentedControl {
+         mut ctx,
+         content,
+         value,
+         readonly,
+         onchange,
+      } = self;
+      let value_signal = ctx.use_controlled_state(value, onchange);
+
+      ctx.default_typed_style(SegmentedControlStyle, || {
+         x().flex().p(2).gap(2).border(1).border_color(token::border)
+      });
+      ctx.default_typed_style(SegmentStyle, || {
+         (
+            x().center().px(12).py(6).text_color(token::text),
+            x_hover().bg_color(token::surface),
+            x_selected()
+               .bg_color(token::primary)
+               .text_color(token::background),
+            x_focus_visible()
+               .outline_width(2)
+               .outline_offset(2)
+               .outline_color(token::primary),
+         )
+      });
+
+      provide_context(
+         OptionGroupContext {
+            value_signal,
+            readonly,
+         },
+         div()
+            .name("segmented-control")
+            .style(SegmentedControlStyle)
+            .member(x_bundle(OptionGroupNode))
+            .children(content),
+      )
+   }
+}
+
+/// An option of a [`segmented_control`].
+pub fn segment<T, IV>(value: T, content: IV) -> FnSchemaView<impl SchemaIntoViewFn<BevyRenderer>>
+where
+   T: Send + Sync + PartialEq + Clone + 'static,
+   IV: IntoView<BevyRenderer> + Send + 'static,
+{
+   fn_schema_view(move || {
+      view_builder(move |ctx, _| {
+         let OptionGroupContext {
+            value_signal,
+            readonly,
+         } = ctx.context::<OptionGroupContext<T>>();
+         let is_selected = use_memo({
+            let value = value.clone();
+            move |_| value_signal.get() == value
+         });
+         let select = move || {
+            if !readonly.get_untracked() {
+               value_signal.set(value.clone());
+            }
+         };
+
+         button()
+            .name("segment")
+            .style(SegmentStyle)
+            .selected(is_selected)
+            .member(x_option())
+            .member(x_bundle(SelectOnFocus(Arc::new(select.clone()))))
+            .member(x_option_keys(KeyCode::ArrowLeft, KeyCode::ArrowRight, true))
+            .member(x_ui_setting_once(move |n| {
+               ().on(n.confirm_event_ids.clone(), select)
+            }))
+            .children(content)
+      })
+   })
+}
@@ -1,16 +1,29 @@
 use core::fmt::Display;
 use std::fmt::Debug;
 
-use bevy::prelude::*;
-use bevy::render::color::Color;
-use rxy_bevy::FocusedEntity;
-use rxy_bevy::RendererState;
-use rxy_core::{ElementAttr, fn_schema_view, NodeTree, RendererNodeId, SchemaElementView, XNest};
-use rxy_core::utils::SyncCell;
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::prelude::{Component, Entity, EventReader, Query, Res, ResMut};
+use bevy_hierarchy::{HierarchyQueryExt, Parent};
+use bevy_input::keyboard::KeyCode;
+use bevy_input::ButtonInput;
+use bevy_ui::{Display as NodeDisplay, Val};
+use bevy_window::ReceivedCharacter;
+
+use rxy_ui::bevy::x_bundle;
 use rxy_ui::prelude::*;
 
-use super::FocusStyle;
-use super::XConfirm;
+use crate::option_group::{x_option, x_option_keys, OptionGroupNode};
+use crate::x_ui_setting_once;
+
+/// Types the search query of an open searchable [`select`] and moves the focus back to the select
+/// when its options are closed.
+pub struct SelectPlugin;
+
+impl Plugin for SelectPlugin {
+   fn build(&self, app: &mut App) {
+      app.add_systems(Update, (select_search_input, update_select_focus));
+   }
+}
 
 #[derive(TypedStyle)]
 pub struct SelectStyle;
@@ -18,6 +31,30 @@ pub struct SelectStyle;
 #[derive(TypedStyle)]
 pub struct SelectSelectionListStyle;
 
+#[derive(TypedStyle)]
+pub struct SelectionGroupLabelStyle;
+
+#[derive(Component)]
+struct SelectState {
+   is_open: RwSignal<bool>,
+   query: RwSignal<String>,
+   searchable: ReadSignal<bool>,
+}
+
+/// A button showing the value, confirming it opens a [`selection_list`] of the content.
+///
+/// The options are navigated with the arrow, home and end keys, escape closes them.
+/// When `searchable`, typing filters the options by their text.
+///
+/// ```ignore
+/// select::<&str>()
+///    .value("One")
+///    .searchable(true)
+///    .slot_content((
+///       selection_item("One", |item| button().children(item.value)),
+///       selection_group("More", selection_item("Two", |item| button().children(item.value))),
+///    ))
+/// ```
 #[derive(ElementSchema)]
 pub struct Select<T>
 where
@@ -27,6 +64,7 @@ where
    content: CloneableSlot,
    value: ReadSignal<T>,
    readonly: ReadSignal<bool>,
+   searchable: ReadSignal<bool>,
    onchange: Sender<T>,
 }
 
@@ -41,73 +79,78 @@ where
          mut ctx,
          onchange,
          readonly,
+         searchable,
       } = self;
       let value = ctx.use_controlled_state(value, onchange);
       let is_open = use_rw_signal(false);
+      let query = use_rw_signal(String::new());
 
       ctx.default_typed_style(SelectStyle, || {
          (
             x().flex()
                .border(1)
-               .border_color(Color::WHITE)
+               .border_color(token::border)
                .center()
                .relative()
                .py(8)
                .min_w(150),
-            x_hover().bg_color(Color::DARK_GRAY),
-            FocusStyle,
+            x_hover().bg_color(token::surface),
+            x_focus_visible()
+               .outline_width(2)
+               .outline_offset(2)
+               .outline_color(token::primary),
          )
       });
       ctx.default_typed_style(SelectSelectionListStyle, || {
          x().absolute()
             .z(1)
             .top(Val::Percent(100.))
-            .bg_color(Color::GRAY)
+            .bg_color(token::surface)
             .w_full()
       });
-      let (id_sender, id_receiver) = oneshot::channel();
 
       button()
          .name("select")
          .style(SelectStyle)
+         .member(x_bundle(SelectState {
+            is_open,
+            query,
+            searchable,
+         }))
          .children((
             rx(move || {
-               format!("{}", value.get())
-                  .into_view()
-                  .text_color(Color::WHITE)
+               let query = query.get();
+               let text = if is_open.get() && !query.is_empty() {
+                  query
+               } else {
+                  value.get().to_string()
+               };
+               text.into_view().text_color(token::text)
             }),
             selection_list::<T>()
                .style(SelectSelectionListStyle)
                .slot_content(content)
                .visibility(is_open)
                .value(value)
+               .query(query)
                .onchange(move |new_value: T| {
                   is_open.set(false);
                   value.set(new_value);
                })
-               .on_build(|member_ctx: ViewMemberCtx<BevyRenderer>, _| {
-                  let _ = id_sender.send(member_ctx.node_id);
-               }),
+               // the clicks on the options do not toggle the select
+               .on(x_pointer_click().stop_propagation(), || {})
+               .rx_member(move || is_open.get().then(|| x_focus_scope().restore_focus(false))),
          ))
-         .on_build_after_children(move |ctx: ViewMemberCtx<BevyRenderer>, _| {
-            let select_entity = ctx.node_id;
-            let selection_list_entity = id_receiver.try_recv().unwrap();
-            rx(move || {
-               (!readonly.get()).then_some(().on(
-                  XConfirm,
-                  move |query: Query<&RendererState<Context<SelectionListContext<T>>>>,
-                        cmd_sender: Res<CmdSender>| {
-                     is_open.update(|is_open| *is_open = !*is_open);
-                     let selection_list_ctx = &query.get(selection_list_entity).unwrap().0 .0;
-                     if let Some(selected_entity) = selection_list_ctx.selected_entity {
-                        cmd_sender.add(move |world: &mut World| {
-                           let mut focused_entity = world.resource_mut::<FocusedEntity>();
-                           focused_entity.0 = Some(selected_entity);
-                        })
-                     }
-                  },
-               ))
-            })
+         .on_esc(move || {
+            is_open.set(false);
+         })
+         .rx_member(move || {
+            readonly.not_then_some(x_ui_setting_once(move |n| {
+               ().on(n.confirm_event_ids.clone(), move || {
+                  query.set(String::new());
+                  is_open.update(|is_open| *is_open = !*is_open);
+               })
+            }))
          })
    }
 }
@@ -115,15 +158,18 @@ where
 #[derive(Clone)]
 pub struct SelectionListContext<T: Send + Sync + 'static> {
    value_signal: RwSignal<T>,
-   selected_entity: Option<RendererNodeId<BevyRenderer>>,
+   query: ReadSignal<String>,
+   readonly: ReadSignal<bool>,
 }
 
+/// A list of [`selection_item`]s, the items that do not contain the `query` are hidden.
 #[derive(ElementSchema)]
 pub struct SelectionList<T: Default + Debug + Send + Sync + PartialEq + Clone + 'static> {
    ctx: SchemaCtx,
    content: Slot,
    value: ReadSignal<T>,
    readonly: ReadSignal<bool>,
+   query: ReadSignal<String>,
    onchange: Sender<T>,
 }
 
@@ -137,19 +183,48 @@ where
          value,
          onchange,
          content,
-         ..
+         readonly,
+         query,
       } = self;
       let value_signal = ctx.use_controlled_state(value, onchange);
       provide_context(
          SelectionListContext {
             value_signal,
-            selected_entity: None,
+            query,
+            readonly,
          },
-         div().style(x().flex_col().py(4)).children(content),
+         div()
+            .name("selection-list")
+            .style(x().flex_col().py(4))
+            .member(x_bundle(OptionGroupNode))
+            .children(content),
       )
    }
 }
 
+/// A labeled group of options in a [`selection_list`].
+#[schema]
+pub fn schema_selection_group(
+   mut ctx: SchemaCtx,
+   Required(label): Required<Slot>,
+   Required(content): Required<Slot>,
+) -> impl IntoElementView<BevyRenderer> {
+   ctx.default_typed_style(SelectionGroupLabelStyle, || {
+      x().px(8).py(4).text_color(token::text_muted)
+   });
+
+   div()
+      .name("selection-group")
+      .style(x().flex_col())
+      .children((
+         div()
+            .name("selection-group-label")
+            .style(SelectionGroupLabelStyle)
+            .children(label),
+         content,
+      ))
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SelectionItem<T> {
    pub value: T,
@@ -161,54 +236,133 @@ pub fn selection_item<T, V>(
    f: impl Fn(SelectionItem<T>) -> V + Send + 'static,
 ) -> FnSchemaView<impl SchemaIntoViewFn<BevyRenderer>>
 where
-   T: Default + Send + Sync + PartialEq + Clone + 'static,
+   T: Default + Display + Send + Sync + PartialEq + Clone + 'static,
    V: IntoElementView<BevyRenderer> + Send,
 {
    fn_schema_view(move || {
       view_builder(|ctx, _| {
-         let parent = ctx.parent;
-         let selection_list = ctx.context::<SelectionListContext<T>>();
+         let SelectionListContext {
+            value_signal,
+            query,
+            readonly,
+         } = ctx.context::<SelectionListContext<T>>();
          let is_selected = use_memo({
             let value = value.clone();
-            let value_signal = selection_list.value_signal;
             move |_| value_signal.get() == value
          });
+         let text = value.to_string().to_lowercase();
 
          rx(move || {
+            let is_selected = is_selected.get();
             let element_view = f(SelectionItem {
                value: value.clone(),
-               is_selected: is_selected.get(),
+               is_selected,
             })
             .into_element_view();
+            let value = value.clone();
+            let text = text.clone();
             into_view(
                element_view
+                  .selected(is_selected)
+                  .display(rx(move || {
+                     if text.contains(&query.get().to_lowercase()) {
+                        NodeDisplay::Flex
+                     } else {
+                        NodeDisplay::None
+                     }
+                  }))
+                  .member(x_option())
+                  .rx_member(move || is_selected.then(x_initial_focus))
+                  .member(x_option_keys(KeyCode::ArrowUp, KeyCode::ArrowDown, false))
+                  .on_esc(close_select)
                   .rx_member(move || {
-                     is_selected.get().then_some(member_builder(
-                        move |member_ctx: ViewMemberCtx<BevyRenderer>, _| {
-                           if let Some(selection_list) = member_ctx
-                              .world
-                              .get_node_state_mut::<Context<SelectionListContext<T>>>(&parent)
-                           {
-                              selection_list.0.selected_entity = Some(member_ctx.node_id);
-                              println!("selected entity: {:?}", member_ctx.node_id);
-                           }
-                        },
-                     ))
-                  })
-                  .on(XConfirm, {
-                     let value_signal = selection_list.value_signal;
                      let value = value.clone();
-                     move |cmd_sender: Res<CmdSender>| {
-                        value_signal.set(value.clone());
-                        cmd_sender.add(move |world: &mut World| {
-                           let select_entity = world.get::<Parent>(parent).unwrap().get();
-                           let mut focused_entity = world.resource_mut::<FocusedEntity>();
-                           focused_entity.0 = Some(select_entity);
+                     readonly.not_then_some(x_ui_setting_once(move |n| {
+                        ().on(n.confirm_event_ids.clone(), move || {
+                           value_signal.set(value.clone());
                         })
-                     }
+                     }))
                   }),
             )
          })
       })
    })
 }
+
+fn close_select(focus: Res<FocusedEntity>, selects: Query<&SelectState>, parents: Query<&Parent>) {
+   let Some(focus) = focus.0 else {
+      return;
+   };
+   if let Some(state) = parents
+      .iter_ancestors(focus)
+      .find_map(|n| selects.get(n).ok())
+   {
+      state.is_open.try_set(false);
+   }
+}
+
+fn select_search_input(
+   mut chars: EventReader<ReceivedCharacter>,
+   keys: Res<ButtonInput<KeyCode>>,
+   focus: Res<FocusedEntity>,
+   selects: Query<&SelectState>,
+   parents: Query<&Parent>,
+) {
+   let typed = chars
+      .read()
+      .flat_map(|n| n.char.chars())
+      .filter(|n| !n.is_control())
+      .collect::<String>();
+   let backspace = keys.just_pressed(KeyCode::Backspace);
+   if typed.is_empty() && !backspace {
+      return;
+   }
+   let Some(focus) = focus.0 else {
+      return;
+   };
+   let Some(state) = selects.get(focus).ok().or_else(|| {
+      parents
+         .iter_ancestors(focus)
+         .find_map(|n| selects.get(n).ok())
+   }) else {
+      return;
+   };
+   if state.is_open.try_get_untracked() != Some(true)
+      || state.searchable.try_get_untracked() != Some(true)
+   {
+      return;
+   }
+   state.query.try_update(|query| {
+      if backspace {
+         query.pop();
+      }
+      query.push_str(&typed);
+   });
+}
+
+fn update_select_focus(
+   mut focus: ResMut<FocusedEntity>,
+   selects: Query<(Entity, &SelectState)>,
+   parents: Query<&Parent>,
+) {
+   let Some(focused) = focus.0 else {
+      return;
+   };
+   for (entity, state) in selects.iter() {
+      if focused == entity {
+         continue;
+      }
+      let focus_within = parents.iter_ancestors(focused).any(|n| n == entity);
+      match state.is_open.try_get_untracked() {
+         // the focus has left the options
+         Some(true) if !focus_within => {
+            state.is_open.try_set(false);
+         }
+         // the options have been closed
+         Some(false) if focus_within => {
+            focus.0 = Some(entity);
+         }
+         _ => {}
+      }
+   }
+}
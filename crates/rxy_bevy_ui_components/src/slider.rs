@@ -1,14 +1,15 @@
-use std::fmt::Debug;
-
-use bevy::{
-    app::AppExit,
-    prelude::{Color, Res},
-};
-use bevy::prelude::*;
-use bevy_mod_picking::prelude::On;
-use rxy_ui::prelude::*;
+use std::ops::RangeInclusive;
+
+use bevy_ecs::prelude::{Query, Res};
+use bevy_hierarchy::Parent;
+use bevy_input::keyboard::KeyCode;
+use bevy_ui::{Node, Val};
 
-use super::{COLOR_PRIMARY, FocusStyle, XConfirm};
+use rxy_ui::bevy::navigation::NavigationTargets;
+use rxy_ui::bevy::x_bundle;
+use rxy_ui::prelude::*;
+use rxy_ui::style::StyleSheets;
+use rxy_ui::XValueWrapper;
 
 #[derive(TypedStyle)]
 pub struct SliderRootStyle;
@@ -19,93 +20,353 @@ pub struct SliderIndicatorStyle;
 #[derive(TypedStyle)]
 pub struct SliderThumbStyle;
 
+#[derive(TypedStyle)]
+pub struct VerticalSliderRootStyle;
+#[derive(TypedStyle)]
+pub struct VerticalSliderBgStyle;
+#[derive(TypedStyle)]
+pub struct VerticalSliderIndicatorStyle;
+#[derive(TypedStyle)]
+pub struct VerticalSliderThumbStyle;
+
+const TRACK_SIZE: f32 = 10.;
+const THUMB_SIZE: f32 = 26.;
+const INDICATOR_SIZE: f32 = 40.;
+const INDICATOR_SPACING: f32 = 6.;
+
+/// The values a slider can take, `0.0..=1.0` by default.
+#[derive(PropValueWrapper, Copy, Clone, Debug, PartialEq)]
+pub struct SliderRange {
+   pub min: f32,
+   pub max: f32,
+}
+
+impl SliderRange {
+   pub fn new(min: f32, max: f32) -> Self {
+      Self { min, max }
+   }
+
+   pub fn len(&self) -> f32 {
+      (self.max - self.min).max(0.)
+   }
+
+   pub fn is_empty(&self) -> bool {
+      self.len() == 0.
+   }
+
+   /// Where the value is within the range, from `0.0` to `1.0`.
+   pub fn fraction(&self, value: f32) -> f32 {
+      if self.is_empty() {
+         return 0.;
+      }
+      ((value - self.min) / self.len()).clamp(0., 1.)
+   }
+
+   /// Clamps the value to the range and rounds it to the nearest step from `min`, a `step` of `0` keeps it continuous.
+   pub fn snap(&self, value: f32, step: f32) -> f32 {
+      let value = value.clamp(self.min, self.max.max(self.min));
+      if step <= 0. {
+         return value;
+      }
+      (self.min + ((value - self.min) / step).round() * step).min(self.max.max(self.min))
+   }
+}
+
+impl Default for SliderRange {
+   fn default() -> Self {
+      Self::new(0., 1.)
+   }
+}
+
+impl From<RangeInclusive<f32>> for SliderRange {
+   fn from(range: RangeInclusive<f32>) -> Self {
+      Self::new(*range.start(), *range.end())
+   }
+}
+
+/// A horizontal slider, the thumb is dragged or moved with the arrow, page and home/end keys when focused.
+///
+/// ```ignore
+/// slider().range(SliderRange::new(0., 100.)).step(5.).value(50.)
+/// ```
 #[schema]
 pub fn schema_slider(
    mut ctx: SchemaCtx,
    value: ReadSignal<f32>,
+   range: ReadSignal<SliderRange>,
+   step: ReadSignal<f32>,
    readonly: ReadSignal<bool>,
    onchange: Sender<f32>,
 ) -> impl IntoElementView<BevyRenderer> {
-   let value = ctx.use_controlled_state(value, onchange);
-   let width = use_memo(move |_| Val::Percent(value.get() * 100.0));
-   let is_drag = use_rw_signal(false);
+   ctx.default_typed_style(SliderRootStyle, || {
+      x().h(TRACK_SIZE)
+         .flex()
+         .min_w(150.)
+         .bg_color(token::border)
+         .relative()
+         .items_center()
+   });
+   ctx.default_typed_style(SliderBgStyle, || {
+      x().h_full()
+         .bg_color(token::primary)
+         .absolute()
+         .left(0)
+         .top(0)
+   });
+   ctx.default_typed_style(SliderIndicatorStyle, || {
+      x().absolute()
+         .w(INDICATOR_SIZE)
+         .left((THUMB_SIZE - INDICATOR_SIZE) / 2.)
+         .bottom(Val::Percent(100.))
+         .mb(INDICATOR_SPACING)
+         .center()
+   });
+   ctx.default_typed_style(SliderThumbStyle, || {
+      (
+         x().bg_color(token::surface)
+            .border(2)
+            .border_color(token::primary)
+            .size(THUMB_SIZE)
+            .top((TRACK_SIZE - THUMB_SIZE) / 2.)
+            .ml(-THUMB_SIZE / 2.)
+            .absolute(),
+         x_hover().bg_color(token::primary),
+         x_focus_visible()
+            .outline_width(2)
+            .outline_offset(2)
+            .outline_color(token::primary),
+      )
+   });
 
-   {
-      let height = 10.;
+   slider_view(
+      ctx,
+      false,
+      (value, range, step, readonly, onchange),
+      (
+         SliderRootStyle,
+         SliderBgStyle,
+         SliderIndicatorStyle,
+         SliderThumbStyle,
+      ),
+   )
+}
 
-      let indicator_height = 30.;
-      let indicator_width = 30.;
-      let indicator_spacing = 20.;
+/// Like [`slider`], the value grows from the bottom to the top.
+#[schema]
+pub fn schema_vertical_slider(
+   mut ctx: SchemaCtx,
+   value: ReadSignal<f32>,
+   range: ReadSignal<SliderRange>,
+   step: ReadSignal<f32>,
+   readonly: ReadSignal<bool>,
+   onchange: Sender<f32>,
+) -> impl IntoElementView<BevyRenderer> {
+   ctx.default_typed_style(VerticalSliderRootStyle, || {
+      x().w(TRACK_SIZE)
+         .flex()
+         .min_h(150.)
+         .bg_color(token::border)
+         .relative()
+         .justify_center()
+   });
+   ctx.default_typed_style(VerticalSliderBgStyle, || {
+      x().w_full()
+         .bg_color(token::primary)
+         .absolute()
+         .left(0)
+         .bottom(Val::Px(0.))
+   });
+   ctx.default_typed_style(VerticalSliderIndicatorStyle, || {
+      x().absolute()
+         .min_w(INDICATOR_SIZE)
+         .h_full()
+         .left(Val::Percent(100.))
+         .ml(INDICATOR_SPACING)
+         .center()
+   });
+   ctx.default_typed_style(VerticalSliderThumbStyle, || {
+      (
+         x().bg_color(token::surface)
+            .border(2)
+            .border_color(token::primary)
+            .size(THUMB_SIZE)
+            .ml((TRACK_SIZE - THUMB_SIZE) / 2.)
+            .mb(-THUMB_SIZE / 2.)
+            .absolute(),
+         x_hover().bg_color(token::primary),
+         x_focus_visible()
+            .outline_width(2)
+            .outline_offset(2)
+            .outline_color(token::primary),
+      )
+   });
 
-      let thumb_height = 26.;
-      let thumb_width = 26.;
+   slider_view(
+      ctx,
+      true,
+      (value, range, step, readonly, onchange),
+      (
+         VerticalSliderRootStyle,
+         VerticalSliderBgStyle,
+         VerticalSliderIndicatorStyle,
+         VerticalSliderThumbStyle,
+      ),
+   )
+}
 
-      ctx.default_typed_style(SliderRootStyle, || {
-         x().h(height)
-            .flex()
-            .min_w(150.)
-            .bg_color(Color::WHITE)
-            .relative()
-            .items_center()
-      });
-      ctx.default_typed_style(SliderBgStyle, || {
-         x().h_full()
-            .bg_color(COLOR_PRIMARY)
-            .absolute()
-            .left(0)
-            .top(0)
-      });
-      ctx.default_typed_style(SliderIndicatorStyle, || {
-         x().h(indicator_height)
-            .w(indicator_width)
-            .ml(-indicator_width / 2.0)
-            .center()
-            .top(-(indicator_height + thumb_height) / 2.0 - indicator_spacing)
-      });
-      ctx.default_typed_style(SliderThumbStyle, || {
-         (
-            x().bg_color(Color::DARK_GRAY)
-               .h(thumb_height)
-               .w(thumb_width)
-               .top(-8)
-               .ml(-thumb_width / 2.0)
-               .absolute(),
-            x_hover().bg_color(Color::GRAY),
-            FocusStyle,
-         )
-      });
-   }
+type SliderProps = (
+   ReadSignal<f32>,
+   ReadSignal<SliderRange>,
+   ReadSignal<f32>,
+   ReadSignal<bool>,
+   Sender<f32>,
+);
 
-   div().name("slider").style(SliderRootStyle).children((
-      div().name("slider-bg").style(SliderBgStyle).width(width),
-      x_if(
+fn slider_view<RS, BS, IS, TS>(
+   mut ctx: SchemaCtx,
+   vertical: bool,
+   (value, range, step, readonly, onchange): SliderProps,
+   (root_style, bg_style, indicator_style, thumb_style): (RS, BS, IS, TS),
+) -> impl IntoElementView<BevyRenderer>
+where
+   RS: StyleSheets<BevyRenderer> + Into<XValueWrapper<RS>>,
+   BS: StyleSheets<BevyRenderer> + Into<XValueWrapper<BS>>,
+   IS: StyleSheets<BevyRenderer> + Into<XValueWrapper<IS>>,
+   TS: StyleSheets<BevyRenderer> + Into<XValueWrapper<TS>>,
+{
+   let value = ctx.use_controlled_state(value, onchange);
+   let percent = use_memo(move |_| Val::Percent(range.get().fraction(value.get()) * 100.));
+   // only the position along the axis is inline, the styles place the thumb on the other axis
+   let (fill_width, fill_height, thumb_left, thumb_bottom) = if vertical {
+      (
+         use_memo(|_| Val::Percent(100.)),
+         percent,
+         use_memo(|_| Val::Px(0.)),
+         percent,
+      )
+   } else {
+      (
+         percent,
+         use_memo(|_| Val::Percent(100.)),
+         percent,
+         use_memo(|_| Val::Auto),
+      )
+   };
+   let is_drag = use_rw_signal(false);
+   // the dragged value before it is snapped to the step
+   let drag_value = use_rw_signal(0.);
+
+   let change_by = move |steps: f32| {
+      let range = range.get_untracked();
+      let step = step.get_untracked();
+      let step_len = if step > 0. { step } else { range.len() / 100. };
+      value.update(|value| *value = range.snap(*value + steps * step_len, step));
+   };
+   let (increase_keys, decrease_keys) = if vertical {
+      (KeyCode::ArrowUp, KeyCode::ArrowDown)
+   } else {
+      (KeyCode::ArrowRight, KeyCode::ArrowLeft)
+   };
+
+   let thumb = button()
+      .name("slider-thumb")
+      .style(thumb_style)
+      .children(x_if(
          is_drag,
          view_builder(move |_, _| {
             div()
                .name("slider-indicator")
-               .style(SliderIndicatorStyle)
-               .left(width)
-               .children(rx(move || format!("{:.1}", value.get())))
+               .style(indicator_style)
+               .children(rx(move || {
+                  let step = step.get();
+                  let precision = if step > 0. && step.fract() == 0. {
+                     0
+                  } else {
+                     2
+                  };
+                  format!("{:.*}", precision, value.get())
+               }))
          }),
-      ),
-      button()
-         .name("slider-thumb")
-         .style(SliderThumbStyle)
-         .left(width)
-         .rx_member(move || {
-            (!readonly.get()).then_some(
-               ().on_pointer_drag(move |e: Res<ListenerInputPointerDrag>| {
-                  value.update(|value| {
-                     *value = (*value + e.delta.x / 150.0).clamp(0.0, 1.0);
-                  });
+      ))
+      .rx_member(move || {
+         readonly.not_then_some((
+            // keeps the directional navigation from leaving the slider along its axis
+            member_builder(move |ctx: ViewMemberCtx<BevyRenderer>, _| {
+               let thumb = ctx.node_id;
+               x_bundle(if vertical {
+                  NavigationTargets::default().up(thumb).down(thumb)
+               } else {
+                  NavigationTargets::default().left(thumb).right(thumb)
                })
-               .on_pointer_drag_start(move || {
-                  is_drag.set(true);
+            }),
+            ().on_just_pressed(increase_keys, move || change_by(1.))
+               .on_just_pressed(decrease_keys, move || change_by(-1.))
+               .on_just_pressed(KeyCode::PageUp, move || change_by(10.))
+               .on_just_pressed(KeyCode::PageDown, move || change_by(-10.))
+               .on_just_pressed(KeyCode::Home, move || {
+                  value.set(range.get_untracked().min);
                })
-               .on_pointer_drag_end(move || {
-                  is_drag.set(false);
+               .on_just_pressed(KeyCode::End, move || {
+                  value.set(range.get_untracked().max);
                }),
+            ().on_pointer_drag_start(move || {
+               drag_value.set(value.get_untracked());
+               is_drag.set(true);
+            })
+            .on_pointer_drag(
+               move |e: Res<ListenerInputPointerDrag>,
+                     parents: Query<&Parent>,
+                     nodes: Query<&Node>| {
+                  let Some(track) = parents
+                     .get(e.listener())
+                     .ok()
+                     .and_then(|parent| nodes.get(parent.get()).ok())
+                  else {
+                     return;
+                  };
+                  let (delta, len) = if vertical {
+                     (-e.delta.y, track.size().y)
+                  } else {
+                     (e.delta.x, track.size().x)
+                  };
+                  if len <= 0. {
+                     return;
+                  }
+                  let range = range.get_untracked();
+                  drag_value.update(|drag_value| {
+                     *drag_value =
+                        (*drag_value + delta / len * range.len()).clamp(range.min, range.max);
+                  });
+                  value.set(range.snap(drag_value.get_untracked(), step.get_untracked()));
+               },
             )
-         }),
+            .on_pointer_drag_end(move || {
+               is_drag.set(false);
+            }),
+         ))
+      });
+
+   div().name("slider").style(root_style).children((
+      div()
+         .name("slider-bg")
+         .style(bg_style)
+         .width(fill_width)
+         .height(fill_height),
+      thumb.left(thumb_left).bottom(thumb_bottom),
    ))
 }
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn snap_to_step() {
+      let range = SliderRange::new(10., 20.);
+      assert_eq!(range.snap(13.4, 2.), 14.);
+      assert_eq!(range.snap(13.4, 0.), 13.4);
+      assert_eq!(range.snap(25., 3.), 19.);
+      assert_eq!(range.snap(5., 3.), 10.);
+      assert_eq!(range.fraction(15.), 0.5);
+   }
+}
@@ -0,0 +1,57 @@
+use rxy_ui::prelude::*;
+
+use crate::x_ui_setting_once;
+
+#[derive(TypedStyle)]
+pub struct SwitchStyle;
+
+#[derive(TypedStyle)]
+pub struct SwitchThumbStyle;
+
+/// A toggle switch, the thumb moves to the end of the track when checked.
+#[schema]
+pub fn schema_switch(
+   mut ctx: SchemaCtx,
+   value: ReadSignal<bool>,
+   readonly: ReadSignal<bool>,
+   onchange: Sender<bool>,
+) -> impl IntoElementView<BevyRenderer> {
+   let is_checked = ctx.use_controlled_state(value, onchange);
+   ctx.default_typed_style(SwitchStyle, || {
+      (
+         x().flex()
+            .items_center()
+            .w(44)
+            .h(24)
+            .p(2)
+            .bg_color(token::border),
+         x_hover().bg_color(token::text_muted),
+         x_checked().bg_color(token::primary),
+         x_focus_visible()
+            .outline_width(2)
+            .outline_offset(2)
+            .outline_color(token::primary),
+      )
+   });
+   ctx.default_typed_style(SwitchThumbStyle, || {
+      (x().size(20).bg_color(token::surface), x_checked().ml(20))
+   });
+
+   button()
+      .name("switch")
+      .style(SwitchStyle)
+      .checked(rx(move || is_checked.get()))
+      .children(
+         div()
+            .name("switch-thumb")
+            .style(SwitchThumbStyle)
+            .checked(rx(move || is_checked.get())),
+      )
+      .rx_member(move || {
+         readonly.not_then_some(x_ui_setting_once(move |n| {
+            ().on(n.confirm_event_ids.clone(), move || {
+               is_checked.update(|is_checked| *is_checked = !*is_checked);
+            })
+         }))
+      })
+}
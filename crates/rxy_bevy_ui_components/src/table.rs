@@ -0,0 +1,420 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use bevy_ecs::prelude::Res;
+use bevy_input::keyboard::KeyCode;
+use bevy_ui::{OverflowAxis, RepeatedGridTrack};
+
+use rxy_ui::bevy::x_bundle;
+use rxy_ui::prelude::*;
+
+use crate::option_group::{x_option, x_option_keys, OptionGroupNode};
+use crate::x_ui_setting_once;
+
+#[derive(TypedStyle)]
+pub struct TableStyle;
+#[derive(TypedStyle)]
+pub struct TableHeaderStyle;
+#[derive(TypedStyle)]
+pub struct TableHeaderCellStyle;
+#[derive(TypedStyle)]
+pub struct TableResizeHandleStyle;
+#[derive(TypedStyle)]
+pub struct TableRowStyle;
+#[derive(TypedStyle)]
+pub struct TableCellStyle;
+
+const DEFAULT_COLUMN_WIDTH: f32 = 120.;
+const DEFAULT_COLUMN_MIN_WIDTH: f32 = 40.;
+
+type CellViewFn<T> = Arc<dyn Fn(&T) -> BoxedDynamicView<BevyRenderer> + Send + Sync>;
+type RowCompareFn<T> = Arc<dyn Fn(&T, &T) -> Ordering + Send + Sync>;
+
+/// A column of a [`table`], see [`table_column`].
+pub struct TableColumn<T> {
+   header: BoxedDynamicView<BevyRenderer>,
+   cell: CellViewFn<T>,
+   compare: Option<RowCompareFn<T>>,
+   width: f32,
+   min_width: f32,
+}
+
+/// A column with the header view, the cell of a row is built by the cell function.
+pub fn table_column<T, IV>(
+   header: impl IntoView<BevyRenderer>,
+   cell: impl Fn(&T) -> IV + Send + Sync + 'static,
+) -> TableColumn<T>
+where
+   IV: IntoView<BevyRenderer>,
+{
+   TableColumn {
+      header: header.into_dynamic(),
+      cell: Arc::new(move |row| cell(row).into_dynamic()),
+      compare: None,
+      width: DEFAULT_COLUMN_WIDTH,
+      min_width: DEFAULT_COLUMN_MIN_WIDTH,
+   }
+}
+
+impl<T> TableColumn<T> {
+   /// The initial width in logical pixels.
+   pub fn width(mut self, width: f32) -> Self {
+      self.width = width;
+      self
+   }
+
+   /// The width the column can not be resized below.
+   pub fn min_width(mut self, min_width: f32) -> Self {
+      self.min_width = min_width;
+      self
+   }
+
+   /// Makes the column sortable, the rows are compared in ascending order.
+   pub fn sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering + Send + Sync + 'static) -> Self {
+      self.compare = Some(Arc::new(compare));
+      self
+   }
+
+   /// Makes the column sortable by the key of the rows.
+   pub fn sort_by_key<SK: Ord>(self, key: impl Fn(&T) -> SK + Send + Sync + 'static) -> Self {
+      self.sort_by(move |a, b| key(a).cmp(&key(b)))
+   }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct TableSort {
+   column: usize,
+   descending: bool,
+}
+
+/// Rows of keyed values laid out in a grid with a [`table_column`] per column.
+///
+/// Confirming the header of a sortable column sorts the rows ascending, then descending, then back
+/// to their order. The rows are keyed, so sorting moves them instead of building them again.
+/// Dragging the right edge of a header resizes its column.
+///
+/// Confirming a row selects it, with `multiple` it toggles the row in the selection instead.
+/// Only the rows whose selection changes are updated.
+///
+/// ```ignore
+/// table::<u32, Player>()
+///    .columns(vec![
+///       table_column("Name", |player: &Player| player.name.clone())
+///          .sort_by_key(|player| player.name.clone()),
+///       table_column("Score", |player: &Player| player.score.to_string())
+///          .sort_by_key(|player| player.score)
+///          .width(80.),
+///    ])
+///    .rows(players.iter().map(|player| (player.id, player.clone())).collect::<Vec<_>>())
+///    .multiple(true)
+///    .onchange(selected_sender)
+/// ```
+#[derive(ElementSchema)]
+pub struct Table<K, T>
+where
+   K: Debug + Hash + Eq + Clone + Send + Sync + 'static,
+   T: Clone + Send + Sync + 'static,
+{
+   ctx: SchemaCtx,
+   columns: Static<Vec<TableColumn<T>>>,
+   rows: ReadSignal<Vec<(K, T)>>,
+   multiple: ReadSignal<bool>,
+   selected: ReadSignal<Vec<K>>,
+   onchange: Sender<Vec<K>>,
+}
+
+impl<K, T> SchemaElementView<BevyRenderer> for Table<K, T>
+where
+   K: Debug + Hash + Eq + Clone + Send + Sync + 'static,
+   T: Clone + Send + Sync + 'static,
+{
+   fn view(self) -> impl IntoElementView<BevyRenderer> {
+      let Table {
+         mut ctx,
+         columns: Static(columns),
+         rows,
+         multiple,
+         selected,
+         onchange,
+      } = self;
+      let selected = ctx.use_controlled_state(selected, onchange);
+
+      ctx.default_typed_style(TableStyle, || {
+         x().flex_col().border(1).border_color(token::border)
+      });
+      ctx.default_typed_style(TableHeaderStyle, || {
+         x().grid()
+            .bg_color(token::surface)
+            .border_bottom(1)
+            .border_color(token::border)
+      });
+      ctx.default_typed_style(TableHeaderCellStyle, || {
+         (
+            x().relative()
+               .flex()
+               .items_center()
+               .px(8)
+               .py(6)
+               .text_color(token::text_muted),
+            x_hover().text_color(token::text),
+            x_selected().text_color(token::text),
+            x_focus_visible()
+               .outline_width(2)
+               .outline_color(token::primary),
+         )
+      });
+      ctx.default_typed_style(TableResizeHandleStyle, || {
+         (
+            x().absolute().top(0).bottom(0).right(-3).w(6).z(1),
+            x_hover().bg_color(token::primary),
+         )
+      });
+      ctx.default_typed_style(TableRowStyle, || {
+         (
+            x().grid().text_color(token::text),
+            x_hover().bg_color(token::surface),
+            x_selected().bg_color(token::background),
+            x_focus_visible()
+               .outline_width(2)
+               .outline_color(token::primary),
+         )
+      });
+      ctx.default_typed_style(TableCellStyle, || {
+         x().px(8).py(4).overflow(OverflowAxis::Clip)
+      });
+
+      let widths = use_rw_signal(columns.iter().map(|n| n.width).collect::<Vec<_>>());
+      let template_columns = use_memo(move |_| {
+         widths.with(|widths| {
+            widths
+               .iter()
+               .map(|width| RepeatedGridTrack::px(1, *width))
+               .collect::<Vec<_>>()
+         })
+      });
+      let sort = use_rw_signal(None::<TableSort>);
+
+      let mut header_cells = Vec::with_capacity(columns.len());
+      let mut cells = Vec::with_capacity(columns.len());
+      let mut compares = Vec::with_capacity(columns.len());
+      for (index, column) in columns.into_iter().enumerate() {
+         let TableColumn {
+            header,
+            cell,
+            compare,
+            min_width,
+            ..
+         } = column;
+         header_cells.push(table_header_cell(
+            index,
+            header,
+            compare.is_some(),
+            min_width,
+            sort,
+            widths,
+         ));
+         cells.push(cell);
+         compares.push(compare);
+      }
+      let cells = Arc::new(cells);
+      let compares = Arc::new(compares);
+
+      // a row is looked up as a selection of its key alone, so a change of the selection only
+      // notifies the rows entering or leaving it
+      let selector = Selector::new_with_fn(
+         move || selected.get(),
+         |row: &Vec<K>, selected| row.iter().all(|key| selected.contains(key)),
+      );
+      let select_row = move |key: K| {
+         let multiple = multiple.get_untracked();
+         selected.update(|selected| select(selected, key, multiple));
+      };
+
+      div().name("table").style(TableStyle).children((
+         div()
+            .name("table-header")
+            .style(TableHeaderStyle)
+            .grid_template_columns(template_columns)
+            .children(x_iter(header_cells)),
+         div()
+            .name("table-body")
+            .flex_col()
+            .member(x_bundle(OptionGroupNode))
+            .children(rx(move || {
+               let mut rows = rows.get();
+               sort_rows(&mut rows, sort.get(), &compares);
+               let cells = cells.clone();
+               let selector = selector.clone();
+               x_iter_keyed(rows.into_iter().map(move |(key, row)| {
+                  let is_selected = use_memo({
+                     let selector = selector.clone();
+                     let key = key.clone();
+                     move |_| selector.selected(vec![key.clone()])
+                  });
+                  let row_cells = cells
+                     .iter()
+                     .map(|cell| {
+                        div()
+                           .name("table-cell")
+                           .style(TableCellStyle)
+                           .children(cell(&row))
+                     })
+                     .collect::<Vec<_>>();
+                  Keyed(
+                     key.clone(),
+                     button()
+                        .name("table-row")
+                        .style(TableRowStyle)
+                        .grid_template_columns(template_columns)
+                        .selected(is_selected)
+                        .member(x_option())
+                        .member(x_option_keys(KeyCode::ArrowUp, KeyCode::ArrowDown, false))
+                        .member(x_ui_setting_once(move |n| {
+                           ().on(n.confirm_event_ids.clone(), move || select_row(key.clone()))
+                        }))
+                        .children(x_iter(row_cells)),
+                  )
+               }))
+            })),
+      ))
+   }
+}
+
+fn table_header_cell(
+   index: usize,
+   header: BoxedDynamicView<BevyRenderer>,
+   sortable: bool,
+   min_width: f32,
+   sort: RwSignal<Option<TableSort>>,
+   widths: RwSignal<Vec<f32>>,
+) -> impl IntoView<BevyRenderer> {
+   let sorted = use_memo(move |_| sort.get().filter(|sort| sort.column == index));
+   let toggle_sort = move || sort.update(|sort| *sort = next_sort(*sort, index));
+
+   button()
+      .name("table-header-cell")
+      .style(TableHeaderCellStyle)
+      .selected(use_memo(move |_| sorted.get().is_some()))
+      .member(
+         sortable
+            .then(|| x_ui_setting_once(move |n| ().on(n.confirm_event_ids.clone(), toggle_sort))),
+      )
+      .children((
+         header,
+         rx(move || match sorted.get() {
+            Some(TableSort {
+               descending: false, ..
+            }) => " ^",
+            Some(TableSort {
+               descending: true, ..
+            }) => " v",
+            None => "",
+         }),
+         div()
+            .name("table-resize-handle")
+            .style(TableResizeHandleStyle)
+            // releasing the drag over the handle does not sort the column
+            .on(x_pointer_click().stop_propagation(), || {})
+            .on_pointer_drag(move |e: Res<ListenerInputPointerDrag>| {
+               widths.update(|widths| {
+                  widths[index] = (widths[index] + e.delta.x).max(min_width);
+               });
+            }),
+      ))
+}
+
+/// Ascending, then descending, then back to the order of the rows.
+fn next_sort(sort: Option<TableSort>, index: usize) -> Option<TableSort> {
+   match sort {
+      Some(TableSort {
+         column,
+         descending: false,
+      }) if column == index => Some(TableSort {
+         column,
+         descending: true,
+      }),
+      Some(TableSort { column, .. }) if column == index => None,
+      _ => Some(TableSort {
+         column: index,
+         descending: false,
+      }),
+   }
+}
+
+fn sort_rows<K, T>(
+   rows: &mut [(K, T)],
+   sort: Option<TableSort>,
+   compares: &[Option<RowCompareFn<T>>],
+) {
+   let Some(TableSort { column, descending }) = sort else {
+      return;
+   };
+   let Some(compare) = &compares[column] else {
+      return;
+   };
+   rows.sort_by(|(_, a), (_, b)| {
+      let ordering = compare(a, b);
+      if descending {
+         ordering.reverse()
+      } else {
+         ordering
+      }
+   });
+}
+
+/// Selects the row alone, with `multiple` toggles it in the selection instead.
+fn select<K: PartialEq>(selected: &mut Vec<K>, key: K, multiple: bool) {
+   if !multiple {
+      *selected = vec![key];
+   } else if let Some(index) = selected.iter().position(|n| *n == key) {
+      selected.remove(index);
+   } else {
+      selected.push(key);
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn sorting_a_column_cycles_ascending_descending_and_unsorted() {
+      let ascending = |column| {
+         Some(TableSort {
+            column,
+            descending: false,
+         })
+      };
+      let descending = Some(TableSort {
+         column: 1,
+         descending: true,
+      });
+      assert_eq!(next_sort(None, 1), ascending(1));
+      assert_eq!(next_sort(ascending(1), 1), descending);
+      assert_eq!(next_sort(descending, 1), None);
+      assert_eq!(next_sort(descending, 0), ascending(0));
+
+      let compares: Vec<Option<RowCompareFn<u32>>> =
+         vec![None, Some(Arc::new(|a: &u32, b: &u32| a.cmp(b)))];
+      let mut rows = vec![("b", 2), ("c", 3), ("a", 1)];
+      sort_rows(&mut rows, ascending(0), &compares);
+      assert_eq!(rows, [("b", 2), ("c", 3), ("a", 1)]);
+      sort_rows(&mut rows, ascending(1), &compares);
+      assert_eq!(rows, [("a", 1), ("b", 2), ("c", 3)]);
+      sort_rows(&mut rows, descending, &compares);
+      assert_eq!(rows, [("c", 3), ("b", 2), ("a", 1)]);
+   }
+
+   #[test]
+   fn multiple_selection_toggles_the_rows() {
+      let mut selected = vec![1];
+      select(&mut selected, 2, false);
+      assert_eq!(selected, [2]);
+      select(&mut selected, 3, true);
+      assert_eq!(selected, [2, 3]);
+      select(&mut selected, 2, true);
+      assert_eq!(selected, [3]);
+   }
+}
@@ -0,0 +1,204 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use bevy_input::keyboard::KeyCode;
+use bevy_ui::GridPlacement;
+
+use rxy_ui::bevy::x_bundle;
+use rxy_ui::prelude::*;
+
+use crate::option_group::{
+   x_option, x_option_keys, OptionGroupContext, OptionGroupNode, SelectOnFocus,
+};
+use crate::x_ui_setting_once;
+
+#[derive(TypedStyle)]
+pub struct TabsStyle;
+
+#[derive(TypedStyle)]
+pub struct TabListStyle;
+
+#[derive(TypedStyle)]
+pub struct TabStyle;
+
+#[derive(TypedStyle)]
+pub struct TabPanelsStyle;
+
+#[derive(TypedStyle)]
+pub struct TabPanelStyle;
+
+/// A row of [`tab`]s above the [`tab_panel`] of the selected one.
+///
+/// A panel is built the first time its tab is selected and is kept hidden when another one is selected.
+/// The left and right arrow keys select the previous or next tab.
+///
+/// ```ignore
+/// tabs::<&str>()
+///    .selected("Video")
+///    .slot_tabs((tab("Video", "Video"), tab("Audio", "Audio")))
+///    .slot_content((
+///       tab_panel("Video", video_settings()),
+///       tab_panel("Audio", audio_settings()),
+///    ))
+/// ```
+#[derive(ElementSchema)]
+pub struct Tabs<T: Default + Debug + Send + Sync + PartialEq + Clone + 'static> {
+   ctx: SchemaCtx,
+   tabs: Slot,
+   content: Slot,
+   selected: ReadSignal<T>,
+   onchange: Sender<T>,
+}
+
+impl<T> SchemaElementView<BevyRenderer> for Tabs<T>
+where
+   T: Default + Debug + Send + Sync + PartialEq + Clone + 'static,
+{
+   fn view(self) -> impl IntoElementView<BevyRenderer> {
+      let Tabs {
+         mut ctx,
+         tabs,
+         content,
+         selected,
+         onchange,
+      } = self;
+      let value_signal = ctx.use_controlled_state(selected, onchange);
+
+      ctx.default_typed_style(TabsStyle, || x().flex_col());
+      ctx.default_typed_style(TabListStyle, || {
+         x().flex()
+            .gap(4)
+            .border_bottom(1)
+            .border_color(token::border)
+      });
+      ctx.default_typed_style(TabStyle, || {
+         (
+            x().px(12).py(6).text_color(token::text_muted),
+            x_hover().text_color(token::text),
+            x_selected()
+               .text_color(token::text)
+               .border_bottom(2)
+               .border_color(token::primary),
+            x_focus_visible()
+               .outline_width(2)
+               .outline_offset(2)
+               .outline_color(token::primary),
+         )
+      });
+      // the panels share a grid cell so the size does not change with the selected tab
+      ctx.default_typed_style(TabPanelsStyle, || x().grid().pt(8));
+      ctx.default_typed_style(TabPanelStyle, || {
+         x().grid_row(GridPlacement::start(1))
+            .grid_column(GridPlacement::start(1))
+      });
+
+      provide_context(
+         OptionGroupContext {
+            value_signal,
+            readonly: use_signal(false).0,
+         },
+         div().name("tabs").style(TabsStyle).children((
+            div()
+               .name("tab-list")
+               .style(TabListStyle)
+               .member(x_bundle(OptionGroupNode))
+               .children(tabs),
+            div()
+               .name("tab-panels")
+               .style(TabPanelsStyle)
+               .children(content),
+         )),
+      )
+   }
+}
+
+/// The header of a [`tab_panel`] in [`tabs`], selects it when focused with the keys or confirmed.
+pub fn tab<T, IV>(value: T, content: IV) -> FnSchemaView<impl SchemaIntoViewFn<BevyRenderer>>
+where
+   T: Send + Sync + PartialEq + Clone + 'static,
+   IV: IntoView<BevyRenderer> + Send + 'static,
+{
+   fn_schema_view(move || {
+      view_builder(move |ctx, _| {
+         let OptionGroupContext { value_signal, .. } = ctx.context::<OptionGroupContext<T>>();
+         let is_selected = use_memo({
+            let value = value.clone();
+            move |_| value_signal.get() == value
+         });
+         let select = move || {
+            value_signal.set(value.clone());
+         };
+
+         button()
+            .name("tab")
+            .style(TabStyle)
+            .selected(is_selected)
+            .member(x_option())
+            .member(x_bundle(SelectOnFocus(Arc::new(select.clone()))))
+            .member(x_option_keys(KeyCode::ArrowLeft, KeyCode::ArrowRight, true))
+            .member(x_ui_setting_once(move |n| {
+               ().on(n.confirm_event_ids.clone(), select)
+            }))
+            .children(content)
+      })
+   })
+}
+
+/// The content of a [`tab`] in [`tabs`].
+pub fn tab_panel<T, IV>(value: T, content: IV) -> FnSchemaView<impl SchemaIntoViewFn<BevyRenderer>>
+where
+   T: Send + Sync + PartialEq + Clone + 'static,
+   IV: IntoView<BevyRenderer> + Send + 'static,
+{
+   fn_schema_view(move || {
+      view_builder(move |ctx, _| {
+         let OptionGroupContext { value_signal, .. } = ctx.context::<OptionGroupContext<T>>();
+         let is_active = use_memo(move |_| value_signal.get() == value);
+         div()
+            .name("tab-panel")
+            .style(TabPanelStyle)
+            .member(x_panel_visibility(is_active))
+            .children(lazy_content(is_active, content))
+      })
+   })
+}
+
+/// Builds the content the first time the panel is active, see [`x_panel_visibility`].
+pub(crate) fn lazy_content(
+   is_active: Memo<bool>,
+   content: impl IntoView<BevyRenderer> + Send,
+) -> impl IntoView<BevyRenderer> {
+   x_if(use_is_built(is_active), content)
+}
+
+/// Whether the panel has been active once.
+fn use_is_built(is_active: Memo<bool>) -> Memo<bool> {
+   use_memo(move |is_built| is_built.copied().unwrap_or(false) || is_active.get())
+}
+
+/// Hides the panel with `set_visibility` while it is not active, its built content is kept.
+pub(crate) fn x_panel_visibility(is_active: Memo<bool>) -> impl ViewMember<BevyRenderer> {
+   rx(move || {
+      let hidden = !is_active.get();
+      member_builder(move |ctx: ViewMemberCtx<BevyRenderer>, _| {
+         ctx.node_id.set_visibility(ctx.world, hidden);
+      })
+   })
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn panel_is_built_once_active() {
+      let active = use_rw_signal(false);
+      let is_built = use_is_built(use_memo(move |_| active.get()));
+      assert!(!is_built.get());
+      active.set(true);
+      assert!(is_built.get());
+      // kept when another panel is selected
+      active.set(false);
+      assert!(is_built.get());
+   }
+}
@@ -0,0 +1,397 @@
+use core::time::Duration;
+use std::sync::Arc;
+
+use bevy_app::{App, Plugin, PostUpdate, Update};
+use bevy_ecs::prelude::{
+   Component, Entity, IntoSystemConfigs, Query, Resource, With, Without, World,
+};
+use bevy_hierarchy::DespawnRecursiveExt;
+use bevy_render::prelude::{Color, Visibility};
+use bevy_time::Time;
+use bevy_transform::prelude::GlobalTransform;
+use bevy_ui::prelude::NodeBundle;
+use bevy_ui::{FocusPolicy, Interaction, Node, PositionType, Style, UiSystem, Val, ZIndex};
+use bevy_window::{PrimaryWindow, Window};
+use glam::Vec2;
+
+use rxy_ui::bevy::{BevyRenderer, RxyViewSpawner};
+use rxy_ui::prelude::*;
+use rxy_ui::{ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin};
+
+/// Shows the tooltips of the views with a [`tooltip`] member.
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+   fn build(&self, app: &mut App) {
+      app.init_resource::<TooltipState>()
+         .add_systems(Update, update_tooltip)
+         .add_systems(PostUpdate, position_tooltip.after(UiSystem::Layout));
+   }
+}
+
+/// The side of the anchor the tooltip prefers, it flips to the opposite side to stay within the window.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TooltipPlacement {
+   #[default]
+   Top,
+   Bottom,
+   Left,
+   Right,
+}
+
+impl TooltipPlacement {
+   pub fn flip(self) -> Self {
+      match self {
+         TooltipPlacement::Top => TooltipPlacement::Bottom,
+         TooltipPlacement::Bottom => TooltipPlacement::Top,
+         TooltipPlacement::Left => TooltipPlacement::Right,
+         TooltipPlacement::Right => TooltipPlacement::Left,
+      }
+   }
+}
+
+type TooltipContentFn = Arc<dyn Fn(&mut World, Entity) + Send + Sync>;
+
+/// The tooltip of a view, added by the [`tooltip`] member.
+#[derive(Component, Clone)]
+pub struct TooltipAnchor {
+   content: TooltipContentFn,
+   pub delay: Duration,
+   pub placement: TooltipPlacement,
+   /// The space between the anchor and the tooltip.
+   pub gap: f32,
+}
+
+/// The overlay node of a shown tooltip.
+#[derive(Component, Clone, Debug)]
+pub struct Tooltip {
+   pub anchor: Entity,
+   placement: TooltipPlacement,
+   gap: f32,
+}
+
+#[derive(Resource, Default, Debug)]
+struct TooltipState {
+   anchor: Option<Entity>,
+   hover_start: Duration,
+   tooltip: Option<Entity>,
+   /// Pressing the anchor hides the tooltip until the pointer leaves it.
+   dismissed: bool,
+}
+
+/// Shows the view in a tooltip after the pointer hovers the element for a while.
+///
+/// ```ignore
+/// button().member(tooltip("Save the game").placement(TooltipPlacement::Bottom))
+/// ```
+pub fn tooltip<IV>(view: IV) -> XTooltip
+where
+   IV: IntoView<BevyRenderer> + Clone + Send + Sync + 'static,
+{
+   tooltip_with(move || view.clone())
+}
+
+/// Like [`tooltip`], the view is created each time the tooltip shows.
+pub fn tooltip_with<F, IV>(f: F) -> XTooltip
+where
+   F: Fn() -> IV + Send + Sync + 'static,
+   IV: IntoView<BevyRenderer>,
+{
+   XTooltip(TooltipAnchor {
+      content: Arc::new(move |world: &mut World, parent: Entity| {
+         let _ = world.spawn_view(
+            div()
+               .p(6)
+               .bg_color(Color::rgba(0.1, 0.1, 0.1, 0.9))
+               .children(f()),
+            move |_| parent,
+         );
+      }),
+      delay: Duration::from_millis(500),
+      placement: TooltipPlacement::default(),
+      gap: 6.,
+   })
+}
+
+pub struct XTooltip(TooltipAnchor);
+
+impl XTooltip {
+   pub fn delay(mut self, delay: Duration) -> Self {
+      self.0.delay = delay;
+      self
+   }
+
+   pub fn placement(mut self, placement: TooltipPlacement) -> Self {
+      self.0.placement = placement;
+      self
+   }
+
+   pub fn gap(mut self, gap: f32) -> Self {
+      self.0.gap = gap;
+      self
+   }
+}
+
+impl ViewMemberOrigin<BevyRenderer> for XTooltip {
+   type Origin = Self;
+}
+
+impl ViewMember<BevyRenderer> for XTooltip {
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(ctx: ViewMemberCtx<BevyRenderer>, view_removed: bool) {
+      if view_removed {
+         return;
+      }
+      ctx.world.entity_mut(ctx.node_id).remove::<TooltipAnchor>();
+   }
+
+   fn build(self, ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      let mut entity_world_mut = ctx.world.entity_mut(ctx.node_id);
+      // the hover is detected with the interaction
+      if !entity_world_mut.contains::<Interaction>() {
+         entity_world_mut.insert(Interaction::default());
+      }
+      entity_world_mut.insert(self.0);
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      self.build(ctx, true);
+   }
+}
+
+fn update_tooltip(world: &mut World) {
+   let hovered = world
+      .query::<(Entity, &Interaction, &TooltipAnchor)>()
+      .iter(world)
+      .filter(|(_, interaction, _)| **interaction != Interaction::None)
+      .map(|(entity, interaction, _)| (entity, *interaction == Interaction::Pressed))
+      .last();
+   let now = world.resource::<Time>().elapsed();
+
+   let mut state = world.resource_mut::<TooltipState>();
+   let old_tooltip = if hovered.map(|n| n.0) != state.anchor {
+      state.anchor = hovered.map(|n| n.0);
+      state.hover_start = now;
+      state.dismissed = false;
+      state.tooltip.take()
+   } else if hovered.is_some_and(|(_, pressed)| pressed) && !state.dismissed {
+      state.dismissed = true;
+      state.tooltip.take()
+   } else {
+      None
+   };
+   if let Some(tooltip) = old_tooltip.and_then(|n| world.get_entity_mut(n)) {
+      tooltip.despawn_recursive();
+   }
+
+   let state = world.resource::<TooltipState>();
+   let Some(anchor) = state.anchor else {
+      return;
+   };
+   if state.tooltip.is_some() || state.dismissed {
+      return;
+   }
+   let Some(anchor_tooltip) = world.get::<TooltipAnchor>(anchor).cloned() else {
+      return;
+   };
+   if now < state.hover_start + anchor_tooltip.delay {
+      return;
+   }
+
+   let tooltip = world
+      .spawn((
+         NodeBundle {
+            style: Style {
+               position_type: PositionType::Absolute,
+               ..Default::default()
+            },
+            focus_policy: FocusPolicy::Pass,
+            z_index: ZIndex::Global(i32::MAX - 1),
+            // shown once positioned
+            visibility: Visibility::Hidden,
+            ..Default::default()
+         },
+         Tooltip {
+            anchor,
+            placement: anchor_tooltip.placement,
+            gap: anchor_tooltip.gap,
+         },
+      ))
+      .id();
+   (anchor_tooltip.content)(world, tooltip);
+   world.resource_mut::<TooltipState>().tooltip = Some(tooltip);
+}
+
+/// The top left corner of a tooltip of the size at the placement, flipped if it doesn't fit.
+fn tooltip_position(
+   placement: TooltipPlacement,
+   gap: f32,
+   (anchor_min, anchor_max): (Vec2, Vec2),
+   size: Vec2,
+   window_size: Vec2,
+) -> Vec2 {
+   let position_at = |placement: TooltipPlacement| {
+      let center = (anchor_min + anchor_max) / 2. - size / 2.;
+      match placement {
+         TooltipPlacement::Top => Vec2::new(center.x, anchor_min.y - gap - size.y),
+         TooltipPlacement::Bottom => Vec2::new(center.x, anchor_max.y + gap),
+         TooltipPlacement::Left => Vec2::new(anchor_min.x - gap - size.x, center.y),
+         TooltipPlacement::Right => Vec2::new(anchor_max.x + gap, center.y),
+      }
+   };
+   let fits = |position: Vec2| {
+      position.x >= 0.
+         && position.y >= 0.
+         && position.x + size.x <= window_size.x
+         && position.y + size.y <= window_size.y
+   };
+   let mut position = position_at(placement);
+   if !fits(position) {
+      let flipped = position_at(placement.flip());
+      if fits(flipped) {
+         position = flipped;
+      }
+   }
+   // keep it within the window on the other axis
+   position.clamp(Vec2::ZERO, (window_size - size).max(Vec2::ZERO))
+}
+
+fn position_tooltip(
+   mut tooltips: Query<(&Tooltip, &Node, &mut Style, &mut Visibility)>,
+   anchors: Query<(&Node, &GlobalTransform), Without<Tooltip>>,
+   windows: Query<&Window, With<PrimaryWindow>>,
+) {
+   let Ok(window) = windows.get_single() else {
+      return;
+   };
+   let window_size = Vec2::new(window.width(), window.height());
+   for (tooltip, node, mut style, mut visibility) in tooltips.iter_mut() {
+      let Ok((anchor_node, anchor_transform)) = anchors.get(tooltip.anchor) else {
+         continue;
+      };
+      let size = node.size();
+      if size.x <= 0. || size.y <= 0. {
+         continue;
+      }
+      let anchor_size = anchor_node.size();
+      let anchor_min = anchor_transform.translation().truncate() - anchor_size / 2.;
+      let position = tooltip_position(
+         tooltip.placement,
+         tooltip.gap,
+         (anchor_min, anchor_min + anchor_size),
+         size,
+         window_size,
+      );
+
+      let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+      if style.left != left || style.top != top {
+         style.left = left;
+         style.top = top;
+      } else if *visibility == Visibility::Hidden {
+         // the layout has applied the position
+         *visibility = Visibility::Inherited;
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use bevy_ecs::system::RunSystemOnce;
+
+   use super::*;
+
+   fn hovered_anchor(world: &mut World, interaction: Interaction) -> Entity {
+      world
+         .spawn((
+            interaction,
+            TooltipAnchor {
+               content: Arc::new(|_, _| {}),
+               delay: Duration::from_millis(500),
+               placement: TooltipPlacement::Top,
+               gap: 6.,
+            },
+         ))
+         .id()
+   }
+
+   fn advance(world: &mut World, millis: u64) {
+      world
+         .resource_mut::<Time>()
+         .advance_by(Duration::from_millis(millis));
+      world.run_system_once(update_tooltip);
+   }
+
+   fn shown_tooltip(world: &World) -> Option<Entity> {
+      world.resource::<TooltipState>().tooltip
+   }
+
+   #[test]
+   fn shows_after_the_delay_until_pressed() {
+      let mut world = World::new();
+      world.init_resource::<Time>();
+      world.init_resource::<TooltipState>();
+      let anchor = hovered_anchor(&mut world, Interaction::Hovered);
+
+      advance(&mut world, 0);
+      assert_eq!(shown_tooltip(&world), None);
+      advance(&mut world, 600);
+      let tooltip = shown_tooltip(&world).unwrap();
+      assert_eq!(world.get::<Tooltip>(tooltip).unwrap().anchor, anchor);
+
+      *world.get_mut::<Interaction>(anchor).unwrap() = Interaction::Pressed;
+      advance(&mut world, 0);
+      assert_eq!(shown_tooltip(&world), None);
+      assert!(world.get_entity(tooltip).is_none());
+
+      // hidden until the pointer leaves the anchor
+      *world.get_mut::<Interaction>(anchor).unwrap() = Interaction::Hovered;
+      advance(&mut world, 600);
+      assert_eq!(shown_tooltip(&world), None);
+      *world.get_mut::<Interaction>(anchor).unwrap() = Interaction::None;
+      advance(&mut world, 0);
+      assert_eq!(world.resource::<TooltipState>().anchor, None);
+   }
+
+   #[test]
+   fn flips_to_stay_within_the_window() {
+      let window_size = Vec2::new(800., 600.);
+      let size = Vec2::new(100., 40.);
+      let position = |placement, anchor_min: Vec2| {
+         tooltip_position(
+            placement,
+            6.,
+            (anchor_min, anchor_min + Vec2::splat(20.)),
+            size,
+            window_size,
+         )
+      };
+
+      let anchor = Vec2::new(400., 300.);
+      assert_eq!(
+         position(TooltipPlacement::Top, anchor),
+         Vec2::new(360., 254.)
+      );
+      assert_eq!(
+         position(TooltipPlacement::Right, anchor),
+         Vec2::new(426., 290.)
+      );
+      // no space above the anchor
+      assert_eq!(
+         position(TooltipPlacement::Top, Vec2::new(400., 10.)),
+         Vec2::new(360., 36.)
+      );
+      // no space on the right
+      assert_eq!(
+         position(TooltipPlacement::Right, Vec2::new(770., 100.)),
+         Vec2::new(664., 90.)
+      );
+      // no space on either side, clamped to the window
+      assert_eq!(
+         position(TooltipPlacement::Top, Vec2::new(0., 300.)),
+         Vec2::new(0., 254.)
+      );
+   }
+}
@@ -0,0 +1,387 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use bevy_ecs::prelude::{Component, Entity, World};
+use bevy_hierarchy::{Children, Parent};
+use bevy_input::keyboard::KeyCode;
+
+use rxy_ui::bevy::navigation::NavigationTargets;
+use rxy_ui::bevy::x_bundle;
+use rxy_ui::prelude::*;
+
+use crate::option_group::{focus_option, x_option, x_option_keys, OptionGroupNode};
+use crate::x_ui_setting_once;
+
+#[derive(TypedStyle)]
+pub struct TreeViewStyle;
+#[derive(TypedStyle)]
+pub struct TreeItemStyle;
+#[derive(TypedStyle)]
+pub struct TreeExpanderStyle;
+
+const TREE_INDENT: f32 = 16.;
+
+type NodeLabelFn<K> = Arc<dyn Fn(&K) -> BoxedDynamicView<BevyRenderer> + Send + Sync>;
+type NodeChildrenFn<K> = Arc<dyn Fn(&K) -> Vec<K> + Send + Sync>;
+
+/// Where a [`tree_view`] reads its nodes from, see [`tree_source`].
+#[derive(PropValueWrapper)]
+pub struct TreeSource<K> {
+   label: NodeLabelFn<K>,
+   children: NodeChildrenFn<K>,
+   has_children: Arc<dyn Fn(&K) -> bool + Send + Sync>,
+}
+
+impl<K> Clone for TreeSource<K> {
+   fn clone(&self) -> Self {
+      Self {
+         label: self.label.clone(),
+         children: self.children.clone(),
+         has_children: self.has_children.clone(),
+      }
+   }
+}
+
+/// The nodes of a tree by their keys.
+///
+/// The children of a node are only loaded once it is expanded, `has_children` tells whether it can be
+/// expanded before that. Both are tracked, so reading signals in them updates the tree.
+pub fn tree_source<K, IV, I>(
+   label: impl Fn(&K) -> IV + Send + Sync + 'static,
+   children: impl Fn(&K) -> I + Send + Sync + 'static,
+   has_children: impl Fn(&K) -> bool + Send + Sync + 'static,
+) -> TreeSource<K>
+where
+   IV: IntoView<BevyRenderer>,
+   I: IntoIterator<Item = K>,
+{
+   TreeSource {
+      label: Arc::new(move |key| label(key).into_dynamic()),
+      children: Arc::new(move |key| children(key).into_iter().collect()),
+      has_children: Arc::new(has_children),
+   }
+}
+
+/// The item of a node, the children of the node are in the [`TreeChildren`] next to it.
+#[derive(Component)]
+struct TreeItem;
+
+#[derive(Component)]
+struct TreeChildren;
+
+/// A collapsible tree of the nodes of the source below the roots.
+///
+/// Confirming an item selects its node. The right arrow key expands the node or moves to its first
+/// child, the left arrow key collapses it or moves to its parent.
+/// Only the items whose selection or expansion changes are updated.
+///
+/// A scene hierarchy browser can mirror the `Children` of the entities into a signal with a system:
+///
+/// ```ignore
+/// tree_view::<Entity>(tree_source(
+///    |entity: &Entity| format!("{entity:?}"),
+///    move |entity: &Entity| hierarchy.with(|n| n.get(entity).cloned().unwrap_or_default()),
+///    move |entity: &Entity| hierarchy.with(|n| n.get(entity).is_some_and(|n| !n.is_empty())),
+/// ))
+/// .roots(roots)
+/// .onchange(selected_sender)
+/// ```
+#[derive(ElementSchema)]
+pub struct TreeView<K>
+where
+   K: Debug + Hash + Eq + Clone + Send + Sync + 'static,
+{
+   ctx: SchemaCtx,
+   source: Required<Static<TreeSource<K>>>,
+   roots: ReadSignal<Vec<K>>,
+   selected: ReadSignal<Option<K>>,
+   onchange: Sender<Option<K>>,
+}
+
+/// The state shared by the nodes of a [`tree_view`].
+struct TreeState<K>
+where
+   K: Debug + Hash + Eq + Clone + Send + Sync + 'static,
+{
+   source: TreeSource<K>,
+   expanded: RwSignal<Vec<K>>,
+   expanded_selector: Selector<Vec<K>>,
+   selected: RwSignal<Option<K>>,
+   selected_selector: Selector<Option<K>>,
+}
+
+impl<K> Clone for TreeState<K>
+where
+   K: Debug + Hash + Eq + Clone + Send + Sync + 'static,
+{
+   fn clone(&self) -> Self {
+      Self {
+         source: self.source.clone(),
+         expanded: self.expanded,
+         expanded_selector: self.expanded_selector.clone(),
+         selected: self.selected,
+         selected_selector: self.selected_selector.clone(),
+      }
+   }
+}
+
+impl<K> SchemaElementView<BevyRenderer> for TreeView<K>
+where
+   K: Debug + Hash + Eq + Clone + Send + Sync + 'static,
+{
+   fn view(self) -> impl IntoElementView<BevyRenderer> {
+      let TreeView {
+         mut ctx,
+         source: Required(Static(source)),
+         roots,
+         selected,
+         onchange,
+      } = self;
+      let selected = ctx.use_controlled_state(selected, onchange);
+
+      ctx.default_typed_style(TreeViewStyle, || x().flex_col());
+      ctx.default_typed_style(TreeItemStyle, || {
+         (
+            x().flex()
+               .items_center()
+               .pr(8)
+               .py(2)
+               .text_color(token::text),
+            x_hover().bg_color(token::surface),
+            x_selected().bg_color(token::background),
+            x_focus_visible()
+               .outline_width(2)
+               .outline_color(token::primary),
+         )
+      });
+      ctx.default_typed_style(TreeExpanderStyle, || {
+         x().w(TREE_INDENT).center().text_color(token::text_muted)
+      });
+
+      let expanded = use_rw_signal(Vec::<K>::new());
+      let state = TreeState {
+         source,
+         expanded,
+         // a node is looked up as an expansion of itself alone, like the rows of a table
+         expanded_selector: Selector::new_with_fn(
+            move || expanded.get(),
+            |node: &Vec<K>, expanded| node.iter().all(|key| expanded.contains(key)),
+         ),
+         selected,
+         selected_selector: Selector::new(move || selected.get()),
+      };
+
+      div()
+         .name("tree-view")
+         .style(TreeViewStyle)
+         .member(x_bundle(OptionGroupNode))
+         .children(rx(move || {
+            let state = state.clone();
+            x_iter_keyed(
+               roots
+                  .get()
+                  .into_iter()
+                  .map(move |key| Keyed(key.clone(), tree_node(key, 0, state.clone()))),
+            )
+         }))
+   }
+}
+
+fn tree_node<K>(key: K, depth: usize, state: TreeState<K>) -> BoxedDynamicView<BevyRenderer>
+where
+   K: Debug + Hash + Eq + Clone + Send + Sync + 'static,
+{
+   let TreeState {
+      source,
+      expanded,
+      expanded_selector,
+      selected,
+      selected_selector,
+   } = state.clone();
+   let is_expanded = use_memo({
+      let key = key.clone();
+      move |_| expanded_selector.selected(vec![key.clone()])
+   });
+   let is_selected = use_memo({
+      let key = key.clone();
+      move |_| selected_selector.selected(Some(key.clone()))
+   });
+   let has_children = {
+      let key = key.clone();
+      let has_children = source.has_children.clone();
+      use_memo(move |_| has_children(&key))
+   };
+   let set_expanded = {
+      let key = key.clone();
+      move |is_expanded: bool| {
+         expanded.update(|expanded| set_expanded(expanded, &key, is_expanded));
+      }
+   };
+
+   let item = button()
+      .name("tree-item")
+      .style(TreeItemStyle)
+      .selected(is_selected)
+      .member(x_bundle(TreeItem))
+      .member(x_option())
+      .member(x_option_keys(KeyCode::ArrowUp, KeyCode::ArrowDown, false))
+      .member(x_ui_setting_once({
+         let key = key.clone();
+         move |n| {
+            ().on(n.confirm_event_ids.clone(), move || {
+               selected.set(Some(key.clone()))
+            })
+         }
+      }))
+      .member(member_builder({
+         let set_expanded = set_expanded.clone();
+         move |ctx: ViewMemberCtx<BevyRenderer>, _| {
+            let item = ctx.node_id;
+            (
+               // the arrow keys expand and collapse the node instead of navigating away from it
+               x_bundle(NavigationTargets::default().left(item).right(item)),
+               ().on_just_pressed(KeyCode::ArrowRight, {
+                  let set_expanded = set_expanded.clone();
+                  move |world: &mut World| {
+                     if is_expanded.get_untracked() {
+                        if let Some(child) = first_child_tree_item(world, item) {
+                           focus_option(world, child);
+                        }
+                     } else if has_children.get_untracked() {
+                        set_expanded(true);
+                     }
+                  }
+               })
+               .on_just_pressed(KeyCode::ArrowLeft, move |world: &mut World| {
+                  if is_expanded.get_untracked() {
+                     set_expanded(false);
+                  } else if let Some(parent) = parent_tree_item(world, item) {
+                     focus_option(world, parent);
+                  }
+               }),
+            )
+         }
+      }))
+      .children((
+         div().name("tree-indent").w(depth as f32 * TREE_INDENT),
+         div()
+            .name("tree-expander")
+            .style(TreeExpanderStyle)
+            .on(x_pointer_click().stop_propagation(), move || {
+               if has_children.get_untracked() {
+                  set_expanded(!is_expanded.get_untracked());
+               }
+            })
+            .children(rx(move || match (has_children.get(), is_expanded.get()) {
+               (false, _) => "",
+               (true, false) => ">",
+               (true, true) => "v",
+            })),
+         (source.label)(&key),
+      ));
+
+   div()
+      .name("tree-node")
+      .flex_col()
+      .children((
+         item,
+         div()
+            .name("tree-children")
+            .flex_col()
+            .member(x_bundle(TreeChildren))
+            .children(rx(move || {
+               // the children are loaded once the node is expanded
+               is_expanded.get().then(|| {
+                  let state = state.clone();
+                  x_iter_keyed((source.children)(&key).into_iter().map(move |child| {
+                     Keyed(child.clone(), tree_node(child, depth + 1, state.clone()))
+                  }))
+               })
+            })),
+      ))
+      .into_dynamic()
+}
+
+fn set_expanded<K: PartialEq + Clone>(expanded: &mut Vec<K>, key: &K, is_expanded: bool) {
+   let index = expanded.iter().position(|n| n == key);
+   match index {
+      Some(index) if !is_expanded => {
+         expanded.remove(index);
+      }
+      None if is_expanded => expanded.push(key.clone()),
+      _ => {}
+   }
+}
+
+/// The item of the node containing the node of the item.
+fn parent_tree_item(world: &World, item: Entity) -> Option<Entity> {
+   let node = world.get::<Parent>(item)?.get();
+   let container = world.get::<Parent>(node)?.get();
+   world.get::<TreeChildren>(container)?;
+   let parent_node = world.get::<Parent>(container)?.get();
+   tree_item_of_node(world, parent_node)
+}
+
+/// The item of the first child node of the node of the item.
+fn first_child_tree_item(world: &World, item: Entity) -> Option<Entity> {
+   let node = world.get::<Parent>(item)?.get();
+   let container = world
+      .get::<Children>(node)?
+      .iter()
+      .copied()
+      .find(|n| world.get::<TreeChildren>(*n).is_some())?;
+   world
+      .get::<Children>(container)?
+      .iter()
+      .find_map(|child_node| tree_item_of_node(world, *child_node))
+}
+
+fn tree_item_of_node(world: &World, node: Entity) -> Option<Entity> {
+   world
+      .get::<Children>(node)?
+      .iter()
+      .copied()
+      .find(|n| world.get::<TreeItem>(*n).is_some())
+}
+
+#[cfg(test)]
+mod tests {
+   use bevy_hierarchy::BuildWorldChildren;
+
+   use super::*;
+
+   #[test]
+   fn expanding_a_node_twice_keeps_it_once() {
+      let mut expanded = vec![];
+      set_expanded(&mut expanded, &1, true);
+      set_expanded(&mut expanded, &1, true);
+      set_expanded(&mut expanded, &2, true);
+      assert_eq!(expanded, [1, 2]);
+      set_expanded(&mut expanded, &1, false);
+      set_expanded(&mut expanded, &3, false);
+      assert_eq!(expanded, [2]);
+   }
+
+   #[test]
+   fn arrow_keys_move_between_the_parent_and_the_first_child() {
+      let mut world = World::new();
+      let spawn_node = |world: &mut World| {
+         let item = world.spawn(TreeItem).id();
+         let children = world.spawn(TreeChildren).id();
+         let node = world.spawn_empty().push_children(&[item, children]).id();
+         (node, item, children)
+      };
+      let (_, root_item, root_children) = spawn_node(&mut world);
+      let (first, first_item, _) = spawn_node(&mut world);
+      let (second, _, _) = spawn_node(&mut world);
+      world
+         .entity_mut(root_children)
+         .push_children(&[first, second]);
+
+      assert_eq!(first_child_tree_item(&world, root_item), Some(first_item));
+      assert_eq!(first_child_tree_item(&world, first_item), None);
+      assert_eq!(parent_tree_item(&world, first_item), Some(root_item));
+      assert_eq!(parent_tree_item(&world, root_item), None);
+   }
+}
@@ -21,6 +21,13 @@ pub trait AttrValue: MaybeReflect + MaybeSend + MaybeSync + Debug + 'static {
       Self: Sized;
 }
 
+/// Attribute values that can be interpolated, used by keyframe animations.
+pub trait AttrValueLerp: AttrValue {
+   fn lerp(&self, to: &Self, t: f32) -> Self
+   where
+      Self: Sized;
+}
+
 impl Clone for SmallBox<dyn AttrValue, S1> {
    fn clone(&self) -> Self {
       self.deref().clone_att_value()
@@ -100,6 +107,18 @@ impl_attr_value_and_wrapper! {
     Cow<'static, str>
 }
 
+impl AttrValueLerp for f32 {
+   fn lerp(&self, to: &Self, t: f32) -> Self {
+      *self + (*to - *self) * t
+   }
+}
+
+impl AttrValueLerp for f64 {
+   fn lerp(&self, to: &Self, t: f32) -> Self {
+      *self + (*to - *self) * t as f64
+   }
+}
+
 impl_x_value_wrappers!(&'static str);
 
 impl Into<XValueWrapper<Cow<'static, str>>> for String {
@@ -14,6 +14,16 @@ macro_rules! define_attr_get_fn {
          }
          unreachable!();
       }
+
+      pub fn get_attr_by_name(
+         attr_name: &str,
+      ) -> Option<&'static dyn ElementAttrUntyped<$renderer>> {
+         ALL_ATTRS
+            .iter()
+            .flat_map(|attrs| attrs.iter())
+            .find(|attr| attr.attr_name() == attr_name)
+            .copied()
+      }
    };
 }
 
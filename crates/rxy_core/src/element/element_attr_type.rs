@@ -6,7 +6,7 @@ use crate::{
 };
 
 #[cfg(feature = "attr_index_u16")]
-pub type AttrIndex = u8;
+pub type AttrIndex = u16;
 #[cfg(not(feature = "attr_index_u16"))]
 pub type AttrIndex = u8;
 
@@ -76,7 +76,7 @@ where
 {
    fn attr_name(&self) -> &'static str;
 
-   fn index(&self) -> u8;
+   fn index(&self) -> AttrIndex;
 
    fn default_value(&self) -> SmallBox<dyn AttrValue, S1>;
 
@@ -99,7 +99,7 @@ where
    }
 
    #[inline]
-   fn index(&self) -> u8 {
+   fn index(&self) -> AttrIndex {
       T::INDEX
    }
 
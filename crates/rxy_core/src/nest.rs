@@ -183,12 +183,15 @@ pub struct MapToStyleSheetsMarker<SS>(PhantomData<SS>);
 
 #[cfg(feature = "style")]
 pub mod impl_style {
+   use alloc::boxed::Box;
+
    use crate::style::{ApplyStyleSheets, StyleSheets, StyledNodeTree};
+   use crate::style::{AttrStyleTokenResolver, StyleToken, StyleTokenTarget};
    use crate::style::{StyleItemValue, StyleSheetCtx, StyleSheetItems};
    use crate::{
-      ElementAttr, ElementAttrType, MapToAttrMarker, MapToStyleSheetsMarker, MapValueWrapper,
-      MaybeSend, Renderer, ViewMember, ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin, XNest,
-      XNestMapper, XValueWrapper,
+      x_world, ElementAttr, ElementAttrType, InnerIvmToVm, MapToAttrMarker,
+      MapToStyleSheetsMarker, MapValueWrapper, MaybeSend, Renderer, RendererWorld, ViewMember,
+      ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin, XNest, XNestMapper, XValueWrapper, XWorld,
    };
 
    impl<T> XNest for ApplyStyleSheets<T>
@@ -269,6 +272,111 @@ pub mod impl_style {
          ElementAttr::<R, EA>::new(self.0.into().0).iter(ctx)
       }
    }
+
+   impl<R, V> XNest for StyleToken<R, V>
+   where
+      R: Renderer,
+      V: 'static,
+   {
+      type Inner = V;
+      type MapInner<M> = InnerIvmToVm<Self, M>;
+
+      fn map_inner<M>(self) -> Self::MapInner<M> {
+         InnerIvmToVm::new(self)
+      }
+
+      fn is_static() -> bool {
+         true
+      }
+   }
+
+   impl<R, V, U> XNestMapper<U> for StyleToken<R, V>
+   where
+      R: Renderer,
+      V: 'static,
+      U: 'static,
+   {
+      #[cfg(feature = "send_sync")]
+      type MapInnerTo =
+         XWorld<R, Box<dyn FnOnce(&mut RendererWorld<R>) -> U + MaybeSend + 'static>>;
+      #[cfg(not(feature = "send_sync"))]
+      type MapInnerTo = XWorld<R, Box<dyn FnOnce(&mut RendererWorld<R>) -> U + 'static>>;
+
+      #[inline]
+      fn map_inner_to(
+         self,
+         f: impl FnOnce(Self::Inner) -> U + MaybeSend + Clone + 'static,
+      ) -> Self::MapInnerTo {
+         x_world(Box::new(move |world| f(self.get(world))))
+      }
+   }
+
+   impl<R, V, EA> ViewMemberOrigin<R> for InnerIvmToVm<StyleToken<R, V>, MapToAttrMarker<EA>>
+   where
+      R: Renderer,
+      V: Into<XValueWrapper<EA::Value>> + 'static,
+      EA: ElementAttrType<R>,
+   {
+      type Origin = ElementAttr<R, EA>;
+   }
+
+   impl<R, V, EA> ViewMember<R> for InnerIvmToVm<StyleToken<R, V>, MapToAttrMarker<EA>>
+   where
+      R: Renderer,
+      R::NodeTree: StyledNodeTree<R>,
+      V: Into<XValueWrapper<EA::Value>> + 'static,
+      EA: ElementAttrType<R>,
+   {
+      #[inline]
+      fn count() -> ViewMemberIndex {
+         ElementAttr::<R, EA>::count()
+      }
+
+      fn unbuild(ctx: ViewMemberCtx<R>, view_removed: bool) {
+         if !view_removed {
+            ctx.world
+               .unwatch_style_token(ctx.node_id.clone(), StyleTokenTarget::Attr(EA::INDEX));
+         }
+         ElementAttr::<R, EA>::unbuild(ctx, view_removed);
+      }
+
+      fn build(self, ctx: ViewMemberCtx<R>, will_rebuild: bool) {
+         let resolver = AttrStyleTokenResolver::<R, EA, V>::new(self.0);
+         let value = resolver.value(&*ctx.world);
+         ctx.world.watch_style_token(
+            ctx.node_id.clone(),
+            StyleTokenTarget::Attr(EA::INDEX),
+            resolver.boxed(),
+         );
+         ElementAttr::<R, EA>::new(value).build(ctx, will_rebuild);
+      }
+
+      fn rebuild(self, ctx: ViewMemberCtx<R>) {
+         self.build(ctx, true);
+      }
+   }
+
+   impl<R, V, EA> StyleSheetItems<R> for InnerIvmToVm<StyleToken<R, V>, MapToAttrMarker<EA>>
+   where
+      R: Renderer,
+      R::NodeTree: StyledNodeTree<R>,
+      V: Into<XValueWrapper<EA::Value>> + MaybeSend + 'static,
+      EA: ElementAttrType<R>,
+   {
+      fn iter(self, ctx: StyleSheetCtx<R>) -> impl Iterator<Item = StyleItemValue> + 'static {
+         let resolver = AttrStyleTokenResolver::<R, EA, V>::new(self.0);
+         let value = resolver.value(&*ctx.world);
+         ctx.world.watch_style_token(
+            ctx.node_id.clone(),
+            StyleTokenTarget::InlineStyleSheet {
+               index: ctx.inline_style_sheet_index,
+               attr_id: EA::INDEX,
+            },
+            resolver.boxed(),
+         );
+         ElementAttr::<R, EA>::new(value).iter(ctx)
+      }
+   }
 }
 
 #[cfg(feature = "xy_reactive")]
@@ -1,6 +1,6 @@
 use crate::{
    impl_attr_value, impl_attr_value_and_wrapper, impl_x_value_wrappers, smallbox, AttrValue,
-   SmallBox, XValueWrapper, S1,
+   AttrValueLerp, SmallBox, XValueWrapper, S1,
 };
 use bevy_asset::Handle;
 use bevy_render::prelude::Color;
@@ -32,6 +32,53 @@ impl_attr_value_and_wrapper! {
     bevy_ui::OverflowAxis
 }
 
+impl AttrValueLerp for Color {
+   fn lerp(&self, to: &Self, t: f32) -> Self {
+      let [r, g, b, a] = self.as_rgba_f32();
+      let [to_r, to_g, to_b, to_a] = to.as_rgba_f32();
+      Color::rgba(
+         AttrValueLerp::lerp(&r, &to_r, t),
+         AttrValueLerp::lerp(&g, &to_g, t),
+         AttrValueLerp::lerp(&b, &to_b, t),
+         AttrValueLerp::lerp(&a, &to_a, t),
+      )
+   }
+}
+
+impl AttrValueLerp for bevy_ui::Val {
+   fn lerp(&self, to: &Self, t: f32) -> Self {
+      use bevy_ui::Val;
+      match (*self, *to) {
+         (Val::Px(from), Val::Px(to)) => Val::Px(AttrValueLerp::lerp(&from, &to, t)),
+         (Val::Percent(from), Val::Percent(to)) => Val::Percent(AttrValueLerp::lerp(&from, &to, t)),
+         (Val::Vw(from), Val::Vw(to)) => Val::Vw(AttrValueLerp::lerp(&from, &to, t)),
+         (Val::Vh(from), Val::Vh(to)) => Val::Vh(AttrValueLerp::lerp(&from, &to, t)),
+         (Val::VMin(from), Val::VMin(to)) => Val::VMin(AttrValueLerp::lerp(&from, &to, t)),
+         (Val::VMax(from), Val::VMax(to)) => Val::VMax(AttrValueLerp::lerp(&from, &to, t)),
+         // different units can not be interpolated, switch at the midpoint
+         (from, to) => {
+            if t < 0.5 {
+               from
+            } else {
+               to
+            }
+         }
+      }
+   }
+}
+
+impl AttrValueLerp for glam::Vec3 {
+   fn lerp(&self, to: &Self, t: f32) -> Self {
+      glam::Vec3::lerp(*self, *to, t)
+   }
+}
+
+impl AttrValueLerp for glam::Quat {
+   fn lerp(&self, to: &Self, t: f32) -> Self {
+      self.slerp(*to, t)
+   }
+}
+
 impl Into<XValueWrapper<bevy_ui::ZIndex>> for i32 {
    fn into(self) -> XValueWrapper<bevy_ui::ZIndex> {
       XValueWrapper(bevy_ui::ZIndex::Global(self))
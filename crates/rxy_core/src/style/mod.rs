@@ -10,6 +10,7 @@ use derive_more::{Deref, DerefMut, From, IntoIterator};
 pub use attr_style_owner::*;
 pub use style_sheet_definition::*;
 pub use style_sheet_items::*;
+pub use style_token::*;
 pub use view_member::*;
 
 use crate::utils::all_tuples;
@@ -21,6 +22,7 @@ use crate::{
 mod attr_style_owner;
 mod style_sheet_definition;
 mod style_sheet_items;
+mod style_token;
 mod view_member;
 
 pub trait StyledNodeTree<R>: NodeTree<R>
@@ -50,6 +52,15 @@ where
    ) -> Result<R>
    where
       T: StyleSheets<R>;
+
+   fn watch_style_token(
+      &mut self,
+      node_id: RendererNodeId<R>,
+      target: StyleTokenTarget,
+      resolver: BoxedStyleTokenResolver<R>,
+   );
+
+   fn unwatch_style_token(&mut self, node_id: RendererNodeId<R>, target: StyleTokenTarget);
 }
 
 pub type Result<R, T = ()> = core::result::Result<T, StyleError<R>>;
@@ -58,7 +69,10 @@ pub type StyleAttrValue = SmallBox<dyn AttrValue, S1>;
 pub mod prelude {
    pub use rxy_macro::TypedStyle;
 
-   pub use super::{x, x_active, x_focus, x_hover};
+   pub use super::{
+      x, x_active, x_checked, x_disabled, x_drag_over, x_focus, x_focus_visible, x_hover,
+      x_selected,
+   };
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -157,6 +171,26 @@ pub fn x_focus() -> StyleSheetOwner<()> {
    StyleSheetOwner(Some(StyleInteraction::Focus), ())
 }
 
+pub fn x_focus_visible() -> StyleSheetOwner<()> {
+   StyleSheetOwner(Some(StyleInteraction::FocusVisible), ())
+}
+
+pub fn x_checked() -> StyleSheetOwner<()> {
+   StyleSheetOwner(Some(StyleInteraction::Checked), ())
+}
+
+pub fn x_selected() -> StyleSheetOwner<()> {
+   StyleSheetOwner(Some(StyleInteraction::Selected), ())
+}
+
+pub fn x_disabled() -> StyleSheetOwner<()> {
+   StyleSheetOwner(Some(StyleInteraction::Disabled), ())
+}
+
+pub fn x_drag_over() -> StyleSheetOwner<()> {
+   StyleSheetOwner(Some(StyleInteraction::DragOver), ())
+}
+
 impl<R, T> MemberOwner<R> for StyleSheetOwner<T>
 where
    R: Renderer,
@@ -311,19 +345,9 @@ impl NodeInterStyleAttrInfos {
       interaction: StyleInteraction,
       strict: bool,
    ) -> Option<&NodeStyleAttrInfo> {
-      match interaction {
-         StyleInteraction::Active => self
-            .get(&StyleInteraction::Active)
-            .and_then(|n| n.get(&attr_id))
-            .condition(strict, |n| {
-               n.or_else(|| {
-                  self
-                     .get(&StyleInteraction::Hover)
-                     .and_then(|n| n.get(&attr_id))
-               })
-            }),
-         interaction => self.get(&interaction).and_then(|n| n.get(&attr_id)),
-      }
+      interaction
+         .match_iter(strict)
+         .find_map(|interaction| self.get(&interaction).and_then(|n| n.get(&attr_id)))
    }
 
    /// There are repeated AttrId
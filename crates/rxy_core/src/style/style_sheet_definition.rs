@@ -12,9 +12,16 @@ bitflags! {
     #[repr(transparent)]
     #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
     pub struct StyleInteraction: u8 {
-        const Focus  = 0b00000001;
-        const Hover  = 0b00000010;
-        const Active = 0b00000110;
+        const Focus        = 0b00000001;
+        const Hover        = 0b00000010;
+        const Active       = 0b00000110;
+        /// Focus reached through keyboard navigation, implies [`Focus`](Self::Focus).
+        const FocusVisible = 0b00001001;
+        const Checked      = 0b00010000;
+        const Selected     = 0b00100000;
+        const Disabled     = 0b01000000;
+        /// A dragged payload that the drop target accepts is over the node.
+        const DragOver     = 0b10000000;
     }
 }
 
@@ -27,8 +34,19 @@ impl StyleInteraction {
       }
    }
 
+   /// The interactions from the highest priority to the lowest.
    pub fn priority_iter() -> impl Iterator<Item = Self> {
-      [Self::Active, Self::Hover, Self::Focus].into_iter()
+      [
+         Self::Disabled,
+         Self::DragOver,
+         Self::Active,
+         Self::Hover,
+         Self::FocusVisible,
+         Self::Focus,
+         Self::Selected,
+         Self::Checked,
+      ]
+      .into_iter()
    }
 
    pub fn match_iter(self, strict: bool) -> impl Iterator<Item = Self> {
@@ -0,0 +1,107 @@
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+
+use crate::style::{StyleAttrValue, StyleSheetIndex};
+use crate::{
+   smallbox, AttrIndex, ElementAttrType, MaybeSend, MaybeSync, Renderer, RendererWorld,
+   XValueWrapper,
+};
+
+/// A style value resolved from the world, e.g. a theme token.
+///
+/// The value is resolved when the attr or style sheet is built. The node tree keeps track of
+/// where the token is used, so that it can resolve it again when its source changes.
+pub struct StyleToken<R, V>
+where
+   R: Renderer,
+{
+   pub resolve: fn(&RendererWorld<R>) -> V,
+}
+
+impl<R, V> StyleToken<R, V>
+where
+   R: Renderer,
+{
+   pub const fn new(resolve: fn(&RendererWorld<R>) -> V) -> Self {
+      Self { resolve }
+   }
+
+   #[inline]
+   pub fn get(&self, world: &RendererWorld<R>) -> V {
+      (self.resolve)(world)
+   }
+}
+
+impl<R, V> Clone for StyleToken<R, V>
+where
+   R: Renderer,
+{
+   fn clone(&self) -> Self {
+      *self
+   }
+}
+
+impl<R, V> Copy for StyleToken<R, V> where R: Renderer {}
+
+/// Where a style token is used on a node.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum StyleTokenTarget {
+   Attr(AttrIndex),
+   InlineStyleSheet {
+      index: StyleSheetIndex,
+      attr_id: AttrIndex,
+   },
+}
+
+impl StyleTokenTarget {
+   pub fn attr_id(&self) -> AttrIndex {
+      match self {
+         StyleTokenTarget::Attr(attr_id) => *attr_id,
+         StyleTokenTarget::InlineStyleSheet { attr_id, .. } => *attr_id,
+      }
+   }
+}
+
+pub trait StyleTokenResolver<R>: MaybeSend + MaybeSync + 'static
+where
+   R: Renderer,
+{
+   fn resolve(&self, world: &RendererWorld<R>) -> StyleAttrValue;
+}
+
+pub type BoxedStyleTokenResolver<R> = Arc<dyn StyleTokenResolver<R>>;
+
+/// Resolves a token as the value of the attr `EA`.
+pub struct AttrStyleTokenResolver<R, EA, V>(pub StyleToken<R, V>, PhantomData<EA>)
+where
+   R: Renderer;
+
+impl<R, EA, V> AttrStyleTokenResolver<R, EA, V>
+where
+   R: Renderer,
+   EA: ElementAttrType<R>,
+   V: Into<XValueWrapper<EA::Value>> + 'static,
+{
+   pub fn new(token: StyleToken<R, V>) -> Self {
+      Self(token, PhantomData)
+   }
+
+   pub fn value(&self, world: &RendererWorld<R>) -> EA::Value {
+      self.0.get(world).into().0
+   }
+
+   pub fn boxed(self) -> BoxedStyleTokenResolver<R> {
+      Arc::new(self)
+   }
+}
+
+impl<R, EA, V> StyleTokenResolver<R> for AttrStyleTokenResolver<R, EA, V>
+where
+   R: Renderer,
+   EA: ElementAttrType<R>,
+   V: Into<XValueWrapper<EA::Value>> + 'static,
+{
+   fn resolve(&self, world: &RendererWorld<R>) -> StyleAttrValue {
+      smallbox!(self.value(world))
+   }
+}
@@ -31,6 +31,7 @@ pub mod prelude {
       effect::{create_effect, Effect},
       memo::{use_memo, ArcMemo, Memo},
       render_effect::create_render_effect,
+      selector::Selector,
       signal::{
          use_rw_signal, use_signal, ArcRwSignal, ArcWriteSignal, ReadSignal, RwSignal, WriteSignal,
       },
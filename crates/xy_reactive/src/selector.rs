@@ -1,5 +1,5 @@
 use crate::{
-   prelude::SignalWith, render_effect::RenderEffect, signal::ArcRwSignal,
+   effect::ErasureEffect, prelude::SignalWith, render_effect::RenderEffect, signal::ArcRwSignal,
    signal_traits::SignalUpdate,
 };
 use parking_lot::RwLock;
@@ -16,7 +16,9 @@ where
    subs: Arc<RwLock<FxHashMap<T, ArcRwSignal<bool>>>>,
    v: Arc<RwLock<Option<T>>>,
    #[allow(clippy::type_complexity)]
-   f: Arc<dyn Fn(&T, &T) -> bool>,
+   f: Arc<dyn Fn(&T, &T) -> bool + Send + Sync>,
+   /// The effect stops once it is dropped, so the selector keeps it.
+   _effect: Arc<ErasureEffect>,
 }
 
 impl<T> Selector<T>
@@ -29,13 +31,13 @@ where
 
    pub fn new_with_fn(
       source: impl Fn() -> T + Clone + 'static,
-      f: impl Fn(&T, &T) -> bool + Clone + 'static,
+      f: impl Fn(&T, &T) -> bool + Clone + Send + Sync + 'static,
    ) -> Self {
       let subs: Arc<RwLock<FxHashMap<T, ArcRwSignal<bool>>>> = Default::default();
       let v: Arc<RwLock<Option<T>>> = Default::default();
-      let f = Arc::new(f) as Arc<dyn Fn(&T, &T) -> bool>;
+      let f = Arc::new(f) as Arc<dyn Fn(&T, &T) -> bool + Send + Sync>;
 
-      RenderEffect::new({
+      let effect = RenderEffect::new({
          let subs = Arc::clone(&subs);
          let f = Arc::clone(&f);
          let v = Arc::clone(&v);
@@ -53,7 +55,12 @@ where
          }
       });
 
-      Selector { subs, v, f }
+      Selector {
+         subs,
+         v,
+         f,
+         _effect: Arc::new(effect.erase()),
+      }
    }
 
    /// Reactively checks whether the given key is selected.
@@ -81,3 +88,35 @@ where
       subs.clear();
    }
 }
+
+#[cfg(test)]
+mod tests {
+   use tokio::task::{yield_now, LocalSet};
+
+   use crate::prelude::{Memo, RwSignal, SignalGet, SignalSet};
+
+   use super::Selector;
+
+   #[tokio::test]
+   async fn keeps_following_the_source() {
+      LocalSet::new()
+         .run_until(async {
+            let selected = RwSignal::new(1);
+            let selector = Selector::new(move || selected.get());
+            let is_one = Memo::new({
+               let selector = selector.clone();
+               move |_| selector.selected(1)
+            });
+            let is_two = Memo::new(move |_| selector.selected(2));
+            assert!(is_one.get());
+            assert!(!is_two.get());
+
+            // the source is read by the effect of the selector, it stops once the effect is dropped
+            selected.set(2);
+            yield_now().await;
+            assert!(!is_one.get());
+            assert!(is_two.get());
+         })
+         .await;
+   }
+}
@@ -4,10 +4,14 @@
 
 use bevy::app::AppExit;
 use bevy::prelude::*;
-use rxy_bevy::navigation::RxyKeyboardNavigationPlugin;
+use rxy_bevy::all_attrs::scale;
+use rxy_bevy::navigation::{
+   GamepadNavigationInput, RxyGamepadNavigationPlugin, RxyKeyboardNavigationPlugin,
+};
 use rxy_ui::prelude::*;
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 mod components;
 
@@ -29,7 +33,12 @@ fn main() {
       RxyPlugin::default(),
       RxyStyleSheetPlugin::default(),
       RxyKeyboardNavigationPlugin::default(),
+      RxyGamepadNavigationPlugin::default(),
    ))
+   .insert_resource(GamepadNavigationInput {
+      arrow_keys: true,
+      ..default()
+   })
    .init_state::<GameState>()
    .add_systems(Startup, setup);
 
@@ -66,6 +75,13 @@ impl ElementEventIds for XConfirm {
    }
 }
 
+fn menu_entry_keyframes(index: u64) -> AttrKeyframes {
+   AttrKeyframes::new(Duration::from_millis(240))
+      .track(scale, [(0., Vec3::new(0.6, 0.6, 1.)), (1., Vec3::ONE)])
+      .delay(Duration::from_millis(index * 80))
+      .easing(Easing::CubicOut)
+}
+
 #[schema]
 fn schema_main_menu() -> impl IntoView<BevyRenderer> {
    #[derive(TypedStyle)]
@@ -85,24 +101,27 @@ fn schema_main_menu() -> impl IntoView<BevyRenderer> {
       div().style(x().size_screen().center()).children(
          div().style(x().flex_col().gap(8).padding(20)).children({
             (
-               button().style(MenuBtnStyle).children("New Game").on(
-                  XConfirm,
-                  |mut next_state: ResMut<NextState<GameState>>| {
+               button()
+                  .style(MenuBtnStyle)
+                  .children("New Game")
+                  .animate(menu_entry_keyframes(0))
+                  .on(XConfirm, |mut next_state: ResMut<NextState<GameState>>| {
                      next_state.set(GameState::InGame);
-                  },
-               ),
-               button().style(MenuBtnStyle).children("Setting").on(
-                  XConfirm,
-                  |mut next_state: ResMut<NextState<GameState>>| {
+                  }),
+               button()
+                  .style(MenuBtnStyle)
+                  .children("Setting")
+                  .animate(menu_entry_keyframes(1))
+                  .on(XConfirm, |mut next_state: ResMut<NextState<GameState>>| {
                      next_state.set(GameState::Setting);
-                  },
-               ),
-               button().style(MenuBtnStyle).children("Exit").on(
-                  XConfirm,
-                  |mut app_exit: EventWriter<AppExit>| {
+                  }),
+               button()
+                  .style(MenuBtnStyle)
+                  .children("Exit")
+                  .animate(menu_entry_keyframes(2))
+                  .on(XConfirm, |mut app_exit: EventWriter<AppExit>| {
                      app_exit.send(AppExit);
-                  },
-               ),
+                  }),
             )
          }),
       ),
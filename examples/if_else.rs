@@ -1,4 +1,7 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
+use rxy_ui::bevy::all_attrs::scale;
 use rxy_ui::prelude::*;
 
 fn main() {
@@ -40,6 +43,20 @@ fn ui() -> impl IntoView<BevyRenderer> {
             })),
          x_if_else(condition, "x_if_else: True", "Else"),
          x_if(condition, "x_if: True"),
+         x_if(
+            condition,
+            div().children("x_if with transition").transition(
+               x_transition()
+                  .enter(
+                     AttrKeyframes::new(Duration::from_millis(200))
+                        .track(scale, [(0., Vec3::ZERO), (1., Vec3::ONE)]),
+                  )
+                  .leave(
+                     AttrKeyframes::new(Duration::from_millis(200))
+                        .track(scale, [(0., Vec3::ONE), (1., Vec3::ZERO)]),
+                  ),
+            ),
+         ),
          "--Footer--",
       )),
    )
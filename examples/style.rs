@@ -96,6 +96,16 @@ fn ui() -> impl IntoView<BevyRenderer> {
                .get()
                .then_some((x().bg_color(Color::RED), x_hover().bg_color(Color::WHITE)))
          }),
+      div()
+         .class("flex p-4 gap-2 bg-sky-500/50 hover:bg-sky-600 active:bg-sky-700 w-1/2")
+         .children("tailwind classes"),
+      div()
+         .style((
+            x().bg_color(token::surface).padding_left(token::spacing_md),
+            x_hover().bg_color(token::primary),
+         ))
+         .on_pointer_click(|mut theme: ResMut<Theme>| theme.toggle())
+         .children("toggle theme"),
       "--Footer--",
    ))
 }